@@ -1,46 +1,215 @@
-use num_traits::MulAddAssign;
-
-use crate::{FloatingPoint, Vector2, Matrix2, Number};
-
-pub mod quaternion;
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Complex<T: Number> {
-    pub re: T,
-    pub im: T
-}
-
-impl<T: Number> Complex<T>  {
-    pub fn new(real: T, imaginary: T) -> Self {
-        Self { re: real, im: imaginary }
-    }
-    // matrix representation of a complex number
-    pub fn matrix(&self) -> Matrix2<T> {
-        Matrix2 { x: Vector2::<T>::new(self.re, self.im) , y: Vector2::<T>::new(self.im, self.re)  }
-    }
-    // matrix representation of a complex number with only the imaginary part
-    pub fn matrix_im(&self) -> Matrix2<T> {
-        Matrix2 { x: Vector2::<T>::new(T::zero(), self.im) , y: Vector2::<T>::new(self.im, T::zero())  }
-    }
-    // matrix representation of a complex number with only the real part
-    pub fn matrix_re(&self) -> Matrix2<T> {
-        Matrix2 { x: Vector2::<T>::new(self.re, T::zero()) , y: Vector2::<T>::new(T::zero(), self.re)  }
-    }
-}
-impl<T: Number> std::ops::Mul for Complex<T>  {
-    fn mul(self, rhs: Self) -> Self {
-        Self { re: self.re * rhs.re - self.im * rhs.im, im: (self.re * rhs.im + self.im * rhs.re) }
-    }
-    type Output = Self;
-}
-impl<T: Number> std::ops::Add for Complex<T>  {
-    fn add(self, rhs: Self) -> Self {
-        Self { re: self.re + rhs.re, im: self.im + rhs.im }
-    }
-    type Output = Self;
-}
-impl<T: Number> std::ops::Sub for Complex<T>  {
-    fn sub(self, rhs: Self) -> Self {
-        Self { re: self.re - rhs.re, im: self.im - rhs.im }
-    }
-    type Output = Self;
-}
\ No newline at end of file
+use num_traits::MulAddAssign;
+
+use num_traits::AsPrimitive;
+
+use crate::{FloatingPoint, Vector2, Matrix2, Number, SignedNumber};
+
+pub mod quaternion;
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex<T: Number> {
+    pub re: T,
+    pub im: T
+}
+
+impl<T: Number> Complex<T>  {
+    pub fn new(real: T, imaginary: T) -> Self {
+        Self { re: real, im: imaginary }
+    }
+    // matrix representation of a complex number
+    pub fn matrix(&self) -> Matrix2<T> {
+        Matrix2 { x: Vector2::<T>::new(self.re, self.im) , y: Vector2::<T>::new(self.im, self.re)  }
+    }
+    // matrix representation of a complex number with only the imaginary part
+    pub fn matrix_im(&self) -> Matrix2<T> {
+        Matrix2 { x: Vector2::<T>::new(T::zero(), self.im) , y: Vector2::<T>::new(self.im, T::zero())  }
+    }
+    // matrix representation of a complex number with only the real part
+    pub fn matrix_re(&self) -> Matrix2<T> {
+        Matrix2 { x: Vector2::<T>::new(self.re, T::zero()) , y: Vector2::<T>::new(T::zero(), self.re)  }
+    }
+    /// the square of the absolute value. cheaper than [`Complex::norm`] since it avoids the square root.
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+impl<T: SignedNumber> Complex<T> {
+    /// the complex conjugate, which mirrors the number across the real axis.
+    pub fn conj(&self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+}
+///
+/// These functions are only available for floating point numbers.
+/// all multivalued functions return their principal value.
+/// 
+impl<T: FloatingPoint> Complex<T>
+    where f32: AsPrimitive<T> {
+    /// the absolute value, or the distance from the origin.
+    pub fn norm(&self) -> T {
+        self.re.hypot(self.im)
+    }
+    /// the angle from the positive real axis in radians, between -pi and pi.
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+    /// creates a complex number from its distance from the origin and angle.
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self { re: r * theta.cos(), im: r * theta.sin() }
+    }
+    /// returns the distance from the origin and the angle as (r, theta).
+    pub fn to_polar(&self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+    /// e raised to the power of the complex number.
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+    /// the natural logarithm, with the imaginary part between -pi and pi.
+    pub fn ln(&self) -> Self {
+        Self { re: self.norm().ln(), im: self.arg() }
+    }
+    /// raises the number to a real power. anything to the power of 0 is 1, and 0 to
+    /// a negative power is infinite.
+    pub fn powf(&self, exponent: T) -> Self {
+        if exponent == T::zero() {
+            return Self::new(T::one(), T::zero());
+        }
+        if self.re == T::zero() && self.im == T::zero() {
+            return if exponent > T::zero() { *self } else { Self::new(T::infinity(), T::zero()) };
+        }
+        let (r, theta) = self.to_polar();
+        Self::from_polar(r.powf(exponent), theta * exponent)
+    }
+    /// raises the number to a complex power. 0 to a power with a real part that is
+    /// not positive has no limit unless the power is real, so the result is NaN.
+    pub fn powc(&self, exponent: Self) -> Self {
+        if exponent.im == T::zero() {
+            return self.powf(exponent.re);
+        }
+        if self.re == T::zero() && self.im == T::zero() {
+            return if exponent.re > T::zero() { *self } else { Self::new(T::nan(), T::nan()) };
+        }
+        (exponent * self.ln()).exp()
+    }
+    /// the square root with a non negative real part.
+    pub fn sqrt(&self) -> Self {
+        let r = self.norm();
+        let re = ((r + self.re) * 0.5.as_()).sqrt();
+        let im = ((r - self.re) * 0.5.as_()).sqrt();
+        Self { re, im: if self.im < T::zero() { -im } else { im } }
+    }
+    pub fn sin(&self) -> Self {
+        Self { re: self.re.sin() * self.im.cosh(), im: self.re.cos() * self.im.sinh() }
+    }
+    pub fn cos(&self) -> Self {
+        Self { re: self.re.cos() * self.im.cosh(), im: -self.re.sin() * self.im.sinh() }
+    }
+    pub fn tan(&self) -> Self {
+        let (re2, im2) = (self.re + self.re, self.im + self.im);
+        let denominator = re2.cos() + im2.cosh();
+        Self { re: re2.sin() / denominator, im: im2.sinh() / denominator }
+    }
+    pub fn sinh(&self) -> Self {
+        Self { re: self.re.sinh() * self.im.cos(), im: self.re.cosh() * self.im.sin() }
+    }
+    pub fn cosh(&self) -> Self {
+        Self { re: self.re.cosh() * self.im.cos(), im: self.re.sinh() * self.im.sin() }
+    }
+    pub fn tanh(&self) -> Self {
+        let (re2, im2) = (self.re + self.re, self.im + self.im);
+        let denominator = re2.cosh() + im2.cos();
+        Self { re: re2.sinh() / denominator, im: im2.sin() / denominator }
+    }
+    /// asin(z) = -i * ln(iz + sqrt(1 - z^2))
+    pub fn asin(&self) -> Self {
+        let i = Self::new(T::zero(), T::one());
+        let root = (Self::new(T::one(), T::zero()) - *self * *self).sqrt();
+        -i * (i * *self + root).ln()
+    }
+    /// acos(z) = -i * ln(z + i * sqrt(1 - z^2))
+    pub fn acos(&self) -> Self {
+        let i = Self::new(T::zero(), T::one());
+        let root = (Self::new(T::one(), T::zero()) - *self * *self).sqrt();
+        -i * (*self + i * root).ln()
+    }
+    /// atan(z) = i/2 * ln((i + z) / (i - z))
+    pub fn atan(&self) -> Self {
+        let i = Self::new(T::zero(), T::one());
+        Self::new(T::zero(), 0.5.as_()) * ((i + *self) / (i - *self)).ln()
+    }
+    /// asinh(z) = ln(z + sqrt(z^2 + 1))
+    pub fn asinh(&self) -> Self {
+        (*self + (*self * *self + T::one()).sqrt()).ln()
+    }
+    /// acosh(z) = ln(z + sqrt(z + 1) * sqrt(z - 1))
+    pub fn acosh(&self) -> Self {
+        (*self + (*self + T::one()).sqrt() * (*self - T::one()).sqrt()).ln()
+    }
+    /// atanh(z) = 1/2 * ln((1 + z) / (1 - z))
+    pub fn atanh(&self) -> Self {
+        let one = Self::new(T::one(), T::zero());
+        ((one + *self) / (one - *self)).ln() * 0.5.as_()
+    }
+}
+impl<T: Number> std::ops::Mul for Complex<T>  {
+    fn mul(self, rhs: Self) -> Self {
+        Self { re: self.re * rhs.re - self.im * rhs.im, im: (self.re * rhs.im + self.im * rhs.re) }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Add for Complex<T>  {
+    fn add(self, rhs: Self) -> Self {
+        Self { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Sub for Complex<T>  {
+    fn sub(self, rhs: Self) -> Self {
+        Self { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Div for Complex<T>  {
+    fn div(self, rhs: Self) -> Self {
+        let denominator = rhs.norm_sqr();
+        Self { re: (self.re * rhs.re + self.im * rhs.im) / denominator, im: (self.im * rhs.re - self.re * rhs.im) / denominator }
+    }
+    type Output = Self;
+}
+impl<T: SignedNumber> std::ops::Neg for Complex<T>  {
+    fn neg(self) -> Self {
+        Self { re: -self.re, im: -self.im }
+    }
+    type Output = Self;
+}
+
+// Operations on scalar values
+impl<T: Number> std::ops::Add<T> for Complex<T>  {
+    fn add(self, rhs: T) -> Self {
+        Self { re: self.re + rhs, im: self.im }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Sub<T> for Complex<T>  {
+    fn sub(self, rhs: T) -> Self {
+        Self { re: self.re - rhs, im: self.im }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Mul<T> for Complex<T>  {
+    fn mul(self, rhs: T) -> Self {
+        Self { re: self.re * rhs, im: self.im * rhs }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Div<T> for Complex<T>  {
+    fn div(self, rhs: T) -> Self {
+        Self { re: self.re / rhs, im: self.im / rhs }
+    }
+    type Output = Self;
+}
+impl<T: Number> From<T> for Complex<T> {
+    fn from(value: T) -> Self {
+        Self { re: value, im: T::zero() }
+    }
+}
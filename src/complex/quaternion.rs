@@ -1,168 +1,449 @@
-use std::fmt::Debug;
-
-use num_traits::AsPrimitive;
-
-use crate::{Vector3, Matrix3, Number, FloatingPoint, Vector};
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Quaternion<T> {
-    pub vector: Vector3<T>,
-    pub scalar: T,
-}
-
-/// ===========================================================
-/// 
-/// Implementation for Quaternion
-/// 
-/// ===========================================================
-
-///
-/// These functions are available for all vectors.
-/// 
-impl<T> Quaternion<T>  {
-    pub fn new(v: Vector3<T>, s: T) -> Self {
-        Self { vector: v, scalar: s }
-    }
-    /// # from_euler
-    /// 
-    /// converts euler angles into quaternion form.
-    /// 
-    /// this function is heavily inspired by [this stackexchange post](https://math.stackexchange.com/questions/2975109/how-to-convert-euler-angles-to-quaternions-and-get-the-same-euler-angles-back-fr)
-    pub fn from_euler(v: Vector3<T>) -> Self 
-        where T: FloatingPoint,
-        f32: AsPrimitive<T>, 
-        f64: AsPrimitive<T> {
-        let (yaw, pitch, roll) = (v.x, v.y, v.z);
-        let x = T::sin(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::cos(yaw/2.0.as_()) - T::cos(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::sin(yaw/2.0.as_());
-        let y = T::cos(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::cos(yaw/2.0.as_()) + T::sin(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::sin(yaw/2.0.as_());
-        let z = T::cos(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::sin(yaw/2.0.as_()) - T::sin(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::cos(yaw/2.0.as_());
-        let w = T::cos(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::cos(yaw/2.0.as_()) + T::sin(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::sin(yaw/2.0.as_());
-        Self { vector: Vector3 { x, y, z }, scalar: w }
-    }
-    pub fn angle_axis(angle: T, vector: Vector3<T>) -> Self 
-        where T: FloatingPoint,
-        f32: AsPrimitive<T>, 
-        f64: AsPrimitive<T> {
-        let half_angle = angle * 0.5.as_();
-        let s = half_angle.sin();
-        Self { vector: vector * s, scalar: half_angle.cos() }
-    }
-    pub fn to_euler(&self) -> Vector3<T>
-        where T: FloatingPoint,
-        f32: AsPrimitive<T>, 
-        f64: AsPrimitive<T> {
-        // let roll: T = T::atan2(2.0.as_() * (self.scalar * self.vector.x + self.vector.y * self.vector.z), self.scalar*self.scalar - self.vector.x*self.vector.x - self.vector.y*self.vector.y + self.vector.z*self.vector.z);
-        // let pitch: T = T::asin((-2.0).as_() * (self.scalar * self.vector.y - self.vector.z * self.vector.x));
-        // let yaw = T::atan2(2.0.as_() * (self.scalar * self.vector.z + self.vector.x * self.vector.y), self.scalar*self.scalar + self.vector.x*self.vector.x - self.vector.y*self.vector.y - self.vector.z*self.vector.z);
-        // Vector3 { x: roll, y: pitch, z: yaw }
-
-        let (x, y, z, w) = (self.vector.x, self.vector.y, self.vector.z, self.scalar);
-        let t0 = 2.0.as_() * (w * x + y * z);
-        let t1 = 1.0.as_() - 2.0.as_() * (x * x + y * y);
-        let roll = T::atan2(t0, t1);
-        let t2 = 2.0.as_() * (w * y - z * x);
-        let t2 = if t2 > 1.0.as_() {1.0.as_()} else {t2};
-        let t2 = if t2 < (-1.0).as_() {(-1.0).as_()} else {t2};
-        let pitch = T::asin(t2);
-        let t3 = 2.0.as_() * (w * z + x * y);
-        let t4 = 1.0.as_() - 2.0.as_() * (y * y + z * z);
-        let yaw = T::atan2(t3, t4);
-        return Vector3::new(yaw, pitch, roll);
-
-        // let sinr_cosp = 2.0.as_() * (self.scalar * self.vector.x + self.vector.y * self.vector.z);
-        // let cosr_cosp = 1.0.as_() - 2.0.as_() * (self.vector.x * self.vector.x + self.vector.y * self.vector.y);
-        // let roll = T::atan2(sinr_cosp, cosr_cosp);
-// 
-        // // pitch (y-axis rotation)
-        // let sinp = T::sqrt(1.0.as_() + 2.0.as_() * (self.scalar * self.vector.y - self.vector.x * self.vector.z));
-        // let cosp = T::sqrt(1.0.as_() - 2.0.as_() * (self.scalar * self.vector.y - self.vector.x * self.vector.z));
-        // let pitch = 2.0.as_() * T::atan2(sinp, cosp) - std::f64::consts::PI.as_() / 2.0.as_();
-// 
-        // // yaw (z-axis rotation)
-        // let siny_cosp = 2.0.as_() * (self.scalar * self.vector.z + self.vector.x * self.vector.y);
-        // let cosy_cosp = 1.0.as_() - 2.0.as_() * (self.vector.y * self.vector.y + self.vector.z * self.vector.z);
-        // let yaw = T::atan2(siny_cosp, cosy_cosp);
-// 
-        // return Vector3::new(roll, pitch, yaw);
-    }
-}
-
-impl<T: Number> From<Quaternion<T>> for Matrix3<T> {
-    fn from(value: Quaternion<T>) -> Self {
-        let x2 = value.vector.x + value.vector.x;
-        let y2 = value.vector.y + value.vector.y;
-        let z2 = value.vector.z + value.vector.z;
-
-        let xx2 = x2 * value.vector.x;
-        let xy2 = x2 * value.vector.y;
-        let xz2 = x2 * value.vector.z;
-
-        let yy2 = y2 * value.vector.y;
-        let yz2 = y2 * value.vector.z;
-        let zz2 = z2 * value.vector.z;
-
-        let sy2 = y2 * value.scalar;
-        let sz2 = z2 * value.scalar;
-        let sx2 = x2 * value.scalar;
-
-        Self { 
-            x: Vector3 { 
-                x: T::one() - yy2 - zz2, 
-                y: xy2 + sz2, 
-                z: xz2 - sy2, 
-            }, 
-            y: Vector3 { 
-                x: xy2 - sz2, 
-                y: T::one() - xx2 - zz2, 
-                z: yz2 + sx2, 
-            }, 
-            z: Vector3 { 
-                x: xz2 + sy2, 
-                y: yz2 - sx2, 
-                z: T::one() - xx2 - yy2, 
-            }, 
-        }
-    }
-}
-// traits for bitwise operations
-
-impl<T: Number> std::ops::Mul<Vector3<T>> for Quaternion<T> {
-    fn mul(self, rhs: Vector3<T>) -> Self::Output {
-        let x2 = self.vector.x + self.vector.x;
-        let y2 = self.vector.y + self.vector.y;
-        let z2 = self.vector.z + self.vector.z;
-        
-        let xx2 = x2 * self.vector.x;
-        let xy2 = x2 * self.vector.y;
-        let xz2 = x2 * self.vector.z;
-
-        let yy2 = y2 * self.vector.y;
-        let yz2 = y2 * self.vector.z;
-        let zz2 = z2 * self.vector.z;
-
-        let sy2 = y2 * self.scalar;
-        let sz2 = z2 * self.scalar;
-        let sx2 = x2 * self.scalar;
-
-        Vector3 {
-            x: (T::one() - (yy2 + zz2)) * rhs.x + (xy2 - sz2) * rhs.y + (xz2 + sy2) * rhs.z,
-            y: (xy2 + sz2) * rhs.x + (T::one() - (xx2 + zz2)) * rhs.y + (yz2 - sx2) * rhs.z,
-            z: (xz2 - sy2) * rhs.x + (yz2 + sx2) * rhs.y + (T::one() - (xx2 + yy2)) * rhs.z,
-        }
-    }
-    type Output = Vector3<T>;
-}
-impl<T: Number> std::ops::Mul for Quaternion<T>  {
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self { 
-            vector: Vector3 { 
-                x: self.scalar * rhs.vector.x + self.vector.x * rhs.scalar + self.vector.y * rhs.vector.z - self.vector.z * rhs.vector.y, 
-                y: self.scalar * rhs.vector.y + self.vector.y * rhs.scalar + self.vector.z * rhs.vector.x - self.vector.x * rhs.vector.z, 
-                z: self.scalar * rhs.vector.z + self.vector.z * rhs.scalar + self.vector.x * rhs.vector.y - self.vector.y * rhs.vector.x
-            }, 
-            scalar: self.scalar * rhs.scalar - self.vector.x * rhs.vector.x - self.vector.y * rhs.vector.y - self.vector.z * rhs.vector.z
-        }
-    }
-    type Output = Self;
+use std::fmt::Debug;
+
+use num_traits::AsPrimitive;
+
+use crate::{Vector3, Matrix3, Matrix4, Number, SignedNumber, FloatingPoint, Vector, EuclideanGeometry};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Quaternion<T> {
+    pub vector: Vector3<T>,
+    pub scalar: T,
+}
+
+/// # EulerOrder
+/// 
+/// the order in which the rotations of a set of euler angles are applied.
+/// extrinsic orders rotate around the fixed world axes, while intrinsic orders rotate
+/// around the axes of the object after the previous rotations were applied. the angles are
+/// always stored in the same place no matter the order, x holds the rotation around the x axis,
+/// y around the y axis and z around the z axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerOrder {
+    ExtrinsicXYZ,
+    ExtrinsicXZY,
+    ExtrinsicYXZ,
+    ExtrinsicYZX,
+    ExtrinsicZXY,
+    ExtrinsicZYX,
+    IntrinsicXYZ,
+    IntrinsicXZY,
+    IntrinsicYXZ,
+    IntrinsicYZX,
+    IntrinsicZXY,
+    IntrinsicZYX,
+}
+impl EulerOrder {
+    /// the axes in the order they are applied when rotating around the fixed world axes.
+    /// an intrinsic rotation is the same as the extrinsic rotation with the order reversed.
+    fn extrinsic_axes(&self) -> [usize; 3] {
+        match self {
+            Self::ExtrinsicXYZ | Self::IntrinsicZYX => [0, 1, 2],
+            Self::ExtrinsicXZY | Self::IntrinsicYZX => [0, 2, 1],
+            Self::ExtrinsicYXZ | Self::IntrinsicZXY => [1, 0, 2],
+            Self::ExtrinsicYZX | Self::IntrinsicXZY => [1, 2, 0],
+            Self::ExtrinsicZXY | Self::IntrinsicYXZ => [2, 0, 1],
+            Self::ExtrinsicZYX | Self::IntrinsicXYZ => [2, 1, 0],
+        }
+    }
+}
+
+/// ===========================================================
+/// 
+/// Implementation for Quaternion
+/// 
+/// ===========================================================
+
+///
+/// These functions are available for all vectors.
+/// 
+impl<T> Quaternion<T>  {
+    pub fn new(v: Vector3<T>, s: T) -> Self {
+        Self { vector: v, scalar: s }
+    }
+    /// # from_euler
+    /// 
+    /// converts euler angles into quaternion form.
+    /// 
+    /// this function is heavily inspired by [this stackexchange post](https://math.stackexchange.com/questions/2975109/how-to-convert-euler-angles-to-quaternions-and-get-the-same-euler-angles-back-fr)
+    pub fn from_euler(v: Vector3<T>) -> Self 
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        let (yaw, pitch, roll) = (v.x, v.y, v.z);
+        let x = T::sin(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::cos(yaw/2.0.as_()) - T::cos(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::sin(yaw/2.0.as_());
+        let y = T::cos(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::cos(yaw/2.0.as_()) + T::sin(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::sin(yaw/2.0.as_());
+        let z = T::cos(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::sin(yaw/2.0.as_()) - T::sin(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::cos(yaw/2.0.as_());
+        let w = T::cos(roll/2.0.as_()) * T::cos(pitch/2.0.as_()) * T::cos(yaw/2.0.as_()) + T::sin(roll/2.0.as_()) * T::sin(pitch/2.0.as_()) * T::sin(yaw/2.0.as_());
+        Self { vector: Vector3 { x, y, z }, scalar: w }
+    }
+    pub fn angle_axis(angle: T, vector: Vector3<T>) -> Self 
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        let half_angle = angle * 0.5.as_();
+        let s = half_angle.sin();
+        Self { vector: vector * s, scalar: half_angle.cos() }
+    }
+    /// # from_euler_ordered
+    /// 
+    /// converts euler angles into quaternion form, applying the rotations
+    /// in the order specified.
+    pub fn from_euler_ordered(v: Vector3<T>, order: EulerOrder) -> Self 
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        let angles = [v.x, v.y, v.z];
+        let mut result = Quaternion::identity();
+        for axis in order.extrinsic_axes() {
+            let mut vector = Vector3::new(T::zero(), T::zero(), T::zero());
+            match axis {
+                0 => vector.x = T::one(),
+                1 => vector.y = T::one(),
+                _ => vector.z = T::one(),
+            }
+            result = Self::angle_axis(angles[axis], vector) * result;
+        }
+        result
+    }
+    /// # to_euler_ordered
+    /// 
+    /// converts the quaternion into euler angles that reproduce the rotation
+    /// when applied in the order specified. when the middle rotation is at ±90 degrees
+    /// the first and last axes line up (gimbal lock), in that case the last rotation is set to 0.
+    pub fn to_euler_ordered(&self, order: EulerOrder) -> Vector3<T>
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        let matrix = Matrix3::from(self.normalize());
+        // element [row][column] of the rotation matrix
+        let m = [
+            [matrix.x.x, matrix.y.x, matrix.z.x],
+            [matrix.x.y, matrix.y.y, matrix.z.y],
+            [matrix.x.z, matrix.y.z, matrix.z.z],
+        ];
+        let [i, j, k] = order.extrinsic_axes();
+        // the formulas for the cyclic orders (xyz, yzx, zxy) differ from the others by a sign.
+        let parity = if (j + 3 - i) % 3 == 1 { T::one() } else { -T::one() };
+        let sin_middle = -parity * m[k][i];
+        let sin_middle = if sin_middle > T::one() { T::one() } else if sin_middle < -T::one() { -T::one() } else { sin_middle };
+        let mut angles = [T::zero(); 3];
+        angles[j] = sin_middle.asin();
+        if sin_middle.abs() < 0.9999999.as_() {
+            angles[i] = T::atan2(parity * m[k][j], m[k][k]);
+            angles[k] = T::atan2(parity * m[j][i], m[i][i]);
+        } else {
+            angles[i] = T::atan2(-parity * m[j][k], m[j][j]);
+            angles[k] = T::zero();
+        }
+        Vector3::new(angles[0], angles[1], angles[2])
+    }
+    /// # from_matrix3
+    /// 
+    /// converts a rotation matrix into quaternion form using Shepperd's method,
+    /// which picks the largest of the four components to divide by so the
+    /// conversion stays accurate for every rotation. the matrix should not contain any scaling.
+    pub fn from_matrix3(m: &Matrix3<T>) -> Self 
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        let (m00, m01, m02) = (m.x.x, m.y.x, m.z.x);
+        let (m10, m11, m12) = (m.x.y, m.y.y, m.z.y);
+        let (m20, m21, m22) = (m.x.z, m.y.z, m.z.z);
+        let trace = m00 + m11 + m22;
+        if trace >= m00 && trace >= m11 && trace >= m22 {
+            let w = 0.5.as_() * (T::one() + trace).sqrt();
+            let s = 0.25.as_() / w;
+            Self { vector: Vector3::new((m21 - m12) * s, (m02 - m20) * s, (m10 - m01) * s), scalar: w }
+        } else if m00 >= m11 && m00 >= m22 {
+            let x = 0.5.as_() * (T::one() + m00 - m11 - m22).sqrt();
+            let s = 0.25.as_() / x;
+            Self { vector: Vector3::new(x, (m01 + m10) * s, (m02 + m20) * s), scalar: (m21 - m12) * s }
+        } else if m11 >= m22 {
+            let y = 0.5.as_() * (T::one() - m00 + m11 - m22).sqrt();
+            let s = 0.25.as_() / y;
+            Self { vector: Vector3::new((m01 + m10) * s, y, (m12 + m21) * s), scalar: (m02 - m20) * s }
+        } else {
+            let z = 0.5.as_() * (T::one() - m00 - m11 + m22).sqrt();
+            let s = 0.25.as_() / z;
+            Self { vector: Vector3::new((m02 + m20) * s, (m12 + m21) * s, z), scalar: (m10 - m01) * s }
+        }
+    }
+    /// # from_matrix4
+    /// 
+    /// converts the rotation part of a transformation matrix into quaternion form,
+    /// the translation is ignored. see [`Quaternion::from_matrix3`].
+    pub fn from_matrix4(m: &Matrix4<T>) -> Self 
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        Self::from_matrix3(&Matrix3::from(*m))
+    }
+    pub fn to_euler(&self) -> Vector3<T>
+        where T: FloatingPoint,
+        f32: AsPrimitive<T>, 
+        f64: AsPrimitive<T> {
+        // let roll: T = T::atan2(2.0.as_() * (self.scalar * self.vector.x + self.vector.y * self.vector.z), self.scalar*self.scalar - self.vector.x*self.vector.x - self.vector.y*self.vector.y + self.vector.z*self.vector.z);
+        // let pitch: T = T::asin((-2.0).as_() * (self.scalar * self.vector.y - self.vector.z * self.vector.x));
+        // let yaw = T::atan2(2.0.as_() * (self.scalar * self.vector.z + self.vector.x * self.vector.y), self.scalar*self.scalar + self.vector.x*self.vector.x - self.vector.y*self.vector.y - self.vector.z*self.vector.z);
+        // Vector3 { x: roll, y: pitch, z: yaw }
+
+        let (x, y, z, w) = (self.vector.x, self.vector.y, self.vector.z, self.scalar);
+        let t0 = 2.0.as_() * (w * x + y * z);
+        let t1 = 1.0.as_() - 2.0.as_() * (x * x + y * y);
+        let roll = T::atan2(t0, t1);
+        let t2 = 2.0.as_() * (w * y - z * x);
+        let t2 = if t2 > 1.0.as_() {1.0.as_()} else {t2};
+        let t2 = if t2 < (-1.0).as_() {(-1.0).as_()} else {t2};
+        let pitch = T::asin(t2);
+        let t3 = 2.0.as_() * (w * z + x * y);
+        let t4 = 1.0.as_() - 2.0.as_() * (y * y + z * z);
+        let yaw = T::atan2(t3, t4);
+        return Vector3::new(yaw, pitch, roll);
+
+        // let sinr_cosp = 2.0.as_() * (self.scalar * self.vector.x + self.vector.y * self.vector.z);
+        // let cosr_cosp = 1.0.as_() - 2.0.as_() * (self.vector.x * self.vector.x + self.vector.y * self.vector.y);
+        // let roll = T::atan2(sinr_cosp, cosr_cosp);
+// 
+        // // pitch (y-axis rotation)
+        // let sinp = T::sqrt(1.0.as_() + 2.0.as_() * (self.scalar * self.vector.y - self.vector.x * self.vector.z));
+        // let cosp = T::sqrt(1.0.as_() - 2.0.as_() * (self.scalar * self.vector.y - self.vector.x * self.vector.z));
+        // let pitch = 2.0.as_() * T::atan2(sinp, cosp) - std::f64::consts::PI.as_() / 2.0.as_();
+// 
+        // // yaw (z-axis rotation)
+        // let siny_cosp = 2.0.as_() * (self.scalar * self.vector.z + self.vector.x * self.vector.y);
+        // let cosy_cosp = 1.0.as_() - 2.0.as_() * (self.vector.y * self.vector.y + self.vector.z * self.vector.z);
+        // let yaw = T::atan2(siny_cosp, cosy_cosp);
+// 
+        // return Vector3::new(roll, pitch, yaw);
+    }
+}
+
+///
+/// These functions are available for all numbers including
+/// floating point.
+/// 
+impl<T: Number> Quaternion<T> {
+    /// the quaternion that represents no rotation.
+    pub fn identity() -> Self {
+        Self { vector: Vector3::new(T::zero(), T::zero(), T::zero()), scalar: T::one() }
+    }
+    pub fn dot(&self, other: &Self) -> T {
+        self.vector.dot(&other.vector) + self.scalar * other.scalar
+    }
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+impl<T: SignedNumber> Quaternion<T> {
+    /// negates the vector part of the quaternion. for unit quaternions
+    /// this is the same as the inverse and represents the opposite rotation.
+    pub fn conjugate(&self) -> Self {
+        Self { vector: -self.vector, scalar: self.scalar }
+    }
+}
+///
+/// These functions are only available for floating point numbers.
+/// 
+impl<T: FloatingPoint> Quaternion<T>
+    where f32: AsPrimitive<T>,
+    f64: AsPrimitive<T> {
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+    pub fn normalize(&self) -> Self {
+        *self / self.magnitude()
+    }
+    /// the quaternion that undoes this quaternion when multiplied with it. if the
+    /// quaternion is already normalized [`Quaternion::conjugate`] is cheaper.
+    pub fn inverse(&self) -> Self {
+        self.conjugate() / self.magnitude_squared()
+    }
+    /// # nlerp
+    /// 
+    /// linearly interpolates between two quaternions and normalizes the result.
+    /// it is cheaper than [`Quaternion::slerp`] but the rotation does not move at a constant speed.
+    /// always takes the shortest path between both rotations.
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() { -*other } else { *other };
+        (*self + (other - *self) * t).normalize()
+    }
+    /// # slerp
+    /// 
+    /// spherical linear interpolation between two unit quaternions, rotating at a
+    /// constant speed along the shortest path between both rotations.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut cos_theta = self.dot(other);
+        let mut other = *other;
+        if cos_theta < T::zero() {
+            other = -other;
+            cos_theta = -cos_theta;
+        }
+        // when the quaternions are almost parallel sin(theta) approaches 0
+        // so linear interpolation is used to avoid dividing by it.
+        if cos_theta > 0.9995.as_() {
+            return (*self + (other - *self) * t).normalize();
+        }
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        *self * a + other * b
+    }
+    /// # look_rotation
+    /// 
+    /// creates the rotation that turns the positive z axis towards forward while keeping
+    /// the positive y axis as close to up as possible. if forward and up are parallel another
+    /// up vector is picked so the result is still a valid rotation.
+    pub fn look_rotation(forward: Vector3<T>, up: Vector3<T>) -> Self {
+        let z = forward.normalize();
+        let mut x = up.cross(z);
+        if x.dot(&x) < 1e-12.as_() {
+            let fallback = if z.x.abs() < 0.9.as_() { Vector3::new(T::one(), T::zero(), T::zero()) } else { Vector3::new(T::zero(), T::one(), T::zero()) };
+            x = z.cross(fallback).cross(z);
+        }
+        let x = x.normalize();
+        let y = z.cross(x);
+        Self::from_matrix3(&Matrix3::from_vec(x, y, z))
+    }
+    /// # from_rotation_arc
+    /// 
+    /// creates the shortest rotation that turns the direction from into the direction to.
+    /// when the directions point in opposite directions there are infinitely many shortest
+    /// rotations, in that case the result is a half turn around an axis perpendicular to from.
+    pub fn from_rotation_arc(from: Vector3<T>, to: Vector3<T>) -> Self {
+        let from = from.normalize();
+        let to = to.normalize();
+        let cos_theta = from.dot(&to);
+        if cos_theta < (-1.0 + 1e-6).as_() {
+            let mut axis = from.cross(Vector3::new(T::one(), T::zero(), T::zero()));
+            if axis.dot(&axis) < 1e-12.as_() {
+                axis = from.cross(Vector3::new(T::zero(), T::one(), T::zero()));
+            }
+            return Self { vector: axis.normalize(), scalar: T::zero() };
+        }
+        // using the unnormalized half way rotation avoids calling any trigonometric functions
+        Self { vector: from.cross(to), scalar: T::one() + cos_theta }.normalize()
+    }
+    /// # swing_twist
+    /// 
+    /// splits the rotation into a twist around the axis and a swing that moves the axis,
+    /// returned as (swing, twist) where `swing * twist` gives back the original rotation.
+    pub fn swing_twist(&self, axis: Vector3<T>) -> (Self, Self) {
+        let axis = axis.normalize();
+        let projection = axis * self.vector.dot(&axis);
+        let twist = Self { vector: projection, scalar: self.scalar };
+        // when the rotation is a half turn perpendicular to the axis there is no twist
+        let twist = if twist.magnitude_squared() < 1e-12.as_() { Self::identity() } else { twist.normalize() };
+        (*self * twist.conjugate(), twist)
+    }
+}
+
+impl<T: Number> From<Quaternion<T>> for Matrix3<T> {
+    fn from(value: Quaternion<T>) -> Self {
+        let x2 = value.vector.x + value.vector.x;
+        let y2 = value.vector.y + value.vector.y;
+        let z2 = value.vector.z + value.vector.z;
+
+        let xx2 = x2 * value.vector.x;
+        let xy2 = x2 * value.vector.y;
+        let xz2 = x2 * value.vector.z;
+
+        let yy2 = y2 * value.vector.y;
+        let yz2 = y2 * value.vector.z;
+        let zz2 = z2 * value.vector.z;
+
+        let sy2 = y2 * value.scalar;
+        let sz2 = z2 * value.scalar;
+        let sx2 = x2 * value.scalar;
+
+        Self { 
+            x: Vector3 { 
+                x: T::one() - yy2 - zz2, 
+                y: xy2 + sz2, 
+                z: xz2 - sy2, 
+            }, 
+            y: Vector3 { 
+                x: xy2 - sz2, 
+                y: T::one() - xx2 - zz2, 
+                z: yz2 + sx2, 
+            }, 
+            z: Vector3 { 
+                x: xz2 + sy2, 
+                y: yz2 - sx2, 
+                z: T::one() - xx2 - yy2, 
+            }, 
+        }
+    }
+}
+// traits for bitwise operations
+
+impl<T: Number> std::ops::Mul<Vector3<T>> for Quaternion<T> {
+    fn mul(self, rhs: Vector3<T>) -> Self::Output {
+        let x2 = self.vector.x + self.vector.x;
+        let y2 = self.vector.y + self.vector.y;
+        let z2 = self.vector.z + self.vector.z;
+        
+        let xx2 = x2 * self.vector.x;
+        let xy2 = x2 * self.vector.y;
+        let xz2 = x2 * self.vector.z;
+
+        let yy2 = y2 * self.vector.y;
+        let yz2 = y2 * self.vector.z;
+        let zz2 = z2 * self.vector.z;
+
+        let sy2 = y2 * self.scalar;
+        let sz2 = z2 * self.scalar;
+        let sx2 = x2 * self.scalar;
+
+        Vector3 {
+            x: (T::one() - (yy2 + zz2)) * rhs.x + (xy2 - sz2) * rhs.y + (xz2 + sy2) * rhs.z,
+            y: (xy2 + sz2) * rhs.x + (T::one() - (xx2 + zz2)) * rhs.y + (yz2 - sx2) * rhs.z,
+            z: (xz2 - sy2) * rhs.x + (yz2 + sx2) * rhs.y + (T::one() - (xx2 + yy2)) * rhs.z,
+        }
+    }
+    type Output = Vector3<T>;
+}
+impl<T: Number> std::ops::Add for Quaternion<T>  {
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { vector: self.vector + rhs.vector, scalar: self.scalar + rhs.scalar }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Sub for Quaternion<T>  {
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { vector: self.vector - rhs.vector, scalar: self.scalar - rhs.scalar }
+    }
+    type Output = Self;
+}
+impl<T: SignedNumber> std::ops::Neg for Quaternion<T>  {
+    fn neg(self) -> Self::Output {
+        Self { vector: -self.vector, scalar: -self.scalar }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Mul<T> for Quaternion<T>  {
+    fn mul(self, rhs: T) -> Self::Output {
+        Self { vector: self.vector * rhs, scalar: self.scalar * rhs }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Div<T> for Quaternion<T>  {
+    fn div(self, rhs: T) -> Self::Output {
+        Self { vector: self.vector / rhs, scalar: self.scalar / rhs }
+    }
+    type Output = Self;
+}
+impl<T: Number> std::ops::Mul for Quaternion<T>  {
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { 
+            vector: Vector3 { 
+                x: self.scalar * rhs.vector.x + self.vector.x * rhs.scalar + self.vector.y * rhs.vector.z - self.vector.z * rhs.vector.y, 
+                y: self.scalar * rhs.vector.y + self.vector.y * rhs.scalar + self.vector.z * rhs.vector.x - self.vector.x * rhs.vector.z, 
+                z: self.scalar * rhs.vector.z + self.vector.z * rhs.scalar + self.vector.x * rhs.vector.y - self.vector.y * rhs.vector.x
+            }, 
+            scalar: self.scalar * rhs.scalar - self.vector.x * rhs.vector.x - self.vector.y * rhs.vector.y - self.vector.z * rhs.vector.z
+        }
+    }
+    type Output = Self;
 }
\ No newline at end of file
@@ -1,4 +1,8 @@
-mod quadratic;
-mod cubic;
-pub use quadratic::*;
-pub use cubic::*;
\ No newline at end of file
+mod quadratic;
+mod cubic;
+mod quartic;
+mod polynomial;
+pub use quadratic::*;
+pub use cubic::*;
+pub use quartic::*;
+pub use polynomial::*;
\ No newline at end of file
@@ -0,0 +1,458 @@
+use std::ops::{Add, Sub, Mul, Neg};
+
+use num_traits::AsPrimitive;
+
+use crate::{complex::Complex, FloatingPoint};
+
+use super::{QuadraticSolution, CubicSolution};
+
+/// # Polynomial
+///
+/// a polynomial of any degree with real coefficients, stored in ascending order
+/// so that `coefficients[i]` is the coefficient of x^i. trailing zero coefficients
+/// are always removed, which makes the zero polynomial an empty list of coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<T: FloatingPoint> {
+    coefficients: Vec<T>,
+}
+
+impl<T: FloatingPoint> Polynomial<T> {
+    /// creates a polynomial from its coefficients in ascending order.
+    pub fn new(coefficients: Vec<T>) -> Self {
+        let mut polynomial = Self { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+    pub fn from_slice(coefficients: &[T]) -> Self {
+        Self::new(coefficients.to_vec())
+    }
+    pub fn zero() -> Self {
+        Self { coefficients: Vec::new() }
+    }
+    pub fn constant(value: T) -> Self {
+        Self::new(vec![value])
+    }
+    /// the polynomial x.
+    pub fn x() -> Self {
+        Self::new(vec![T::zero(), T::one()])
+    }
+    /// creates the polynomial with the given roots and a leading coefficient of 1.
+    pub fn from_roots(roots: &[T]) -> Self {
+        roots.iter().fold(Self::constant(T::one()), |polynomial, root| {
+            polynomial * Self::new(vec![-*root, T::one()])
+        })
+    }
+    /// the coefficients in ascending order.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+    /// the degree of the polynomial. the zero polynomial is treated as having a degree of 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+    /// the coefficient of the highest power of x.
+    pub fn leading_coefficient(&self) -> T {
+        self.coefficients.last().copied().unwrap_or(T::zero())
+    }
+    fn trim(&mut self) {
+        while self.coefficients.last() == Some(&T::zero()) {
+            self.coefficients.pop();
+        }
+    }
+    /// evaluates the polynomial at x using horner's method.
+    pub fn evaluate(&self, x: T) -> T {
+        self.coefficients.iter().rev().fold(T::zero(), |acc, c| acc * x + *c)
+    }
+    /// evaluates the polynomial at a complex x using horner's method.
+    pub fn evaluate_complex(&self, x: Complex<T>) -> Complex<T> {
+        self.coefficients.iter().rev().fold(Complex::new(T::zero(), T::zero()), |acc, c| acc * x + *c)
+    }
+    pub fn derivative(&self) -> Self {
+        let mut power = T::zero();
+        Self::new(self.coefficients.iter().skip(1).map(|c| {
+            power += T::one();
+            *c * power
+        }).collect())
+    }
+    /// the antiderivative of the polynomial where constant is the value at x = 0.
+    pub fn antiderivative(&self, constant: T) -> Self {
+        let mut coefficients = Vec::with_capacity(self.coefficients.len() + 1);
+        coefficients.push(constant);
+        let mut power = T::zero();
+        for c in self.coefficients.iter() {
+            power += T::one();
+            coefficients.push(*c / power);
+        }
+        Self::new(coefficients)
+    }
+    /// the definite integral of the polynomial from a to b.
+    pub fn integrate(&self, a: T, b: T) -> T {
+        let antiderivative = self.antiderivative(T::zero());
+        antiderivative.evaluate(b) - antiderivative.evaluate(a)
+    }
+    /// returns the polynomial self(other(x)).
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coefficients.iter().rev().fold(Self::zero(), |acc, c| &(&acc * other) + &Self::constant(*c))
+    }
+    /// divides the polynomial by divisor returning the quotient and remainder.
+    /// # Panics
+    /// panics when divisor is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "attempted to divide a polynomial by zero");
+        if self.coefficients.len() < divisor.coefficients.len() {
+            return (Self::zero(), self.clone());
+        }
+        let divisor_degree = divisor.degree();
+        let leading = divisor.leading_coefficient();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![T::zero(); remainder.len() - divisor_degree];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + divisor_degree] / leading;
+            quotient[i] = factor;
+            for (j, c) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= factor * *c;
+            }
+            // the leading term is removed exactly instead of leaving rounding errors behind
+            remainder[i + divisor_degree] = T::zero();
+        }
+        (Self::new(quotient), Self::new(remainder))
+    }
+    /// multiplies the polynomial so that its leading coefficient becomes 1.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let leading = self.leading_coefficient();
+        Self::new(self.coefficients.iter().map(|c| *c / leading).collect())
+    }
+    /// the largest absolute coefficient, used to decide when a coefficient is only rounding error.
+    fn max_coefficient(&self) -> T {
+        self.coefficients.iter().fold(T::zero(), |max, c| max.max(c.abs()))
+    }
+    /// all roots lie inside a circle with this radius, based on cauchy's bound.
+    fn root_bound(&self) -> T {
+        let leading = self.leading_coefficient().abs();
+        let max = self.coefficients[..self.degree()].iter().fold(T::zero(), |max, c| max.max(c.abs()));
+        T::one() + max / leading
+    }
+}
+
+impl<T: FloatingPoint> Polynomial<T>
+    where f32: AsPrimitive<T> {
+    /// # sturm_sequence
+    ///
+    /// the sturm sequence p, p', -rem(p, p'), ... used to count the distinct real roots
+    /// inside of an interval. remainders are cleaned of coefficients that are only
+    /// rounding error so repeated roots still end the sequence.
+    pub fn sturm_sequence(&self) -> Vec<Self> {
+        let mut sequence = Vec::new();
+        if self.is_zero() {
+            return sequence;
+        }
+        sequence.push(self.clone());
+        let mut current = self.clone();
+        let mut next = self.derivative();
+        while !next.is_zero() {
+            let (_, remainder) = current.div_rem(&next);
+            let tolerance = T::epsilon() * 64.0.as_() * current.max_coefficient().max(next.max_coefficient());
+            let remainder = -Self::new(remainder.coefficients.iter().map(|c| {
+                if c.abs() <= tolerance { T::zero() } else { *c }
+            }).collect());
+            sequence.push(next.clone());
+            current = next;
+            next = remainder;
+        }
+        sequence
+    }
+    /// counts the distinct real roots in the interval (a, b] using a sturm sequence
+    /// created from [`Polynomial::sturm_sequence`].
+    pub fn count_real_roots(sequence: &[Self], a: T, b: T) -> usize {
+        Self::sign_changes(sequence, a).saturating_sub(Self::sign_changes(sequence, b))
+    }
+    fn sign_changes(sequence: &[Self], x: T) -> usize {
+        let mut changes = 0;
+        let mut last = T::zero();
+        for polynomial in sequence {
+            let value = polynomial.evaluate(x);
+            if value == T::zero() {
+                continue;
+            }
+            if last != T::zero() && (value < T::zero()) != (last < T::zero()) {
+                changes += 1;
+            }
+            last = value;
+        }
+        changes
+    }
+    /// # real_roots
+    ///
+    /// finds every distinct real root of the polynomial sorted in ascending order.
+    /// polynomials of degree 3 or less use [`super::QuadraticFormula`] and [`super::CubicFormula`],
+    /// higher degrees isolate each root with a sturm sequence and refine it with newton's method,
+    /// falling back to bisection whenever a newton step leaves the isolating interval.
+    /// constant polynomials, including the zero polynomial, return no roots.
+    pub fn real_roots(&self) -> Vec<T> {
+        let mut roots = match self.degree() {
+            0 => Vec::new(),
+            1..=3 => self.low_degree_roots().into_iter()
+                .filter(|root| root.im == T::zero())
+                .map(|root| self.polish(root.re))
+                // coefficients that overflow give roots that are not numbers
+                .filter(|root| root.is_finite())
+                .collect(),
+            _ => {
+                let bound = self.root_bound();
+                return self.real_roots_in(-bound, bound);
+            }
+        };
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        roots.dedup_by(|a, b| (*a - *b).abs() <= T::epsilon().sqrt() * a.abs().max(T::one()));
+        roots
+    }
+    /// finds every distinct real root in the interval (a, b] sorted in ascending order.
+    pub fn real_roots_in(&self, a: T, b: T) -> Vec<T> {
+        let mut roots = Vec::new();
+        if self.degree() == 0 || a >= b {
+            return roots;
+        }
+        let sequence = self.sturm_sequence();
+        let count = Self::count_real_roots(&sequence, a, b);
+        self.isolate(&sequence, a, b, count, &mut roots);
+        roots
+    }
+    fn isolate(&self, sequence: &[Self], a: T, b: T, count: usize, roots: &mut Vec<T>) {
+        if count == 0 {
+            return;
+        }
+        let width = b - a;
+        // roots that are too close together to be separated are returned as one root
+        if count == 1 || width <= T::epsilon() * a.abs().max(b.abs()).max(T::one()) {
+            roots.push(self.refine(sequence, a, b));
+            return;
+        }
+        let middle = a + width * 0.5.as_();
+        let left = Self::count_real_roots(sequence, a, middle);
+        self.isolate(sequence, a, middle, left, roots);
+        self.isolate(sequence, middle, b, count.saturating_sub(left), roots);
+    }
+    /// refines the single root inside of (a, b].
+    fn refine(&self, sequence: &[Self], mut a: T, mut b: T) -> T {
+        let derivative = self.derivative();
+        let mut fa = self.evaluate(a);
+        let fb = self.evaluate(b);
+        if fb == T::zero() {
+            return b;
+        }
+        for _ in 0..200 {
+            let tolerance = T::epsilon() * a.abs().max(b.abs()).max(T::one());
+            if b - a <= tolerance {
+                break;
+            }
+            let middle = a + (b - a) * 0.5.as_();
+            if fa != T::zero() && (fa < T::zero()) != (fb < T::zero()) {
+                // the root changes sign so newton's method can be safeguarded with bisection
+                let mut x = middle;
+                let mut last_step = b - a;
+                for _ in 0..200 {
+                    let fx = self.evaluate(x);
+                    if fx == T::zero() {
+                        return x;
+                    }
+                    if (fx < T::zero()) == (fa < T::zero()) { a = x; fa = fx; } else { b = x; }
+                    let df = derivative.evaluate(x);
+                    let step = fx / df;
+                    let newton = x - step;
+                    // newton's method is only trusted when it stays inside the interval and
+                    // converges at least as fast as bisection would
+                    let next = if df != T::zero() && newton > a && newton < b && step.abs() * 2.0.as_() <= last_step.abs() {
+                        last_step = step;
+                        newton
+                    } else {
+                        last_step = (b - a) * 0.5.as_();
+                        a + last_step
+                    };
+                    if (next - x).abs() <= T::epsilon() * next.abs().max(T::one()) || b - a <= tolerance {
+                        return next;
+                    }
+                    x = next;
+                }
+                return x;
+            }
+            // roots of even multiplicity touch zero without changing sign,
+            // so the interval is halved by counting roots instead
+            if Self::count_real_roots(sequence, a, middle) > 0 {
+                b = middle;
+            } else {
+                a = middle;
+                fa = self.evaluate(a);
+            }
+        }
+        a + (b - a) * 0.5.as_()
+    }
+    /// improves a root found with a closed form formula using newton's method.
+    fn polish(&self, mut x: T) -> T {
+        let derivative = self.derivative();
+        let mut fx = self.evaluate(x);
+        for _ in 0..4 {
+            let df = derivative.evaluate(x);
+            if df == T::zero() { break; }
+            let next = x - fx / df;
+            let f_next = self.evaluate(next);
+            if f_next.abs() >= fx.abs() { break; }
+            x = next;
+            fx = f_next;
+        }
+        x
+    }
+    /// # complex_roots
+    ///
+    /// finds all roots of the polynomial including complex ones. repeated roots are returned
+    /// once for every time they repeat so the amount of roots always equals the degree.
+    /// polynomials of degree 3 or less use [`super::QuadraticFormula`] and [`super::CubicFormula`]
+    /// while higher degrees use the [Durand–Kerner method](https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method).
+    pub fn complex_roots(&self) -> Vec<Complex<T>> {
+        match self.degree() {
+            0 => Vec::new(),
+            1..=3 => self.low_degree_roots(),
+            _ => self.durand_kerner(),
+        }
+    }
+    fn low_degree_roots(&self) -> Vec<Complex<T>> {
+        let real = |x: T| Complex::new(x, T::zero());
+        let c = &self.coefficients;
+        match self.degree() {
+            1 => vec![real(-c[0] / c[1])],
+            2 => match T::quadratic_formula(c[2], c[1], c[0]) {
+                QuadraticSolution::OneReal(r) => vec![real(r), real(r)],
+                QuadraticSolution::TwoReal(r) => r.iter().map(|r| real(*r)).collect(),
+                QuadraticSolution::TwoComplex(r) => r.to_vec(),
+                // unreachable since the leading coefficient is never 0
+                QuadraticSolution::None(_) | QuadraticSolution::All(_) => Vec::new(),
+            },
+            3 => match T::cubic_formula(c[3], c[2], c[1], c[0]) {
+                CubicSolution::OneReal(r) => vec![real(r); 3],
+                // the second root is the one that repeats
+                CubicSolution::TwoReal([r0, r1]) => vec![real(r0), real(r1), real(r1)],
+                CubicSolution::ThreeReal(r) => r.iter().map(|r| real(*r)).collect(),
+                CubicSolution::OneRealTwoComplex(r, [c0, c1]) => vec![real(r), c0, c1],
+                CubicSolution::TwoComplex(r) => r.to_vec(),
+                CubicSolution::None(_) | CubicSolution::All(_) => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+    fn durand_kerner(&self) -> Vec<Complex<T>> {
+        let monic = self.monic();
+        let degree = monic.degree();
+        let radius = monic.root_bound();
+        // the starting guesses are spread along a spiral since they must not be symmetric
+        let seed = Complex::new(0.4.as_(), 0.9.as_());
+        let mut roots: Vec<Complex<T>> = Vec::with_capacity(degree);
+        let mut guess = Complex::new(T::one(), T::zero());
+        for _ in 0..degree {
+            roots.push(guess * radius);
+            guess = guess * seed;
+        }
+        for _ in 0..1000 {
+            let mut change = T::zero();
+            for i in 0..degree {
+                let root = roots[i];
+                let mut denominator = Complex::new(T::one(), T::zero());
+                for (j, other) in roots.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator * (root - *other);
+                    }
+                }
+                if denominator.norm_sqr() == T::zero() {
+                    continue;
+                }
+                let step = monic.evaluate_complex(root) / denominator;
+                roots[i] = root - step;
+                change = change.max(step.norm() / root.norm().max(T::one()));
+            }
+            if change <= T::epsilon() * 4.0.as_() {
+                break;
+            }
+        }
+        // roots whose imaginary part is only rounding error are made real
+        let tolerance = T::epsilon().sqrt();
+        for root in roots.iter_mut() {
+            if root.im.abs() <= tolerance * root.re.abs().max(T::one()) {
+                *root = Complex::new(root.re, T::zero());
+            }
+        }
+        roots
+    }
+}
+
+impl<T: FloatingPoint> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new((0..len).map(|i| {
+            self.coefficients.get(i).copied().unwrap_or(T::zero()) + rhs.coefficients.get(i).copied().unwrap_or(T::zero())
+        }).collect())
+    }
+}
+impl<T: FloatingPoint> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new((0..len).map(|i| {
+            self.coefficients.get(i).copied().unwrap_or(T::zero()) - rhs.coefficients.get(i).copied().unwrap_or(T::zero())
+        }).collect())
+    }
+}
+impl<T: FloatingPoint> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut coefficients = vec![T::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] += *a * *b;
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+impl<T: FloatingPoint> Add for Polynomial<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+impl<T: FloatingPoint> Sub for Polynomial<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+impl<T: FloatingPoint> Mul for Polynomial<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+impl<T: FloatingPoint> Mul<T> for Polynomial<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.coefficients.iter().map(|c| *c * rhs).collect())
+    }
+}
+impl<T: FloatingPoint> Neg for Polynomial<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { coefficients: self.coefficients.iter().map(|c| -*c).collect() }
+    }
+}
+impl<T: FloatingPoint> From<Vec<T>> for Polynomial<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::new(value)
+    }
+}
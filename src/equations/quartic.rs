@@ -0,0 +1,193 @@
+use num_traits::AsPrimitive;
+
+use crate::{Number, complex::Complex, FloatingPoint};
+
+use super::{CubicFormula, CubicSolution};
+/// Contains all values in CubicSolution but extended to handle quartics.
+/// Since the coefficients are real, complex solutions always come in conjugate pairs.
+#[derive(Debug)]
+pub enum QuarticSolution<T: Number> {
+    None(T),
+    All(T),
+    // Used when the solution is a cubic or quadratic or when a quartic has repeating solutions
+    OneReal(T),
+    TwoReal([T; 2]),
+    ThreeReal([T; 3]),
+    FourReal([T; 4]),
+    TwoComplex([Complex<T>; 2]),
+    OneRealTwoComplex(T, [Complex<T>; 2]),
+    TwoRealTwoComplex([T; 2], [Complex<T>; 2]),
+    FourComplex([Complex<T>; 4]),
+}
+pub trait QuarticFormula: CubicFormula {
+    /// used to solve the equation ax^4 + bx^3 + cx^2 + dx + e using Ferrari's method.
+    /// when a is 0 the equation is solved as a cubic instead. real solutions that
+    /// repeat are only returned once, similar to [`CubicSolution::TwoReal`].
+    /// for more information on Ferrari's method check [this wikipedia article](https://en.wikipedia.org/wiki/Quartic_equation#Ferrari's_solution).
+    fn quartic_formula(a: Self, b: Self, c: Self, d: Self, e: Self) -> QuarticSolution<Self>;
+}
+
+impl QuarticFormula for f32 {
+    fn quartic_formula(a: Self, b: Self, c: Self, d: Self, e: Self) -> QuarticSolution<Self> {
+        solve_quartic(a, b, c, d, e)
+    }
+}
+impl QuarticFormula for f64 {
+    fn quartic_formula(a: Self, b: Self, c: Self, d: Self, e: Self) -> QuarticSolution<Self> {
+        solve_quartic(a, b, c, d, e)
+    }
+}
+
+fn solve_quartic<T: FloatingPoint>(a: T, b: T, c: T, d: T, e: T) -> QuarticSolution<T>
+    where f32: AsPrimitive<T>,
+    f64: AsPrimitive<T> {
+    // if a equals 0 the quartic becomes a cubic
+    if a == T::zero() {
+        return match T::cubic_formula(b, c, d, e) {
+            CubicSolution::None(n) => QuarticSolution::None(n),
+            CubicSolution::All(n) => QuarticSolution::All(n),
+            CubicSolution::OneReal(r) => QuarticSolution::OneReal(r),
+            CubicSolution::TwoReal(r) => QuarticSolution::TwoReal(r),
+            CubicSolution::TwoComplex(c) => QuarticSolution::TwoComplex(c),
+            CubicSolution::ThreeReal(r) => QuarticSolution::ThreeReal(r),
+            CubicSolution::OneRealTwoComplex(r, c) => QuarticSolution::OneRealTwoComplex(r, c),
+        };
+    }
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+    // substituting x = y - b/4 removes the cubic term giving the depressed quartic
+    // y^4 + py^2 + qy + r
+    let shift = b * 0.25.as_();
+    let b2 = b * b;
+    let p = c - 0.375.as_() * b2;
+    let q = d - 0.5.as_() * b * c + 0.125.as_() * b2 * b;
+    let r = e - 0.25.as_() * b * d + 0.0625.as_() * b2 * c - (3.0 / 256.0).as_() * b2 * b2;
+
+    // the magnitude of the roots, used to decide when q is small enough to ignore
+    let scale = p.abs().sqrt().max(r.abs().sqrt().sqrt()).max(T::one());
+    let roots = if q.abs() <= T::epsilon() * 16.0.as_() * scale * scale * scale {
+        // biquadratic, solve z^2 + pz + r and take the square roots of z
+        let [z0, z1] = complex_quadratic(p, r);
+        let (y0, y1) = (z0.sqrt(), z1.sqrt());
+        [y0, -y0, y1, -y1]
+    } else {
+        // find m so the right hand side of (y^2 + p/2 + m)^2 = 2my^2 - qy + m^2 + mp + p^2/4 - r
+        // becomes a perfect square, which happens at the roots of the resolvent cubic
+        // 8m^3 + 8pm^2 + (2p^2 - 8r)m - q^2. it always has a positive real root since q != 0.
+        let (ra, rb, rc, rd): (T, T, T, T) = (8.0.as_(), 8.0.as_() * p, 2.0.as_() * p * p - 8.0.as_() * r, -q * q);
+        let mut m = match T::cubic_formula(ra, rb, rc, rd) {
+            CubicSolution::OneReal(m) | CubicSolution::OneRealTwoComplex(m, _) => m,
+            CubicSolution::TwoReal([m0, m1]) => m0.max(m1),
+            CubicSolution::ThreeReal([m0, m1, m2]) => m0.max(m1).max(m2),
+            _ => T::zero(),
+        };
+        // the cubic formula loses precision with repeated roots so the root is polished
+        for _ in 0..4 {
+            let f = ((ra * m + rb) * m + rc) * m + rd;
+            let df = (3.0.as_() * ra * m + 2.0.as_() * rb) * m + rc;
+            if df == T::zero() { break; }
+            m -= f / df;
+        }
+        let m = m.max(T::min_positive_value());
+        let s = (m * 2.0.as_()).sqrt();
+        let half_p = p * 0.5.as_();
+        let ratio = q / (2.0.as_() * s);
+        let [y0, y1] = complex_quadratic(-s, half_p + m + ratio);
+        let [y2, y3] = complex_quadratic(s, half_p + m - ratio);
+        [y0, y1, y2, y3]
+    };
+
+    let tolerance = T::epsilon().sqrt();
+    let mut reals: [T; 4] = [T::zero(); 4];
+    let mut real_count = 0;
+    let mut complex: [Complex<T>; 4] = [Complex::new(T::zero(), T::zero()); 4];
+    let mut complex_count = 0;
+    for root in roots {
+        let x = root - shift;
+        if x.im.abs() <= tolerance * x.re.abs().max(T::one()) {
+            let x = polish_root(x.re, b, c, d, e);
+            if reals[..real_count].iter().all(|r| (*r - x).abs() > tolerance * x.abs().max(T::one())) {
+                reals[real_count] = x;
+                real_count += 1;
+            }
+        } else {
+            complex[complex_count] = polish_complex_root(x, b, c, d, e);
+            complex_count += 1;
+        }
+    }
+    // complex roots come in conjugate pairs, but a pair lying right at the tolerance can be split
+    // by rounding. the root without a partner is then the one closest to the real axis.
+    if complex_count % 2 == 1 {
+        let mut closest = 0;
+        for i in 1..complex_count {
+            if complex[i].im.abs() < complex[closest].im.abs() {
+                closest = i;
+            }
+        }
+        let x = polish_root(complex[closest].re, b, c, d, e);
+        complex.copy_within(closest + 1..complex_count, closest);
+        complex_count -= 1;
+        if reals[..real_count].iter().all(|r| (*r - x).abs() > tolerance * x.abs().max(T::one())) {
+            reals[real_count] = x;
+            real_count += 1;
+        }
+    }
+    match (real_count, complex_count) {
+        (1, 0) => QuarticSolution::OneReal(reals[0]),
+        (2, 0) => QuarticSolution::TwoReal([reals[0], reals[1]]),
+        (3, 0) => QuarticSolution::ThreeReal([reals[0], reals[1], reals[2]]),
+        (4, 0) => QuarticSolution::FourReal(reals),
+        (0, 2) => QuarticSolution::TwoComplex([complex[0], complex[1]]),
+        (1, 2) => QuarticSolution::OneRealTwoComplex(reals[0], [complex[0], complex[1]]),
+        (2, 2) => QuarticSolution::TwoRealTwoComplex([reals[0], reals[1]], [complex[0], complex[1]]),
+        (0, 4) => QuarticSolution::FourComplex(complex),
+        // every root is either real or part of a complex pair and at least one real root
+        // is kept when any of them are real
+        (real_count, complex_count) => unreachable!("{} real and {} complex roots of a quartic", real_count, complex_count),
+    }
+}
+
+/// solves y^2 + by + c allowing complex solutions.
+fn complex_quadratic<T: FloatingPoint>(b: T, c: T) -> [Complex<T>; 2]
+    where f32: AsPrimitive<T> {
+    let half_b = b * 0.5.as_();
+    let root = Complex::new(half_b * half_b - c, T::zero()).sqrt();
+    // avoids cancellation by never subtracting numbers with the same sign
+    let y0 = if half_b > T::zero() { -root - half_b } else { root - half_b };
+    let y1 = if y0.norm_sqr() == T::zero() { y0 } else { Complex::new(c, T::zero()) / y0 };
+    [y0, y1]
+}
+
+/// improves a real root of x^4 + bx^3 + cx^2 + dx + e with a few newton iterations.
+fn polish_root<T: FloatingPoint>(mut x: T, b: T, c: T, d: T, e: T) -> T
+    where f32: AsPrimitive<T> {
+    let evaluate = |x: T| (((x + b) * x + c) * x + d) * x + e;
+    let mut fx = evaluate(x);
+    for _ in 0..4 {
+        let df = ((4.0.as_() * x + 3.0.as_() * b) * x + 2.0.as_() * c) * x + d;
+        if df == T::zero() { break; }
+        let next = x - fx / df;
+        let f_next = evaluate(next);
+        if f_next.abs() >= fx.abs() { break; }
+        x = next;
+        fx = f_next;
+    }
+    x
+}
+
+/// the same as [`polish_root`] but for complex roots, which lose precision
+/// when the roots of the quartic differ greatly in magnitude.
+fn polish_complex_root<T: FloatingPoint>(mut x: Complex<T>, b: T, c: T, d: T, e: T) -> Complex<T>
+    where f32: AsPrimitive<T> {
+    let evaluate = |x: Complex<T>| (((x + b) * x + c) * x + d) * x + e;
+    let mut fx = evaluate(x);
+    for _ in 0..4 {
+        let df = ((x * 4.0.as_() + 3.0.as_() * b) * x + 2.0.as_() * c) * x + d;
+        if df.norm_sqr() == T::zero() { break; }
+        let next = x - fx / df;
+        let f_next = evaluate(next);
+        if f_next.norm_sqr() >= fx.norm_sqr() { break; }
+        x = next;
+        fx = f_next;
+    }
+    x
+}
@@ -0,0 +1,480 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, Shape, FloatingPoint};
+use super::{FontError, reader::Reader, outline::OutlineBuilder};
+
+const TAG: [u8; 4] = *b"CFF ";
+/// the most numbers the stack of a type 2 charstring can hold.
+const MAX_STACK: usize = 48;
+/// how deep subroutines can call each other.
+const MAX_SUBR_DEPTH: usize = 10;
+
+const CHAR_STRINGS: u16 = 17;
+const PRIVATE: u16 = 18;
+const SUBRS: u16 = 19;
+const CHARSTRING_TYPE: u16 = 1206;
+const FD_ARRAY: u16 = 1236;
+const FD_SELECT: u16 = 1237;
+
+/// an array of byte strings, which is how cff stores every list of things.
+#[derive(Clone, Copy, Debug, Default)]
+struct Index<'a> {
+    count: usize,
+    offset_size: u8,
+    offsets: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> Index<'a> {
+    fn parse(reader: &mut Reader<'a>) -> Result<Self, FontError> {
+        let count = reader.u16()? as usize;
+        if count == 0 {
+            return Ok(Self::default());
+        }
+        let offset_size = reader.u8()?;
+        if !(1..=4).contains(&offset_size) {
+            return Err(FontError::UnsupportedTable(TAG));
+        }
+        let offsets = reader.bytes((count + 1) * offset_size as usize)?;
+        let mut index = Self { count, offset_size, offsets, data: &[] };
+        // the offsets start at 1, the last one being one past the end of the data
+        let len = index.offset(count)?.checked_sub(1).ok_or(FontError::UnsupportedTable(TAG))?;
+        index.data = reader.bytes(len)?;
+        Ok(index)
+    }
+    fn offset(&self, i: usize) -> Result<usize, FontError> {
+        let mut reader = Reader::at(self.offsets, i * self.offset_size as usize)?;
+        Ok(reader.offset_of_size(self.offset_size)? as usize)
+    }
+    fn get(&self, i: usize) -> Option<&'a [u8]> {
+        if i >= self.count {
+            return None;
+        }
+        let (start, end) = (self.offset(i).ok()?, self.offset(i + 1).ok()?);
+        self.data.get(start.checked_sub(1)?..end.checked_sub(1)?)
+    }
+}
+
+/// the numbers of a dict that are followed by an operator, with the escaped operators 12 x stored as 1200 + x.
+fn parse_dict(data: &[u8]) -> Result<Vec<(u16, Vec<f64>)>, FontError> {
+    let mut reader = Reader::new(data);
+    let mut entries = Vec::new();
+    let mut operands = Vec::new();
+    while !reader.is_empty() {
+        let b0 = reader.u8()?;
+        match b0 {
+            0..=11 | 13..=21 => entries.push((b0 as u16, std::mem::take(&mut operands))),
+            12 => entries.push((1200 + reader.u8()? as u16, std::mem::take(&mut operands))),
+            28 => operands.push(reader.i16()? as f64),
+            29 => operands.push(reader.i32()? as f64),
+            30 => operands.push(parse_real(&mut reader)?),
+            32..=254 => operands.push(parse_small_number(b0, &mut reader)?),
+            _ => return Err(FontError::UnsupportedTable(TAG)),
+        }
+    }
+    Ok(entries)
+}
+/// the numbers stored in one to three bytes, shared by dicts and charstrings.
+fn parse_small_number(b0: u8, reader: &mut Reader) -> Result<f64, FontError> {
+    Ok(match b0 {
+        32..=246 => b0 as f64 - 139.0,
+        247..=250 => (b0 as f64 - 247.0) * 256.0 + reader.u8()? as f64 + 108.0,
+        _ => -(b0 as f64 - 251.0) * 256.0 - reader.u8()? as f64 - 108.0,
+    })
+}
+/// a real number written out as nibbles of digits, a point, an exponent and a minus sign.
+fn parse_real(reader: &mut Reader) -> Result<f64, FontError> {
+    let mut text = String::new();
+    loop {
+        let byte = reader.u8()?;
+        for nibble in [byte >> 4, byte & 0xF] {
+            match nibble {
+                0..=9 => text.push((b'0' + nibble) as char),
+                0xA => text.push('.'),
+                0xB => text.push('E'),
+                0xC => text.push_str("E-"),
+                0xE => text.push('-'),
+                0xF => return text.parse().map_err(|_| FontError::UnsupportedTable(TAG)),
+                _ => return Err(FontError::UnsupportedTable(TAG)),
+            }
+        }
+    }
+}
+fn dict_value(dict: &[(u16, Vec<f64>)], operator: u16) -> Option<&[f64]> {
+    dict.iter().find(|(op, _)| *op == operator).map(|(_, operands)| &operands[..])
+}
+/// an offset or size in a dict, which has to be a whole number of bytes that fits in 32 bits.
+fn dict_usize(value: f64) -> Result<usize, FontError> {
+    if (0.0..=u32::MAX as f64).contains(&value) {
+        Ok(value as usize)
+    } else {
+        Err(FontError::UnexpectedEnd)
+    }
+}
+fn dict_offset(dict: &[(u16, Vec<f64>)], operator: u16) -> Result<Option<usize>, FontError> {
+    dict_value(dict, operator).and_then(|operands| operands.first()).map(|&offset| dict_usize(offset)).transpose()
+}
+/// the local subroutines of a private dict, which are found at an offset from the start of the dict.
+fn private_subrs<'a>(cff: &'a [u8], private: &[f64]) -> Result<Index<'a>, FontError> {
+    let [size, offset] = *private else {
+        return Err(FontError::UnsupportedTable(TAG));
+    };
+    let (size, offset) = (dict_usize(size)?, dict_usize(offset)?);
+    let end = offset.checked_add(size).ok_or(FontError::UnexpectedEnd)?;
+    let dict = parse_dict(cff.get(offset..end).ok_or(FontError::UnexpectedEnd)?)?;
+    match dict_offset(&dict, SUBRS)? {
+        Some(subrs) => Index::parse(&mut Reader::at(cff, offset.checked_add(subrs).ok_or(FontError::UnexpectedEnd)?)?),
+        None => Ok(Index::default()),
+    }
+}
+
+/// the local subroutines of a font, cid keyed fonts pick a private dict for every glyph.
+#[derive(Clone, Debug)]
+enum LocalSubrs<'a> {
+    Single(Index<'a>),
+    Cid { fd_select: &'a [u8], subrs: Vec<Index<'a>> },
+}
+
+/// # Cff
+///
+/// the cubic outlines of a compact font format table, made of type 2 charstrings.
+#[derive(Clone, Debug)]
+pub(crate) struct Cff<'a> {
+    char_strings: Index<'a>,
+    global_subrs: Index<'a>,
+    local_subrs: LocalSubrs<'a>,
+}
+
+impl<'a> Cff<'a> {
+    pub fn parse(cff: &'a [u8]) -> Result<Self, FontError> {
+        let mut reader = Reader::new(cff);
+        let major = reader.u8()?;
+        reader.skip(1)?;
+        let header_size = reader.u8()?;
+        if major != 1 {
+            return Err(FontError::UnsupportedTable(TAG));
+        }
+        let mut reader = Reader::at(cff, header_size as usize)?;
+        let _names = Index::parse(&mut reader)?;
+        let top_dicts = Index::parse(&mut reader)?;
+        let _strings = Index::parse(&mut reader)?;
+        let global_subrs = Index::parse(&mut reader)?;
+        // only the first font of the set is read, which is the only one an opentype font can have
+        let top = parse_dict(top_dicts.get(0).ok_or(FontError::UnsupportedTable(TAG))?)?;
+        if dict_value(&top, CHARSTRING_TYPE).is_some_and(|value| value != [2.0]) {
+            return Err(FontError::UnsupportedTable(TAG));
+        }
+        let char_strings = dict_offset(&top, CHAR_STRINGS)?.ok_or(FontError::UnsupportedTable(TAG))?;
+        let char_strings = Index::parse(&mut Reader::at(cff, char_strings)?)?;
+        let local_subrs = match (dict_offset(&top, FD_ARRAY)?, dict_offset(&top, FD_SELECT)?) {
+            (Some(fd_array), Some(fd_select)) => {
+                let fonts = Index::parse(&mut Reader::at(cff, fd_array)?)?;
+                let subrs = (0..fonts.count).map(|i| {
+                    let dict = parse_dict(fonts.get(i).ok_or(FontError::UnexpectedEnd)?)?;
+                    dict_value(&dict, PRIVATE).map_or(Ok(Index::default()), |private| private_subrs(cff, private))
+                }).collect::<Result<_, _>>()?;
+                LocalSubrs::Cid { fd_select: cff.get(fd_select..).ok_or(FontError::UnexpectedEnd)?, subrs }
+            }
+            _ => LocalSubrs::Single(dict_value(&top, PRIVATE).map_or(Ok(Index::default()), |private| private_subrs(cff, private))?),
+        };
+        Ok(Self { char_strings, global_subrs, local_subrs })
+    }
+    pub fn glyph_count(&self) -> usize {
+        self.char_strings.count
+    }
+    /// which font dict of a cid keyed font the glyph uses, from format 0 or 3 of the fd select.
+    fn font_dict(fd_select: &[u8], glyph: u16) -> Result<usize, FontError> {
+        let mut reader = Reader::new(fd_select);
+        match reader.u8()? {
+            0 => Ok(Reader::at(fd_select, 1 + glyph as usize)?.u8()? as usize),
+            3 => {
+                let ranges = reader.u16()?;
+                let mut first = reader.u16()?;
+                for _ in 0..ranges {
+                    let font = reader.u8()?;
+                    let next = reader.u16()?;
+                    if (first..next).contains(&glyph) {
+                        return Ok(font as usize);
+                    }
+                    first = next;
+                }
+                Err(FontError::MalformedGlyph(glyph))
+            }
+            _ => Err(FontError::UnsupportedTable(TAG)),
+        }
+    }
+    /// # outline
+    ///
+    /// the contours of the glyph from running its charstring. the width at the start of the charstring
+    /// is skipped as the advance comes from the horizontal metrics, and the accents of the deprecated
+    /// seac form of endchar aren't drawn.
+    pub fn outline<T: FloatingPoint>(&self, glyph: u16) -> Result<Shape<T>, FontError>
+        where f64: AsPrimitive<T> {
+        let char_string = self.char_strings.get(glyph as usize).ok_or(FontError::GlyphOutOfRange(glyph))?;
+        let local = match &self.local_subrs {
+            LocalSubrs::Single(subrs) => *subrs,
+            LocalSubrs::Cid { fd_select, subrs } => {
+                *subrs.get(Self::font_dict(fd_select, glyph)?).ok_or(FontError::MalformedGlyph(glyph))?
+            }
+        };
+        let mut interpreter = Interpreter {
+            global: self.global_subrs,
+            local,
+            builder: OutlineBuilder::new(),
+            stack: Vec::with_capacity(MAX_STACK),
+            position: Vector2::new(0.0, 0.0),
+            stems: 0,
+            width_parsed: false,
+            ended: false,
+        };
+        interpreter.run(char_string, 0).map_err(|error| match error {
+            FontError::UnexpectedEnd => FontError::MalformedGlyph(glyph),
+            error => error,
+        })?;
+        Ok(interpreter.builder.finish())
+    }
+}
+
+/// subroutine numbers are stored minus a bias that depends on how many there are, which lets
+/// small numbers reach more of them.
+fn subr_bias(count: usize) -> i32 {
+    match count {
+        0..=1239 => 107,
+        1240..=33899 => 1131,
+        _ => 32768,
+    }
+}
+
+/// runs a type 2 charstring, see Adobe's technical note 5177.
+struct Interpreter<'a, T: FloatingPoint> {
+    global: Index<'a>,
+    local: Index<'a>,
+    builder: OutlineBuilder<T>,
+    stack: Vec<f64>,
+    position: Vector2<f64>,
+    /// how many stem hints there are, which decides how long a hint mask is.
+    stems: usize,
+    width_parsed: bool,
+    ended: bool,
+}
+
+impl<'a, T: FloatingPoint> Interpreter<'a, T>
+    where f64: AsPrimitive<T> {
+    /// a charstring that can't be run, which [`Cff::outline`] reports as a malformed glyph the same
+    /// as one that ends too early.
+    fn malformed() -> FontError {
+        FontError::UnexpectedEnd
+    }
+    /// the first operator that clears the stack can have the width of the glyph in front of its
+    /// arguments, which it has when there's one more argument than it needs.
+    fn skip_width(&mut self, has_width: bool) {
+        if !self.width_parsed && has_width && !self.stack.is_empty() {
+            self.stack.remove(0);
+        }
+        self.width_parsed = true;
+    }
+    fn line(&mut self, dx: f64, dy: f64) {
+        self.position += Vector2::new(dx, dy);
+        self.builder.line_to(self.position);
+    }
+    fn curve(&mut self, d: [f64; 6]) {
+        let first = self.position + Vector2::new(d[0], d[1]);
+        let second = first + Vector2::new(d[2], d[3]);
+        self.position = second + Vector2::new(d[4], d[5]);
+        self.builder.curve_to(first, second, self.position);
+    }
+    fn move_to(&mut self, dx: f64, dy: f64) {
+        self.position += Vector2::new(dx, dy);
+        self.builder.move_to(self.position);
+    }
+    /// hlineto and vlineto, which alternate between horizontal and vertical lines.
+    fn alternating_lines(&mut self, mut horizontal: bool) {
+        for i in 0..self.stack.len() {
+            let d = self.stack[i];
+            if horizontal { self.line(d, 0.0) } else { self.line(0.0, d) }
+            horizontal = !horizontal;
+        }
+    }
+    /// hvcurveto and vhcurveto, curves which alternate between starting horizontally and ending
+    /// vertically and the other way around. the last curve can have one more argument for the
+    /// direction it would otherwise keep.
+    fn alternating_curves(&mut self, mut horizontal: bool) -> Result<(), FontError> {
+        let args = std::mem::take(&mut self.stack);
+        if args.len() < 4 {
+            return Err(Self::malformed());
+        }
+        let mut i = 0;
+        while i + 4 <= args.len() {
+            let last = if args.len() - i == 5 { args[i + 4] } else { 0.0 };
+            let a = &args[i..i + 4];
+            if horizontal {
+                self.curve([a[0], 0.0, a[1], a[2], last, a[3]]);
+            } else {
+                self.curve([0.0, a[0], a[1], a[2], a[3], last]);
+            }
+            horizontal = !horizontal;
+            i += 4;
+        }
+        Ok(())
+    }
+    fn run(&mut self, char_string: &[u8], depth: usize) -> Result<(), FontError> {
+        if depth > MAX_SUBR_DEPTH {
+            return Err(Self::malformed());
+        }
+        let mut reader = Reader::new(char_string);
+        while !reader.is_empty() && !self.ended {
+            let b0 = reader.u8()?;
+            match b0 {
+                28 => self.push(reader.i16()? as f64)?,
+                32..=254 => self.push(parse_small_number(b0, &mut reader)?)?,
+                255 => self.push(reader.i32()? as f64 / 65536.0)?,
+                10 | 29 => {
+                    let subrs = if b0 == 10 { self.local } else { self.global };
+                    let index = self.stack.pop().ok_or_else(Self::malformed)? as i32 + subr_bias(subrs.count);
+                    let subr = usize::try_from(index).ok().and_then(|i| subrs.get(i)).ok_or_else(Self::malformed)?;
+                    self.run(subr, depth + 1)?;
+                    continue;
+                }
+                11 => return Ok(()),
+                12 => self.escaped(reader.u8()?)?,
+                _ => self.operator(b0, &mut reader)?,
+            }
+            if !matches!(b0, 28 | 32..=255) {
+                self.stack.clear();
+            }
+        }
+        Ok(())
+    }
+    fn push(&mut self, value: f64) -> Result<(), FontError> {
+        if self.stack.len() >= MAX_STACK {
+            return Err(Self::malformed());
+        }
+        self.stack.push(value);
+        Ok(())
+    }
+    fn operator(&mut self, op: u8, reader: &mut Reader) -> Result<(), FontError> {
+        let len = self.stack.len();
+        match op {
+            // hstem, vstem, hstemhm and vstemhm only count the hints
+            1 | 3 | 18 | 23 => {
+                self.skip_width(len % 2 == 1);
+                self.stems += self.stack.len() / 2;
+            }
+            // hintmask and cntrmask, arguments in front of them are vertical stems
+            19 | 20 => {
+                self.skip_width(len % 2 == 1);
+                self.stems += self.stack.len() / 2;
+                reader.skip(self.stems.div_ceil(8))?;
+            }
+            21 => {
+                self.skip_width(len > 2);
+                let [dx, dy] = self.args()?;
+                self.move_to(dx, dy);
+            }
+            22 => {
+                self.skip_width(len > 1);
+                let [dx] = self.args()?;
+                self.move_to(dx, 0.0);
+            }
+            4 => {
+                self.skip_width(len > 1);
+                let [dy] = self.args()?;
+                self.move_to(0.0, dy);
+            }
+            5 => {
+                for pair in std::mem::take(&mut self.stack).chunks_exact(2) {
+                    self.line(pair[0], pair[1]);
+                }
+            }
+            6 | 7 => self.alternating_lines(op == 6),
+            8 => {
+                for d in std::mem::take(&mut self.stack).chunks_exact(6) {
+                    self.curve([d[0], d[1], d[2], d[3], d[4], d[5]]);
+                }
+            }
+            // rcurveline, curves followed by a line
+            24 => {
+                let args = std::mem::take(&mut self.stack);
+                if args.len() < 8 {
+                    return Err(Self::malformed());
+                }
+                let (curves, line) = args.split_at(args.len() - 2);
+                for d in curves.chunks_exact(6) {
+                    self.curve([d[0], d[1], d[2], d[3], d[4], d[5]]);
+                }
+                self.line(line[0], line[1]);
+            }
+            // rlinecurve, lines followed by a curve
+            25 => {
+                let args = std::mem::take(&mut self.stack);
+                if args.len() < 8 {
+                    return Err(Self::malformed());
+                }
+                let (lines, d) = args.split_at(args.len() - 6);
+                for pair in lines.chunks_exact(2) {
+                    self.line(pair[0], pair[1]);
+                }
+                self.curve([d[0], d[1], d[2], d[3], d[4], d[5]]);
+            }
+            // vvcurveto and hhcurveto, curves that start and end going in the same direction
+            26 | 27 => {
+                let mut args = &std::mem::take(&mut self.stack)[..];
+                let mut across = 0.0;
+                if args.len() % 4 == 1 {
+                    across = args[0];
+                    args = &args[1..];
+                }
+                for a in args.chunks_exact(4) {
+                    if op == 26 {
+                        self.curve([across, a[0], a[1], a[2], 0.0, a[3]]);
+                    } else {
+                        self.curve([a[0], across, a[1], a[2], a[3], 0.0]);
+                    }
+                    across = 0.0;
+                }
+            }
+            30 | 31 => self.alternating_curves(op == 31)?,
+            14 => {
+                self.skip_width(len == 1 || len == 5);
+                self.builder.close();
+                self.ended = true;
+            }
+            _ => return Err(Self::malformed()),
+        }
+        Ok(())
+    }
+    fn args<const N: usize>(&self) -> Result<[f64; N], FontError> {
+        self.stack.get(..N).and_then(|args| args.try_into().ok()).ok_or_else(Self::malformed)
+    }
+    /// the flex operators, two curves that hinting can flatten into a line.
+    fn escaped(&mut self, op: u8) -> Result<(), FontError> {
+        match op {
+            35 => {
+                let d: [f64; 13] = self.args()?;
+                self.curve([d[0], d[1], d[2], d[3], d[4], d[5]]);
+                self.curve([d[6], d[7], d[8], d[9], d[10], d[11]]);
+            }
+            34 => {
+                let [dx1, dx2, dy2, dx3, dx4, dx5, dx6] = self.args()?;
+                self.curve([dx1, 0.0, dx2, dy2, dx3, 0.0]);
+                self.curve([dx4, 0.0, dx5, -dy2, dx6, 0.0]);
+            }
+            36 => {
+                let [dx1, dy1, dx2, dy2, dx3, dx4, dx5, dy5, dx6] = self.args()?;
+                self.curve([dx1, dy1, dx2, dy2, dx3, 0.0]);
+                self.curve([dx4, 0.0, dx5, dy5, dx6, -(dy1 + dy2 + dy5)]);
+            }
+            37 => {
+                let d: [f64; 11] = self.args()?;
+                let dx = d[0] + d[2] + d[4] + d[6] + d[8];
+                let dy = d[1] + d[3] + d[5] + d[7] + d[9];
+                // the last point only moves along the axis the curves moved along the most
+                let (dx6, dy6) = if dx.abs() > dy.abs() { (d[10], -dy) } else { (-dx, d[10]) };
+                self.curve([d[0], d[1], d[2], d[3], d[4], d[5]]);
+                self.curve([d[6], d[7], d[8], d[9], dx6, dy6]);
+            }
+            _ => return Err(Self::malformed()),
+        }
+        Ok(())
+    }
+}
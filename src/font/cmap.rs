@@ -0,0 +1,129 @@
+use super::{FontError, reader::Reader};
+
+/// how much a subtable is preferred, formats 12 and 4 of the unicode encodings are the most common
+/// and format 12 reaches past the basic multilingual plane.
+fn subtable_score(platform: u16, encoding: u16, format: u16) -> u8 {
+    let unicode = platform == 0 || (platform == 3 && (encoding == 1 || encoding == 10));
+    match format {
+        _ if !unicode => 0,
+        12 => 4,
+        4 => 3,
+        6 => 2,
+        0 => 1,
+        _ => 0,
+    }
+}
+
+/// # find_subtable
+///
+/// the unicode subtable of the cmap table that maps the most characters and that can be read,
+/// [`None`] when there isn't one.
+pub(crate) fn find_subtable(cmap: &[u8]) -> Result<Option<&[u8]>, FontError> {
+    let mut reader = Reader::new(cmap);
+    reader.skip(2)?;
+    let count = reader.u16()?;
+    let mut best: Option<(u8, &[u8])> = None;
+    for _ in 0..count {
+        let platform = reader.u16()?;
+        let encoding = reader.u16()?;
+        let offset = reader.u32()? as usize;
+        let format = Reader::at(cmap, offset)?.u16()?;
+        let score = subtable_score(platform, encoding, format);
+        if score > 0 && best.is_none_or(|(best, _)| score > best) {
+            best = Some((score, &cmap[offset..]));
+        }
+    }
+    Ok(best.map(|(_, subtable)| subtable))
+}
+
+/// # lookup
+///
+/// the glyph of the character in a subtable picked by [`find_subtable`], [`None`] when the font
+/// doesn't have it or the subtable is malformed.
+pub(crate) fn lookup(subtable: &[u8], c: u32) -> Option<u16> {
+    let glyph = match Reader::new(subtable).u16().ok()? {
+        0 => lookup_byte_encoding(subtable, c),
+        4 => lookup_segment_mapping(subtable, c),
+        6 => lookup_trimmed_table(subtable, c),
+        12 => lookup_segmented_coverage(subtable, c),
+        _ => None,
+    }?;
+    (glyph != 0).then_some(glyph)
+}
+
+fn lookup_byte_encoding(subtable: &[u8], c: u32) -> Option<u16> {
+    if c > 255 {
+        return None;
+    }
+    Reader::at(subtable, 6 + c as usize).ok()?.u8().ok().map(u16::from)
+}
+
+/// format 4, ranges of the basic multilingual plane that are either offset by a delta or looked up in
+/// an array of glyphs.
+fn lookup_segment_mapping(subtable: &[u8], c: u32) -> Option<u16> {
+    let c = u16::try_from(c).ok()?;
+    let segments = Reader::at(subtable, 6).ok()?.u16().ok()? as usize / 2;
+    let end_codes = 14;
+    let start_codes = end_codes + segments * 2 + 2;
+    let deltas = start_codes + segments * 2;
+    let range_offsets = deltas + segments * 2;
+    let read = |array: usize, i: usize| Reader::at(subtable, array + i * 2).ok()?.u16().ok();
+    // the segments are sorted by their end, find the first one ending at or after c
+    let (mut low, mut high) = (0, segments);
+    while low < high {
+        let middle = (low + high) / 2;
+        if read(end_codes, middle)? < c {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    if low == segments {
+        return None;
+    }
+    let start = read(start_codes, low)?;
+    if c < start {
+        return None;
+    }
+    let delta = read(deltas, low)?;
+    let range_offset = read(range_offsets, low)?;
+    if range_offset == 0 {
+        return Some(c.wrapping_add(delta));
+    }
+    // the offset is from where it's stored to the glyph of the start of the segment
+    let glyph = Reader::at(subtable, range_offsets + low * 2 + range_offset as usize + (c - start) as usize * 2).ok()?.u16().ok()?;
+    (glyph != 0).then(|| glyph.wrapping_add(delta))
+}
+
+/// format 6, a single array of glyphs for a range of characters.
+fn lookup_trimmed_table(subtable: &[u8], c: u32) -> Option<u16> {
+    let mut reader = Reader::at(subtable, 6).ok()?;
+    let first = reader.u16().ok()? as u32;
+    let count = reader.u16().ok()? as u32;
+    if c < first || c - first >= count {
+        return None;
+    }
+    Reader::at(subtable, 10 + (c - first) as usize * 2).ok()?.u16().ok()
+}
+
+/// format 12, sorted groups of characters mapped to consecutive glyphs.
+fn lookup_segmented_coverage(subtable: &[u8], c: u32) -> Option<u16> {
+    let count = Reader::at(subtable, 12).ok()?.u32().ok()? as usize;
+    let group = |i: usize| {
+        let mut reader = Reader::at(subtable, 16 + i * 12).ok()?;
+        Some((reader.u32().ok()?, reader.u32().ok()?, reader.u32().ok()?))
+    };
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let middle = (low + high) / 2;
+        let (start, end, glyph) = group(middle)?;
+        if c < start {
+            high = middle;
+        } else if c > end {
+            low = middle + 1;
+        } else {
+            return glyph.checked_add(c - start).and_then(|glyph| u16::try_from(glyph).ok());
+        }
+    }
+    None
+}
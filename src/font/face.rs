@@ -0,0 +1,215 @@
+use std::fmt::{self, Display};
+
+use num_traits::AsPrimitive;
+
+use crate::{Shape, FloatingPoint};
+use super::{reader::Reader, cmap, glyf::Glyf, cff::Cff};
+
+/// # FontError
+///
+/// why a font or one of its glyphs couldn't be read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontError {
+    /// the data ends in the middle of something.
+    UnexpectedEnd,
+    /// the data isn't a truetype or opentype font.
+    UnknownFormat,
+    /// a table the font needs isn't there.
+    MissingTable([u8; 4]),
+    /// a table has a version or format that can't be read.
+    UnsupportedTable([u8; 4]),
+    /// the font doesn't have a glyph with this index.
+    GlyphOutOfRange(u16),
+    /// the outline of the glyph doesn't make sense, like a composite glyph that includes itself.
+    MalformedGlyph(u16),
+}
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = |tag: &[u8; 4]| String::from_utf8_lossy(tag).into_owned();
+        match self {
+            FontError::UnexpectedEnd => write!(f, "unexpected end of font data"),
+            FontError::UnknownFormat => write!(f, "not a truetype or opentype font"),
+            FontError::MissingTable(t) => write!(f, "missing {:?} table", tag(t)),
+            FontError::UnsupportedTable(t) => write!(f, "unsupported {:?} table", tag(t)),
+            FontError::GlyphOutOfRange(glyph) => write!(f, "glyph {} is out of range", glyph),
+            FontError::MalformedGlyph(glyph) => write!(f, "glyph {} is malformed", glyph),
+        }
+    }
+}
+impl std::error::Error for FontError {}
+
+/// how a glyph is placed on a line of horizontal text, in font units.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HorizontalMetrics {
+    /// how far the pen moves after the glyph.
+    pub advance_width: u16,
+    /// the distance from the pen to the left of the bounding box of the glyph.
+    pub left_side_bearing: i16,
+}
+
+/// # Glyph
+///
+/// the outline of a glyph in font units, with y going up from the baseline, and how far it advances.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Glyph<T: FloatingPoint> {
+    pub index: u16,
+    pub shape: Shape<T>,
+    pub metrics: HorizontalMetrics,
+}
+
+#[derive(Clone, Debug)]
+enum Outlines<'a> {
+    TrueType(Glyf<'a>),
+    Cff(Cff<'a>),
+}
+
+/// # Font
+///
+/// a truetype or opentype font read straight from its bytes, which turns the quadratic outlines of
+/// the glyf table and the cubic outlines of the cff table into [`Shape`]s. only the tables needed
+/// for outlines are read: head, maxp, hhea, hmtx, cmap and either glyf and loca or cff, with hinting
+/// and variations left out.
+#[derive(Clone, Debug)]
+pub struct Font<'a> {
+    units_per_em: u16,
+    glyph_count: u16,
+    ascender: i16,
+    descender: i16,
+    line_gap: i16,
+    long_metrics: u16,
+    hmtx: &'a [u8],
+    cmap: Option<&'a [u8]>,
+    outlines: Outlines<'a>,
+}
+
+/// the tag of a table and its data.
+type Table<'a> = ([u8; 4], &'a [u8]);
+
+/// finds the tables of the font starting at offset, table offsets are from the start of the data
+/// even for fonts inside a collection.
+fn table_directory(data: &[u8], offset: usize) -> Result<Vec<Table<'_>>, FontError> {
+    let mut reader = Reader::at(data, offset)?;
+    let version = reader.tag()?;
+    if !matches!(&version, b"\0\x01\0\0" | b"OTTO" | b"true") {
+        return Err(FontError::UnknownFormat);
+    }
+    let count = reader.u16()?;
+    reader.skip(6)?;
+    (0..count).map(|_| {
+        let tag = reader.tag()?;
+        reader.skip(4)?;
+        let (start, len) = (reader.u32()? as usize, reader.u32()? as usize);
+        let table = start.checked_add(len).and_then(|end| data.get(start..end)).ok_or(FontError::UnexpectedEnd)?;
+        Ok((tag, table))
+    }).collect()
+}
+
+impl<'a> Font<'a> {
+    /// reads a .ttf or .otf font.
+    pub fn parse(data: &'a [u8]) -> Result<Self, FontError> {
+        Self::parse_at(data, 0)
+    }
+    /// reads the font at index of a .ttc collection.
+    pub fn parse_collection(data: &'a [u8], index: u32) -> Result<Self, FontError> {
+        let mut reader = Reader::new(data);
+        if &reader.tag()? != b"ttcf" {
+            return Err(FontError::UnknownFormat);
+        }
+        reader.skip(4)?;
+        if index >= reader.u32()? {
+            return Err(FontError::UnknownFormat);
+        }
+        let offset = Reader::at(data, 12 + index as usize * 4)?.u32()?;
+        Self::parse_at(data, offset as usize)
+    }
+    fn parse_at(data: &'a [u8], offset: usize) -> Result<Self, FontError> {
+        let tables = table_directory(data, offset)?;
+        let find = |tag: &[u8; 4]| tables.iter().find(|(t, _)| t == tag).map(|&(_, table)| table);
+        let table = |tag: &[u8; 4]| find(tag).ok_or(FontError::MissingTable(*tag));
+
+        let head = table(b"head")?;
+        let units_per_em = Reader::at(head, 18)?.u16()?;
+        let long_offsets = Reader::at(head, 50)?.i16()? != 0;
+        let glyph_count = Reader::at(table(b"maxp")?, 4)?.u16()?;
+        let hhea = table(b"hhea")?;
+        let mut reader = Reader::at(hhea, 4)?;
+        let (ascender, descender, line_gap) = (reader.i16()?, reader.i16()?, reader.i16()?);
+        let long_metrics = Reader::at(hhea, 34)?.u16()?;
+        let hmtx = table(b"hmtx")?;
+        if long_metrics == 0 || hmtx.len() < long_metrics as usize * 4 {
+            return Err(FontError::UnsupportedTable(*b"hmtx"));
+        }
+        let cmap = find(b"cmap").map(cmap::find_subtable).transpose()?.flatten();
+        let outlines = match (find(b"glyf"), find(b"CFF ")) {
+            (Some(glyf), _) => Outlines::TrueType(Glyf { loca: table(b"loca")?, glyf, long_offsets }),
+            (None, Some(cff)) => Outlines::Cff(Cff::parse(cff)?),
+            (None, None) if find(b"CFF2").is_some() => return Err(FontError::UnsupportedTable(*b"CFF2")),
+            (None, None) => return Err(FontError::MissingTable(*b"glyf")),
+        };
+        Ok(Self { units_per_em, glyph_count, ascender, descender, line_gap, long_metrics, hmtx, cmap, outlines })
+    }
+    /// how many font units make up the size of the font.
+    pub fn units_per_em(&self) -> u16 {
+        self.units_per_em
+    }
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count
+    }
+    /// how far above the baseline the font goes, in font units.
+    pub fn ascender(&self) -> i16 {
+        self.ascender
+    }
+    /// how far below the baseline the font goes, which is usually negative.
+    pub fn descender(&self) -> i16 {
+        self.descender
+    }
+    /// the space between the descender of a line and the ascender of the next.
+    pub fn line_gap(&self) -> i16 {
+        self.line_gap
+    }
+    /// the glyph of the character from the unicode character map, [`None`] when the font doesn't have
+    /// one and the missing glyph 0 should be drawn instead.
+    pub fn glyph_index(&self, c: char) -> Option<u16> {
+        cmap::lookup(self.cmap?, c as u32).filter(|&glyph| glyph < self.glyph_count)
+    }
+    /// the horizontal metrics of the glyph, glyphs past the last long metric share its advance.
+    pub fn metrics(&self, glyph: u16) -> Option<HorizontalMetrics> {
+        if glyph >= self.glyph_count {
+            return None;
+        }
+        let last = self.long_metrics - 1;
+        let advance_width = Reader::at(self.hmtx, glyph.min(last) as usize * 4).ok()?.u16().ok()?;
+        let left_side_bearing = if glyph <= last {
+            Reader::at(self.hmtx, glyph as usize * 4 + 2).ok()?.i16().ok()?
+        } else {
+            // left out side bearings count as 0
+            Reader::at(self.hmtx, self.long_metrics as usize * 4 + (glyph - self.long_metrics) as usize * 2)
+                .and_then(|mut reader| reader.i16()).unwrap_or(0)
+        };
+        Some(HorizontalMetrics { advance_width, left_side_bearing })
+    }
+    /// # outline
+    ///
+    /// the contours of the glyph in font units with y going up, lines and quadratics for truetype
+    /// outlines and lines and cubics for cff outlines. every contour is closed and glyphs like the space
+    /// have none. the outside of the glyph goes clockwise for truetype outlines and counter clockwise for
+    /// cff outlines, which fill the same way with [`FillRule::NonZero`](crate::FillRule::NonZero).
+    pub fn outline<T: FloatingPoint>(&self, glyph: u16) -> Result<Shape<T>, FontError>
+        where f64: AsPrimitive<T> {
+        if glyph >= self.glyph_count {
+            return Err(FontError::GlyphOutOfRange(glyph));
+        }
+        match &self.outlines {
+            Outlines::TrueType(glyf) => glyf.outline(glyph),
+            Outlines::Cff(cff) => cff.outline(glyph),
+        }
+    }
+    /// the outline and metrics of the glyph.
+    pub fn glyph<T: FloatingPoint>(&self, glyph: u16) -> Result<Glyph<T>, FontError>
+        where f64: AsPrimitive<T> {
+        let shape = self.outline(glyph)?;
+        let metrics = self.metrics(glyph).ok_or(FontError::GlyphOutOfRange(glyph))?;
+        Ok(Glyph { index: glyph, shape, metrics })
+    }
+}
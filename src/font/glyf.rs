@@ -0,0 +1,210 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, Shape, FloatingPoint};
+use super::{FontError, reader::Reader, outline::OutlineBuilder};
+
+/// how deep composite glyphs can be nested, which stops glyphs that include themselves.
+const MAX_COMPONENT_DEPTH: usize = 8;
+
+const ON_CURVE: u8 = 0x01;
+const X_SHORT: u8 = 0x02;
+const Y_SHORT: u8 = 0x04;
+const REPEAT: u8 = 0x08;
+const X_SAME_OR_POSITIVE: u8 = 0x10;
+const Y_SAME_OR_POSITIVE: u8 = 0x20;
+
+const ARGS_ARE_WORDS: u16 = 0x0001;
+const ARGS_ARE_XY_VALUES: u16 = 0x0002;
+const HAS_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const HAS_X_AND_Y_SCALE: u16 = 0x0040;
+const HAS_TWO_BY_TWO: u16 = 0x0080;
+const SCALED_COMPONENT_OFFSET: u16 = 0x0800;
+const UNSCALED_COMPONENT_OFFSET: u16 = 0x1000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    position: Vector2<f64>,
+    on_curve: bool,
+}
+
+/// the quadratic outlines of the glyf table, found through the offsets of the loca table.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Glyf<'a> {
+    pub loca: &'a [u8],
+    pub glyf: &'a [u8],
+    pub long_offsets: bool,
+}
+
+impl<'a> Glyf<'a> {
+    fn glyph_data(&self, glyph: u16) -> Result<&'a [u8], FontError> {
+        let offset = |i: usize| -> Result<usize, FontError> {
+            if self.long_offsets {
+                Ok(Reader::at(self.loca, i * 4)?.u32()? as usize)
+            } else {
+                Ok(Reader::at(self.loca, i * 2)?.u16()? as usize * 2)
+            }
+        };
+        let (start, end) = (offset(glyph as usize)?, offset(glyph as usize + 1)?);
+        if end < start {
+            return Err(FontError::MalformedGlyph(glyph));
+        }
+        self.glyf.get(start..end).ok_or(FontError::UnexpectedEnd)
+    }
+    /// appends the contours of the glyph to contours as lists of points, placing the components of
+    /// composite glyphs.
+    fn contours(&self, glyph: u16, depth: usize, contours: &mut Vec<Vec<Point>>) -> Result<(), FontError> {
+        let data = self.glyph_data(glyph)?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        let mut reader = Reader::new(data);
+        let count = reader.i16()?;
+        reader.skip(8)?;
+        if count >= 0 {
+            simple_contours(glyph, reader, count as usize, contours)
+        } else if depth < MAX_COMPONENT_DEPTH {
+            self.composite_contours(reader, depth, contours)
+        } else {
+            Err(FontError::MalformedGlyph(glyph))
+        }
+    }
+    fn composite_contours(&self, mut reader: Reader<'a>, depth: usize, contours: &mut Vec<Vec<Point>>) -> Result<(), FontError> {
+        let first = contours.len();
+        loop {
+            let flags = reader.u16()?;
+            let component = reader.u16()?;
+            let (arg1, arg2) = match (flags & ARGS_ARE_WORDS != 0, flags & ARGS_ARE_XY_VALUES != 0) {
+                (true, true) => (reader.i16()? as i32, reader.i16()? as i32),
+                (true, false) => (reader.u16()? as i32, reader.u16()? as i32),
+                (false, true) => (reader.i8()? as i32, reader.i8()? as i32),
+                (false, false) => (reader.u8()? as i32, reader.u8()? as i32),
+            };
+            // the columns of the matrix placing the component
+            let (mut a, mut b, mut c, mut d) = (1.0, 0.0, 0.0, 1.0);
+            if flags & HAS_SCALE != 0 {
+                a = reader.f2dot14()?;
+                d = a;
+            } else if flags & HAS_X_AND_Y_SCALE != 0 {
+                a = reader.f2dot14()?;
+                d = reader.f2dot14()?;
+            } else if flags & HAS_TWO_BY_TWO != 0 {
+                a = reader.f2dot14()?;
+                b = reader.f2dot14()?;
+                c = reader.f2dot14()?;
+                d = reader.f2dot14()?;
+            }
+            let transform = |p: Vector2<f64>| Vector2::new(a * p.x + c * p.y, b * p.x + d * p.y);
+            let mut placed = Vec::new();
+            self.contours(component, depth + 1, &mut placed)?;
+            for point in placed.iter_mut().flatten() {
+                point.position = transform(point.position);
+            }
+            let offset = if flags & ARGS_ARE_XY_VALUES != 0 {
+                let offset = Vector2::new(arg1 as f64, arg2 as f64);
+                let scaled = flags & SCALED_COMPONENT_OFFSET != 0 && flags & UNSCALED_COMPONENT_OFFSET == 0;
+                if scaled { transform(offset) } else { offset }
+            } else {
+                // moves the component so its point arg2 lands on point arg1 of the glyph so far
+                let parent = contours[first..].iter().flatten().nth(arg1 as usize);
+                let child = placed.iter().flatten().nth(arg2 as usize);
+                match (parent, child) {
+                    (Some(parent), Some(child)) => parent.position - child.position,
+                    _ => return Err(FontError::MalformedGlyph(component)),
+                }
+            };
+            for point in placed.iter_mut().flatten() {
+                point.position += offset;
+            }
+            contours.extend(placed);
+            if flags & MORE_COMPONENTS == 0 {
+                return Ok(());
+            }
+        }
+    }
+    /// # outline
+    ///
+    /// the contours of the glyph, made of lines between points on the curve and quadratics with the
+    /// points that are off the curve as their control points. two control points in a row have a point on
+    /// the curve halfway between them.
+    pub fn outline<T: FloatingPoint>(&self, glyph: u16) -> Result<Shape<T>, FontError>
+        where f64: AsPrimitive<T> {
+        let mut contours = Vec::new();
+        self.contours(glyph, 0, &mut contours)?;
+        let mut builder = OutlineBuilder::new();
+        for contour in contours.iter().filter(|contour| contour.len() > 1) {
+            let midpoint = |a: &Point, b: &Point| (a.position + b.position) * 0.5;
+            // start on a point on the curve, or between the last and first points when there isn't one
+            let (start, rest) = match contour.iter().position(|point| point.on_curve) {
+                Some(i) => (contour[i].position, contour[i + 1..].iter().chain(&contour[..i])),
+                None => (midpoint(&contour[contour.len() - 1], &contour[0]), contour[..].iter().chain(&contour[..0])),
+            };
+            builder.move_to(start);
+            let mut control: Option<Vector2<f64>> = None;
+            for point in rest {
+                match (point.on_curve, control) {
+                    (true, Some(c)) => {
+                        builder.quad_to(c, point.position);
+                        control = None;
+                    }
+                    (true, None) => builder.line_to(point.position),
+                    (false, Some(c)) => {
+                        builder.quad_to(c, (c + point.position) * 0.5);
+                        control = Some(point.position);
+                    }
+                    (false, None) => control = Some(point.position),
+                }
+            }
+            if let Some(c) = control {
+                builder.quad_to(c, start);
+            }
+            builder.close();
+        }
+        Ok(builder.finish())
+    }
+}
+
+fn simple_contours(glyph: u16, mut reader: Reader, count: usize, contours: &mut Vec<Vec<Point>>) -> Result<(), FontError> {
+    let mut ends = Vec::with_capacity(count);
+    for _ in 0..count {
+        ends.push(reader.u16()? as usize);
+    }
+    if ends.windows(2).any(|pair| pair[1] < pair[0]) {
+        return Err(FontError::MalformedGlyph(glyph));
+    }
+    let points = ends.last().map_or(0, |&end| end + 1);
+    let instructions = reader.u16()? as usize;
+    reader.skip(instructions)?;
+    let mut flags = Vec::with_capacity(points);
+    while flags.len() < points {
+        let flag = reader.u8()?;
+        flags.push(flag);
+        if flag & REPEAT != 0 {
+            for _ in 0..reader.u8()? {
+                flags.push(flag);
+            }
+        }
+    }
+    flags.truncate(points);
+    // the coordinates are stored as differences from the previous point, all x first and then all y
+    let mut coordinates = |short: u8, same_or_positive: u8| -> Result<Vec<f64>, FontError> {
+        let mut value = 0i32;
+        flags.iter().map(|&flag| {
+            value += match (flag & short != 0, flag & same_or_positive != 0) {
+                (true, true) => reader.u8()? as i32,
+                (true, false) => -(reader.u8()? as i32),
+                (false, true) => 0,
+                (false, false) => reader.i16()? as i32,
+            };
+            Ok(value as f64)
+        }).collect()
+    };
+    let xs = coordinates(X_SHORT, X_SAME_OR_POSITIVE)?;
+    let ys = coordinates(Y_SHORT, Y_SAME_OR_POSITIVE)?;
+    let mut start = 0;
+    for end in ends {
+        contours.push((start..=end).map(|i| Point { position: Vector2::new(xs[i], ys[i]), on_curve: flags[i] & ON_CURVE != 0 }).collect());
+        start = end + 1;
+    }
+    Ok(())
+}
@@ -0,0 +1,7 @@
+mod reader;
+mod outline;
+mod cmap;
+mod glyf;
+mod cff;
+mod face;
+pub use face::*;
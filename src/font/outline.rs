@@ -0,0 +1,59 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, Contour, Shape, LinearSegment, QuadraticSegment, CubicSegment, FloatingPoint};
+
+/// collects the path commands of a glyph into a [`Shape`], closing every contour with a line back
+/// to where it started. lines without length are left out.
+pub(crate) struct OutlineBuilder<T: FloatingPoint> {
+    shape: Shape<T>,
+    contour: Contour<T>,
+    start: Vector2<T>,
+    current: Vector2<T>,
+}
+
+impl<T: FloatingPoint> OutlineBuilder<T>
+    where f64: AsPrimitive<T> {
+    pub fn new() -> Self {
+        let origin = Vector2::new(T::zero(), T::zero());
+        Self { shape: Shape::new(), contour: Contour::new(), start: origin, current: origin }
+    }
+    fn point(p: Vector2<f64>) -> Vector2<T> {
+        Vector2::new(p.x.as_(), p.y.as_())
+    }
+    pub fn move_to(&mut self, p: Vector2<f64>) {
+        self.close();
+        self.start = Self::point(p);
+        self.current = self.start;
+    }
+    pub fn line_to(&mut self, p: Vector2<f64>) {
+        let p = Self::point(p);
+        if p != self.current {
+            self.contour.push(LinearSegment::new(self.current, p));
+            self.current = p;
+        }
+    }
+    pub fn quad_to(&mut self, control: Vector2<f64>, p: Vector2<f64>) {
+        let p = Self::point(p);
+        self.contour.push(QuadraticSegment::new(self.current, Self::point(control), p));
+        self.current = p;
+    }
+    pub fn curve_to(&mut self, first: Vector2<f64>, second: Vector2<f64>, p: Vector2<f64>) {
+        let p = Self::point(p);
+        self.contour.push(CubicSegment::new(self.current, Self::point(first), Self::point(second), p));
+        self.current = p;
+    }
+    /// ends the current contour, nothing happens when it's empty.
+    pub fn close(&mut self) {
+        if self.current != self.start {
+            self.contour.push(LinearSegment::new(self.current, self.start));
+            self.current = self.start;
+        }
+        if !self.contour.edges().is_empty() {
+            self.shape.push(std::mem::take(&mut self.contour));
+        }
+    }
+    pub fn finish(mut self) -> Shape<T> {
+        self.close();
+        self.shape
+    }
+}
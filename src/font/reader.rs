@@ -0,0 +1,70 @@
+use super::FontError;
+
+/// reads the big endian numbers fonts are made of, every read past the end of the data fails
+/// with [`FontError::UnexpectedEnd`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+    pub fn at(data: &'a [u8], offset: usize) -> Result<Self, FontError> {
+        if offset > data.len() {
+            return Err(FontError::UnexpectedEnd);
+        }
+        Ok(Self { data, offset })
+    }
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    pub fn is_empty(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+    pub fn bytes(&mut self, len: usize) -> Result<&'a [u8], FontError> {
+        let end = self.offset.checked_add(len).filter(|&end| end <= self.data.len()).ok_or(FontError::UnexpectedEnd)?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+    pub fn skip(&mut self, len: usize) -> Result<(), FontError> {
+        self.bytes(len).map(|_| ())
+    }
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], FontError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+    pub fn u8(&mut self) -> Result<u8, FontError> {
+        Ok(self.array::<1>()?[0])
+    }
+    pub fn i8(&mut self) -> Result<i8, FontError> {
+        Ok(self.u8()? as i8)
+    }
+    pub fn u16(&mut self) -> Result<u16, FontError> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+    pub fn i16(&mut self) -> Result<i16, FontError> {
+        Ok(i16::from_be_bytes(self.array()?))
+    }
+    pub fn u32(&mut self) -> Result<u32, FontError> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+    pub fn i32(&mut self) -> Result<i32, FontError> {
+        Ok(i32::from_be_bytes(self.array()?))
+    }
+    /// an unsigned number of 1 to 4 bytes, which is how cff stores offsets.
+    pub fn offset_of_size(&mut self, size: u8) -> Result<u32, FontError> {
+        Ok(self.bytes(size as usize)?.iter().fold(0, |offset, &byte| offset << 8 | byte as u32))
+    }
+    /// a 2.14 fixed point number, which composite glyphs use for their scale.
+    pub fn f2dot14(&mut self) -> Result<f64, FontError> {
+        Ok(self.i16()? as f64 / 16384.0)
+    }
+    pub fn tag(&mut self) -> Result<[u8; 4], FontError> {
+        self.array()
+    }
+}
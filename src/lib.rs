@@ -1,6 +1,8 @@
-#![allow(unused)]
-mod linear;
-pub mod complex;
-pub mod data;
-pub mod equations;
+#![allow(unused)]
+mod linear;
+pub mod complex;
+pub mod data;
+pub mod equations;
+pub mod msdf;
+pub mod font;
 pub use linear::*;
\ No newline at end of file
@@ -0,0 +1,237 @@
+use super::{Vector2, Vector3, Rectangle, Cube, traits::{Number, min, max}};
+
+fn clamp<T: Number>(value: T, low: T, high: T) -> T {
+    max(low, min(value, high))
+}
+
+/// # Aabb2
+///
+/// an axis aligned bounding box in 2 dimensions made from its minimum and maximum corners.
+/// a box is only valid when every component of min is less than or equal to max.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb2<T: Number> {
+    pub min: Vector2<T>,
+    pub max: Vector2<T>,
+}
+/// # Aabb3
+///
+/// an axis aligned bounding box in 3 dimensions made from its minimum and maximum corners.
+/// a box is only valid when every component of min is less than or equal to max.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb3<T: Number> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T: Number> Aabb2<T> {
+    pub fn new(min: Vector2<T>, max: Vector2<T>) -> Self {
+        Self { min, max }
+    }
+    /// a box with no size containing only the point.
+    pub fn from_point(point: Vector2<T>) -> Self {
+        Self { min: point, max: point }
+    }
+    /// the smallest box containing every point, or [`None`] when there are no points.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Vector2<T>>) -> Option<Self>
+        where T: 'a {
+        let mut points = points.into_iter();
+        let first = Self::from_point(*points.next()?);
+        Some(points.fold(first, |aabb, point| aabb.include(*point)))
+    }
+    /// the smallest box containing both this box and the point.
+    pub fn include(&self, point: Vector2<T>) -> Self {
+        Self {
+            min: Vector2::new(min(self.min.x, point.x), min(self.min.y, point.y)),
+            max: Vector2::new(max(self.max.x, point.x), max(self.max.y, point.y)),
+        }
+    }
+    /// the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Vector2::new(min(self.min.x, other.min.x), min(self.min.y, other.min.y)),
+            max: Vector2::new(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
+        }
+    }
+    /// the area both boxes share, or [`None`] when they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let aabb = Self {
+            min: Vector2::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y)),
+            max: Vector2::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y)),
+        };
+        if aabb.min.x > aabb.max.x || aabb.min.y > aabb.max.y {
+            return None;
+        }
+        Some(aabb)
+    }
+    /// whether the boxes touch or overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y
+    }
+    /// whether the point is inside of the box or on its edges.
+    pub fn contains_point(&self, point: Vector2<T>) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+        self.min.y <= point.y && point.y <= self.max.y
+    }
+    /// whether the other box is completely inside of this box.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.min.x <= other.min.x && other.max.x <= self.max.x &&
+        self.min.y <= other.min.y && other.max.y <= self.max.y
+    }
+    /// grows the box by amount in every direction. a negative amount shrinks it.
+    pub fn expand(&self, amount: T) -> Self {
+        Self { min: self.min - amount, max: self.max + amount }
+    }
+    pub fn center(&self) -> Vector2<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+    /// the width and height of the box.
+    pub fn size(&self) -> Vector2<T> {
+        self.max - self.min
+    }
+    /// half of the width and height of the box, the distance from the center to the edges.
+    pub fn extents(&self) -> Vector2<T> {
+        self.size() / (T::one() + T::one())
+    }
+    pub fn area(&self) -> T {
+        let size = self.size();
+        size.x * size.y
+    }
+    pub fn perimeter(&self) -> T {
+        let size = self.size();
+        (size.x + size.y) * (T::one() + T::one())
+    }
+    /// the point inside of the box closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector2<T>) -> Vector2<T> {
+        Vector2::new(clamp(point.x, self.min.x, self.max.x), clamp(point.y, self.min.y, self.max.y))
+    }
+}
+
+impl<T: Number> Aabb3<T> {
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+    /// a box with no size containing only the point.
+    pub fn from_point(point: Vector3<T>) -> Self {
+        Self { min: point, max: point }
+    }
+    /// the smallest box containing every point, or [`None`] when there are no points.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Vector3<T>>) -> Option<Self>
+        where T: 'a {
+        let mut points = points.into_iter();
+        let first = Self::from_point(*points.next()?);
+        Some(points.fold(first, |aabb, point| aabb.include(*point)))
+    }
+    /// the smallest box containing both this box and the point.
+    pub fn include(&self, point: Vector3<T>) -> Self {
+        Self {
+            min: Vector3::new(min(self.min.x, point.x), min(self.min.y, point.y), min(self.min.z, point.z)),
+            max: Vector3::new(max(self.max.x, point.x), max(self.max.y, point.y), max(self.max.z, point.z)),
+        }
+    }
+    /// the smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Vector3::new(min(self.min.x, other.min.x), min(self.min.y, other.min.y), min(self.min.z, other.min.z)),
+            max: Vector3::new(max(self.max.x, other.max.x), max(self.max.y, other.max.y), max(self.max.z, other.max.z)),
+        }
+    }
+    /// the volume both boxes share, or [`None`] when they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let aabb = Self {
+            min: Vector3::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y), max(self.min.z, other.min.z)),
+            max: Vector3::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y), min(self.max.z, other.max.z)),
+        };
+        if aabb.min.x > aabb.max.x || aabb.min.y > aabb.max.y || aabb.min.z > aabb.max.z {
+            return None;
+        }
+        Some(aabb)
+    }
+    /// whether the boxes touch or overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x &&
+        self.min.y <= other.max.y && other.min.y <= self.max.y &&
+        self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+    /// whether the point is inside of the box or on its faces.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x &&
+        self.min.y <= point.y && point.y <= self.max.y &&
+        self.min.z <= point.z && point.z <= self.max.z
+    }
+    /// whether the other box is completely inside of this box.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.min.x <= other.min.x && other.max.x <= self.max.x &&
+        self.min.y <= other.min.y && other.max.y <= self.max.y &&
+        self.min.z <= other.min.z && other.max.z <= self.max.z
+    }
+    /// grows the box by amount in every direction. a negative amount shrinks it.
+    pub fn expand(&self, amount: T) -> Self {
+        Self { min: self.min - amount, max: self.max + amount }
+    }
+    pub fn center(&self) -> Vector3<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+    /// the width, height and depth of the box.
+    pub fn size(&self) -> Vector3<T> {
+        self.max - self.min
+    }
+    /// half of the size of the box, the distance from the center to the faces.
+    pub fn extents(&self) -> Vector3<T> {
+        self.size() / (T::one() + T::one())
+    }
+    pub fn surface_area(&self) -> T {
+        let size = self.size();
+        (size.x * size.y + size.y * size.z + size.z * size.x) * (T::one() + T::one())
+    }
+    pub fn volume(&self) -> T {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+    /// the point inside of the box closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        Vector3::new(
+            clamp(point.x, self.min.x, self.max.x),
+            clamp(point.y, self.min.y, self.max.y),
+            clamp(point.z, self.min.z, self.max.z)
+        )
+    }
+}
+
+/// rectangles have the top at the largest y value.
+impl<T: Number> From<Aabb2<T>> for Rectangle<T> {
+    fn from(value: Aabb2<T>) -> Self {
+        Rectangle::new(
+            Vector2::new(value.min.x, value.max.y),
+            value.max,
+            value.min,
+            Vector2::new(value.max.x, value.min.y)
+        )
+    }
+}
+impl<T: Number> From<Rectangle<T>> for Aabb2<T> {
+    fn from(value: Rectangle<T>) -> Self {
+        Aabb2::from_point(value.top_left())
+            .include(value.top_right())
+            .include(value.bottom_left())
+            .include(value.bottom_right())
+    }
+}
+/// cubes have the front at the smallest z value.
+impl<T: Number> From<Aabb3<T>> for Cube<T> {
+    fn from(value: Aabb3<T>) -> Self {
+        let face = Rectangle::from(Aabb2::new(Vector2::new(value.min.x, value.min.y), Vector2::new(value.max.x, value.max.y)));
+        Cube::with_depth(face, value.min.z, face, value.max.z)
+    }
+}
+impl<T: Number> From<Cube<T>> for Aabb3<T> {
+    fn from(value: Cube<T>) -> Self {
+        let front = Aabb2::from(*value.front());
+        let back = Aabb2::from(*value.back());
+        let face = front.union(&back);
+        Aabb3::new(
+            Vector3::new(face.min.x, face.min.y, min(value.front_depth(), value.back_depth())),
+            Vector3::new(face.max.x, face.max.y, max(value.front_depth(), value.back_depth()))
+        )
+    }
+}
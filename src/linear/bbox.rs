@@ -1,17 +1,126 @@
-use num_traits::One;
-
-use crate::linear::{LinearSegment, Vector2, Rectangle, Number, Cube};
-
-pub trait BoundingBox2D<T: Number> {
-    fn bbox(&self) -> Rectangle<T>;
-}
-pub trait BoundingBox3D<T: Number> {
-    fn bbox(&self) -> Cube<T>;
-}
-
-
-impl<T: Number> BoundingBox2D<T> for LinearSegment<Vector2<T>> {
-    fn bbox(&self) -> Rectangle<T> {
-        Rectangle::new(Vector2::<T>::one(), Vector2::<T>::one(), Vector2::<T>::one(), Vector2::<T>::one())
-    }
-}
\ No newline at end of file
+use crate::equations::QuadraticSolution;
+use crate::linear::{LinearSegment, QuadraticSegment, CubicSegment, Segment, Vector2, Vector3, Aabb2, Aabb3, Number, FloatingPoint};
+use crate::linear::traits::{min, max};
+
+pub trait BoundingBox2D<T: Number> {
+    /// the smallest box that contains the whole shape.
+    fn bbox(&self) -> Aabb2<T>;
+    /// a box containing every control point, which always contains the shape
+    /// but may be larger than [`BoundingBox2D::bbox`]. this is cheaper to calculate.
+    fn hull_bbox(&self) -> Aabb2<T>;
+}
+pub trait BoundingBox3D<T: Number> {
+    /// the smallest box that contains the whole shape.
+    fn bbox(&self) -> Aabb3<T>;
+    /// a box containing every control point, which always contains the shape
+    /// but may be larger than [`BoundingBox3D::bbox`]. this is cheaper to calculate.
+    fn hull_bbox(&self) -> Aabb3<T>;
+}
+
+/// the minimum and maximum of a single coordinate of a quadratic bezier curve. the curve
+/// can only go past its endpoints where its derivative 2((p1 - p0) + t(p0 - 2p1 + p2)) is 0.
+fn quadratic_extrema<T: FloatingPoint>(p0: T, p1: T, p2: T) -> (T, T) {
+    let (mut low, mut high) = (min(p0, p2), max(p0, p2));
+    let mut include = |t: T| {
+        if t > T::zero() && t < T::one() {
+            let mt = T::one() - t;
+            let value = mt * mt * p0 + (T::one() + T::one()) * mt * t * p1 + t * t * p2;
+            low = min(low, value);
+            high = max(high, value);
+        }
+    };
+    match T::quadratic_formula(T::zero(), p0 - (p1 + p1) + p2, p1 - p0) {
+        QuadraticSolution::OneReal(t) => include(t),
+        QuadraticSolution::TwoReal([t0, t1]) => { include(t0); include(t1); }
+        _ => {}
+    }
+    (low, high)
+}
+/// the minimum and maximum of a single coordinate of a cubic bezier curve. the curve can only
+/// go past its endpoints where its derivative 3(at^2 + bt + c) is 0, with a = -p0 + 3p1 - 3p2 + p3,
+/// b = 2(p0 - 2p1 + p2) and c = p1 - p0.
+fn cubic_extrema<T: FloatingPoint>(p0: T, p1: T, p2: T, p3: T) -> (T, T) {
+    let (mut low, mut high) = (min(p0, p3), max(p0, p3));
+    let mut include = |t: T| {
+        if t > T::zero() && t < T::one() {
+            let mt = T::one() - t;
+            let three = T::one() + T::one() + T::one();
+            let value = mt * mt * mt * p0 + three * mt * mt * t * p1 + three * mt * t * t * p2 + t * t * t * p3;
+            low = min(low, value);
+            high = max(high, value);
+        }
+    };
+    let a = p3 - p0 + (p1 - p2) * (T::one() + T::one() + T::one());
+    let b = (p0 - (p1 + p1) + p2) * (T::one() + T::one());
+    let c = p1 - p0;
+    match T::quadratic_formula(a, b, c) {
+        QuadraticSolution::OneReal(t) => include(t),
+        QuadraticSolution::TwoReal([t0, t1]) => { include(t0); include(t1); }
+        _ => {}
+    }
+    (low, high)
+}
+
+impl<T: Number> BoundingBox2D<T> for LinearSegment<Vector2<T>> {
+    fn bbox(&self) -> Aabb2<T> {
+        self.hull_bbox()
+    }
+    fn hull_bbox(&self) -> Aabb2<T> {
+        Aabb2::from_points(self.iter()).unwrap()
+    }
+}
+impl<T: FloatingPoint> BoundingBox2D<T> for QuadraticSegment<Vector2<T>> {
+    fn bbox(&self) -> Aabb2<T> {
+        let [p0, p1, p2] = [*self.get_point(0), *self.get_point(1), *self.get_point(2)];
+        let (low_x, high_x) = quadratic_extrema(p0.x, p1.x, p2.x);
+        let (low_y, high_y) = quadratic_extrema(p0.y, p1.y, p2.y);
+        Aabb2::new(Vector2::new(low_x, low_y), Vector2::new(high_x, high_y))
+    }
+    fn hull_bbox(&self) -> Aabb2<T> {
+        Aabb2::from_points(self.iter()).unwrap()
+    }
+}
+impl<T: FloatingPoint> BoundingBox2D<T> for CubicSegment<Vector2<T>> {
+    fn bbox(&self) -> Aabb2<T> {
+        let [p0, p1, p2, p3] = [*self.get_point(0), *self.get_point(1), *self.get_point(2), *self.get_point(3)];
+        let (low_x, high_x) = cubic_extrema(p0.x, p1.x, p2.x, p3.x);
+        let (low_y, high_y) = cubic_extrema(p0.y, p1.y, p2.y, p3.y);
+        Aabb2::new(Vector2::new(low_x, low_y), Vector2::new(high_x, high_y))
+    }
+    fn hull_bbox(&self) -> Aabb2<T> {
+        Aabb2::from_points(self.iter()).unwrap()
+    }
+}
+
+impl<T: Number> BoundingBox3D<T> for LinearSegment<Vector3<T>> {
+    fn bbox(&self) -> Aabb3<T> {
+        self.hull_bbox()
+    }
+    fn hull_bbox(&self) -> Aabb3<T> {
+        Aabb3::from_points(self.iter()).unwrap()
+    }
+}
+impl<T: FloatingPoint> BoundingBox3D<T> for QuadraticSegment<Vector3<T>> {
+    fn bbox(&self) -> Aabb3<T> {
+        let [p0, p1, p2] = [*self.get_point(0), *self.get_point(1), *self.get_point(2)];
+        let (low_x, high_x) = quadratic_extrema(p0.x, p1.x, p2.x);
+        let (low_y, high_y) = quadratic_extrema(p0.y, p1.y, p2.y);
+        let (low_z, high_z) = quadratic_extrema(p0.z, p1.z, p2.z);
+        Aabb3::new(Vector3::new(low_x, low_y, low_z), Vector3::new(high_x, high_y, high_z))
+    }
+    fn hull_bbox(&self) -> Aabb3<T> {
+        Aabb3::from_points(self.iter()).unwrap()
+    }
+}
+impl<T: FloatingPoint> BoundingBox3D<T> for CubicSegment<Vector3<T>> {
+    fn bbox(&self) -> Aabb3<T> {
+        let [p0, p1, p2, p3] = [*self.get_point(0), *self.get_point(1), *self.get_point(2), *self.get_point(3)];
+        let (low_x, high_x) = cubic_extrema(p0.x, p1.x, p2.x, p3.x);
+        let (low_y, high_y) = cubic_extrema(p0.y, p1.y, p2.y, p3.y);
+        let (low_z, high_z) = cubic_extrema(p0.z, p1.z, p2.z, p3.z);
+        Aabb3::new(Vector3::new(low_x, low_y, low_z), Vector3::new(high_x, high_y, high_z))
+    }
+    fn hull_bbox(&self) -> Aabb3<T> {
+        Aabb3::from_points(self.iter()).unwrap()
+    }
+}
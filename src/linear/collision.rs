@@ -0,0 +1,536 @@
+use std::ops::Mul;
+
+use num_traits::{Float, One, Zero};
+
+use crate::complex::quaternion::Quaternion;
+
+use super::{
+    Vector, Vector2, Vector3, Matrix3, Aabb2, Aabb3, Triangle, Sphere, Capsule, Obb, Simplex,
+    EuclideanGeometry, traits::FloatingPoint
+};
+
+/// # SupportMap
+///
+/// a convex shape described by its furthest point in every direction, which is all gjk and epa
+/// need to know about it.
+pub trait SupportMap<V: Vector> {
+    /// the point of the shape furthest along direction. direction doesn't need to be a unit vector.
+    fn support(&self, direction: V) -> V;
+}
+
+/// the point furthest along direction out of points, the first one when there are ties.
+fn furthest<'a, V: Vector + 'a>(points: impl IntoIterator<Item = &'a V>, direction: V) -> V {
+    let mut points = points.into_iter();
+    let first = *points.next().expect("a convex hull needs at least one point");
+    points.fold((first, first.dot(&direction)), |(best, best_dot), &point| {
+        let dot = point.dot(&direction);
+        if dot > best_dot { (point, dot) } else { (best, best_dot) }
+    }).0
+}
+
+impl<T: FloatingPoint> SupportMap<Vector2<T>> for Vector2<T> {
+    fn support(&self, _direction: Vector2<T>) -> Vector2<T> {
+        *self
+    }
+}
+impl<T: FloatingPoint> SupportMap<Vector3<T>> for Vector3<T> {
+    fn support(&self, _direction: Vector3<T>) -> Vector3<T> {
+        *self
+    }
+}
+/// the convex hull of the points, which can't be empty.
+impl<V: Vector> SupportMap<V> for [V] {
+    fn support(&self, direction: V) -> V {
+        furthest(self, direction)
+    }
+}
+/// the convex hull of the points, which can't be empty.
+impl<V: Vector, const N: usize> SupportMap<V> for [V; N] {
+    fn support(&self, direction: V) -> V {
+        furthest(self, direction)
+    }
+}
+/// the convex hull of the points, which can't be empty.
+impl<V: Vector> SupportMap<V> for Vec<V> {
+    fn support(&self, direction: V) -> V {
+        furthest(self, direction)
+    }
+}
+impl<V: Vector> SupportMap<V> for Triangle<V> {
+    fn support(&self, direction: V) -> V {
+        furthest(self.points(), direction)
+    }
+}
+impl<T: FloatingPoint> SupportMap<Vector2<T>> for Aabb2<T> {
+    fn support(&self, direction: Vector2<T>) -> Vector2<T> {
+        let pick = |d: T, min: T, max: T| if d < T::zero() { min } else { max };
+        Vector2::new(pick(direction.x, self.min.x, self.max.x), pick(direction.y, self.min.y, self.max.y))
+    }
+}
+impl<T: FloatingPoint> SupportMap<Vector3<T>> for Aabb3<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let pick = |d: T, min: T, max: T| if d < T::zero() { min } else { max };
+        Vector3::new(
+            pick(direction.x, self.min.x, self.max.x),
+            pick(direction.y, self.min.y, self.max.y),
+            pick(direction.z, self.min.z, self.max.z)
+        )
+    }
+}
+impl<T: FloatingPoint> SupportMap<Vector3<T>> for Obb<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        let (local, e) = (self.rotation * direction, self.half_extents);
+        let pick = |d: T, extent: T| if d < T::zero() { -extent } else { extent };
+        self.to_world(Vector3::new(pick(local.x, e.x), pick(local.y, e.y), pick(local.z, e.z)))
+    }
+}
+impl<T: FloatingPoint> SupportMap<Vector3<T>> for Sphere<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        Rounded::new(self.center, self.radius).support(direction)
+    }
+}
+impl<T: FloatingPoint> SupportMap<Vector3<T>> for Capsule<T> {
+    fn support(&self, direction: Vector3<T>) -> Vector3<T> {
+        Rounded::new([self.start, self.end], self.radius).support(direction)
+    }
+}
+
+/// # Rounded
+///
+/// every point within radius of shape, which makes circles and spheres out of points and capsules
+/// out of line segments in any dimension.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rounded<S, T> {
+    pub shape: S,
+    pub radius: T,
+}
+impl<S, T> Rounded<S, T> {
+    pub fn new(shape: S, radius: T) -> Self {
+        Self { shape, radius }
+    }
+}
+impl<V: Vector, S: SupportMap<V>> SupportMap<V> for Rounded<S, V::Scalar> where V::Scalar: FloatingPoint {
+    fn support(&self, direction: V) -> V {
+        let point = self.shape.support(direction);
+        let length = direction.length();
+        if length == V::Scalar::zero() {
+            return point;
+        }
+        point + direction * (self.radius / length)
+    }
+}
+
+/// # MinkowskiSum
+///
+/// every point of a added to every point of b, like sweeping b around the outline of a.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    pub a: A,
+    pub b: B,
+}
+impl<A, B> MinkowskiSum<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+impl<V: Vector, A: SupportMap<V>, B: SupportMap<V>> SupportMap<V> for MinkowskiSum<A, B> {
+    fn support(&self, direction: V) -> V {
+        self.a.support(direction) + self.b.support(direction)
+    }
+}
+
+/// # Transformed
+///
+/// a shape moved by a linear map followed by a translation, so every point p of the shape becomes
+/// `p * linear + translation` like the points of an [`Obb`]. the linear map can rotate, scale and
+/// skew the shape as long as it doesn't flatten it.
+#[derive(Clone, Copy, Debug)]
+pub struct Transformed<S, M, V> {
+    pub shape: S,
+    pub linear: M,
+    pub translation: V,
+}
+impl<S, M, V> Transformed<S, M, V> {
+    pub fn new(shape: S, linear: M, translation: V) -> Self {
+        Self { shape, linear, translation }
+    }
+}
+impl<S, T: FloatingPoint> Transformed<S, Matrix3<T>, Vector3<T>> {
+    /// the shape turned by a unit quaternion and then moved by translation.
+    pub fn from_quaternion(shape: S, rotation: Quaternion<T>, translation: Vector3<T>) -> Self {
+        Self { shape, linear: Matrix3::from(rotation), translation }
+    }
+}
+impl<V, M, S> SupportMap<V> for Transformed<S, M, V>
+    where V: Vector + Mul<M, Output = V>, M: Copy + Mul<V, Output = V>, S: SupportMap<V> {
+    fn support(&self, direction: V) -> V {
+        // the point of the shape furthest along direction after the map is the one furthest along
+        // the direction taken back through the transpose of the map
+        self.shape.support(self.linear * direction) * self.linear + self.translation
+    }
+}
+
+/// # Separation
+///
+/// how far apart two shapes that don't touch are, along with the closest points of both.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Separation<V: Vector> {
+    pub distance: V::Scalar,
+    /// the point of the first shape closest to the second.
+    pub point_a: V,
+    /// the point of the second shape closest to the first.
+    pub point_b: V,
+}
+/// # Gjk
+///
+/// the outcome of [`gjk_2d`] or [`gjk_3d`].
+#[derive(Clone, Copy, Debug)]
+pub enum Gjk<V: Vector + Zero, const N: usize> {
+    /// the shapes overlap or touch, with the simplex of points of a - b around the origin that shows
+    /// it. the simplex can be passed on to [`epa_2d`] or [`epa_3d`] to find how deep the overlap is.
+    Intersecting(Simplex<V, N>),
+    Separated(Separation<V>),
+}
+impl<V: Vector + Zero, const N: usize> Gjk<V, N> {
+    pub fn is_intersecting(&self) -> bool {
+        matches!(self, Gjk::Intersecting(_))
+    }
+    /// the distance between the shapes, 0 when they intersect.
+    pub fn distance(&self) -> V::Scalar {
+        match self {
+            Gjk::Intersecting(_) => V::Scalar::zero(),
+            Gjk::Separated(separation) => separation.distance,
+        }
+    }
+}
+/// # Penetration
+///
+/// how far two overlapping shapes go into each other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Penetration<V: Vector> {
+    pub depth: V::Scalar,
+    /// the unit direction from the first shape towards the second. moving the second shape depth
+    /// along it, or the first shape depth against it, leaves them just touching.
+    pub normal: V,
+}
+
+/// how many times gjk and epa refine their answer before settling for the best one so far.
+const MAX_ITERATIONS: usize = 128;
+
+/// answers are accurate up to this fraction of their size.
+fn tolerance<T: FloatingPoint>() -> T {
+    T::epsilon().sqrt()
+}
+
+/// solves the first n rows and columns of a x = b with gaussian elimination, or [`None`] when
+/// the system is too close to singular to trust.
+fn solve<T: FloatingPoint>(mut a: [[T; 3]; 3], mut b: [T; 3], n: usize) -> Option<[T; 3]> {
+    let scale = (0..n).map(|i| a[i][i].abs()).fold(T::zero(), T::max);
+    for column in 0..n {
+        let pivot = (column..n).fold(column, |best, row| if a[row][column].abs() > a[best][column].abs() { row } else { best });
+        if a[pivot][column].abs() <= T::epsilon() * scale * (T::one() + T::one()).powi(4) {
+            return None;
+        }
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+        for row in column + 1..n {
+            let factor = a[row][column] / a[column][column];
+            let pivot_row = a[column];
+            for (target, value) in a[row][column..n].iter_mut().zip(&pivot_row[column..n]) {
+                *target -= factor * *value;
+            }
+            let value = b[column];
+            b[row] -= factor * value;
+        }
+    }
+    let mut x = [T::zero(); 3];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(b[row], |sum, k| sum - a[row][k] * x[k]);
+        x[row] = sum / a[row][row];
+    }
+    Some(x)
+}
+
+/// # closest_to_origin
+///
+/// the point of the simplex made from up to 4 points closest to the origin, along with the weights
+/// of the points that add up to it. every face of the simplex is tried, which for so few points is
+/// simpler and sturdier than working out which region of the simplex the origin is in. points with a
+/// weight of 0 aren't needed to make the closest point.
+fn closest_to_origin<V: Vector>(points: &[V]) -> (V, [V::Scalar; 4]) where V::Scalar: FloatingPoint {
+    let zero = V::Scalar::zero();
+    let mut best: Option<(V::Scalar, V, [V::Scalar; 4])> = None;
+    for mask in 1..1usize << points.len() {
+        let mut subset = [0; 4];
+        let mut len = 0;
+        for i in (0..points.len()).filter(|i| mask & 1 << i != 0) {
+            subset[len] = i;
+            len += 1;
+        }
+        // the closest point of the plane through the subset is origin + edges · lambda
+        let origin = points[subset[0]];
+        let edges: [V; 3] = std::array::from_fn(|i| if i + 1 < len { points[subset[i + 1]] - origin } else { origin });
+        let k = len - 1;
+        let mut gram = [[zero; 3]; 3];
+        let mut rhs = [zero; 3];
+        for i in 0..k {
+            for j in 0..k {
+                gram[i][j] = edges[i].dot(&edges[j]);
+            }
+            rhs[i] = -edges[i].dot(&origin);
+        }
+        let Some(lambda) = solve(gram, rhs, k) else { continue };
+        let mut weights = [zero; 4];
+        weights[subset[0]] = V::Scalar::one();
+        let mut point = origin;
+        for i in 0..k {
+            weights[subset[i + 1]] = lambda[i];
+            weights[subset[0]] -= lambda[i];
+            point += edges[i] * lambda[i];
+        }
+        if subset[..len].iter().any(|&i| weights[i] < zero) {
+            continue;
+        }
+        let distance = point.dot(&point);
+        if best.is_none_or(|(best, ..)| distance < best) {
+            best = Some((distance, point, weights));
+        }
+    }
+    let (_, point, weights) = best.expect("every single point is its own closest point");
+    (point, weights)
+}
+
+/// the gjk distance algorithm from
+/// [Gilbert, Johnson and Keerthi's A fast procedure for computing the distance between complex objects in three-dimensional space](https://doi.org/10.1109/56.2083).
+/// it walks a simplex of points of the minkowski difference a - b towards the origin, which is
+/// inside of it exactly when the shapes overlap. N is one more than the dimension.
+fn gjk<V, A, B, const N: usize>(a: &A, b: &B, initial: V) -> Gjk<V, N>
+    where V: Vector + Zero, V::Scalar: FloatingPoint, A: SupportMap<V> + ?Sized, B: SupportMap<V> + ?Sized {
+    let tolerance = tolerance::<V::Scalar>();
+    let support = |direction: V| {
+        let from_a = a.support(direction);
+        (from_a - b.support(direction * -V::Scalar::one()), from_a)
+    };
+    // the points of a the points of the simplex came from, to find the closest points at the end
+    let (w, from_a) = support(initial);
+    let (mut points, mut from_as) = (Simplex::<V, N>::new(), Simplex::<V, N>::new());
+    points.push(w);
+    from_as.push(from_a);
+    let mut scale = w.dot(&w);
+    let mut closest = (w, [V::Scalar::one(), V::Scalar::zero(), V::Scalar::zero(), V::Scalar::zero()]);
+
+    for _ in 0..MAX_ITERATIONS {
+        let (v, weights) = closest_to_origin(&points.points[..points.size]);
+        let kept: Vec<usize> = (0..points.size).filter(|&i| weights[i] > V::Scalar::zero()).collect();
+        let mut kept_weights = [V::Scalar::zero(); 4];
+        for (to, &from) in kept.iter().enumerate() {
+            kept_weights[to] = weights[from];
+        }
+        points = Simplex::from_slice(&kept.iter().map(|&i| points.points[i]).collect::<Vec<_>>());
+        from_as = Simplex::from_slice(&kept.iter().map(|&i| from_as.points[i]).collect::<Vec<_>>());
+        closest = (v, kept_weights);
+
+        let vv = v.dot(&v);
+        if points.size == N || vv <= tolerance * tolerance * scale {
+            return Gjk::Intersecting(points);
+        }
+        let (w, from_a) = support(v * -V::Scalar::one());
+        scale = scale.max(w.dot(&w));
+        // w can't get any closer to the origin than v, so the distance is found
+        if vv - v.dot(&w) <= tolerance * vv || points.points[..points.size].contains(&w) {
+            break;
+        }
+        points.push(w);
+        from_as.push(from_a);
+        // push puts the new point first, so the weights have to move along with the points
+        closest.1.rotate_right(1);
+        closest.1[0] = V::Scalar::zero();
+    }
+    let (v, weights) = closest;
+    let point_a = (0..from_as.size).fold(from_as.points[0] * V::Scalar::zero(), |sum, i| sum + from_as.points[i] * weights[i]);
+    Gjk::Separated(Separation { distance: v.dot(&v).sqrt(), point_a, point_b: point_a - v })
+}
+
+/// # gjk_2d
+///
+/// whether two convex shapes in 2 dimensions overlap, and how far apart they are and their closest
+/// points when they don't. see [`epa_2d`] for how deep the overlap is.
+pub fn gjk_2d<T: FloatingPoint, A: SupportMap<Vector2<T>> + ?Sized, B: SupportMap<Vector2<T>> + ?Sized>(a: &A, b: &B) -> Gjk<Vector2<T>, 3> {
+    gjk(a, b, Vector2::new(T::one(), T::zero()))
+}
+/// # gjk_3d
+///
+/// whether two convex shapes in 3 dimensions overlap, and how far apart they are and their closest
+/// points when they don't. see [`epa_3d`] for how deep the overlap is.
+pub fn gjk_3d<T: FloatingPoint, A: SupportMap<Vector3<T>> + ?Sized, B: SupportMap<Vector3<T>> + ?Sized>(a: &A, b: &B) -> Gjk<Vector3<T>, 4> {
+    gjk(a, b, Vector3::new(T::one(), T::zero(), T::zero()))
+}
+
+/// adds points of the minkowski difference found along directions to points until they aren't flat,
+/// since shapes that only touch can leave gjk with a smaller simplex. independent tells whether a new
+/// point adds a dimension to the points. false when every direction stays flat.
+fn fill_simplex<V: Vector>(
+    points: &mut Vec<V>,
+    full: usize,
+    support: impl Fn(V) -> V,
+    directions: impl Fn(&[V]) -> Vec<V>,
+    independent: impl Fn(&[V], V) -> bool,
+) -> bool {
+    while points.len() < full {
+        let found = directions(points).into_iter().map(&support).find(|&w| independent(points, w));
+        match found {
+            Some(w) => points.push(w),
+            None => return false,
+        }
+    }
+    true
+}
+
+/// # epa_2d
+///
+/// how far two overlapping convex shapes in 2 dimensions go into each other, starting from the simplex
+/// [`gjk_2d`] found around the origin. it grows a polygon inside of the minkowski difference a - b
+/// towards the side closest to the origin using the expanding polytope algorithm from
+/// [van den Bergen's Proximity Queries and Penetration Depth Computation on 3D Game Objects](https://www.gdcvault.com/play/1014080/).
+/// [`None`] when the shapes are flat and only touch.
+pub fn epa_2d<T: FloatingPoint, A: SupportMap<Vector2<T>> + ?Sized, B: SupportMap<Vector2<T>> + ?Sized>(a: &A, b: &B, simplex: &Simplex<Vector2<T>, 3>) -> Option<Penetration<Vector2<T>>> {
+    let tolerance = tolerance::<T>();
+    let one = T::one();
+    let support = |direction: Vector2<T>| a.support(direction) - b.support(direction * -one);
+    let axes = [Vector2::new(one, T::zero()), Vector2::new(T::zero(), one), Vector2::new(-one, T::zero()), Vector2::new(T::zero(), -one)];
+    let mut polygon = simplex.to_vec();
+    let scale = polygon.iter().chain(axes.iter().map(|&axis| support(axis)).collect::<Vec<_>>().iter()).fold(T::zero(), |scale, p| scale.max(p.length()));
+    let filled = fill_simplex(&mut polygon, 3, support,
+        |points| match points {
+            [p0, p1] => {
+                let normal = (*p1 - *p0).get_orthonomal(true, true);
+                [normal, normal * -one].into_iter().chain(axes).collect()
+            }
+            _ => axes.to_vec(),
+        },
+        |points, w| match points {
+            [p0] => (w - *p0).length() > tolerance * scale,
+            [p0, p1] => (*p1 - *p0).cross(w - *p0).abs() > tolerance * scale * scale,
+            _ => false,
+        });
+    if !filled {
+        return None;
+    }
+    if (polygon[1] - polygon[0]).cross(polygon[2] - polygon[0]) < T::zero() {
+        polygon.swap(1, 2);
+    }
+
+    let mut best = None;
+    for _ in 0..MAX_ITERATIONS {
+        // the polygon goes counter clockwise so the outside of every edge is on its right
+        let (index, normal, distance) = (0..polygon.len()).filter_map(|i| {
+            let edge = polygon[(i + 1) % polygon.len()] - polygon[i];
+            let length = edge.length();
+            (length > T::zero()).then(|| {
+                let normal = Vector2::new(edge.y, -edge.x) / length;
+                (i, normal, normal.dot(&polygon[i]))
+            })
+        }).min_by(|x, y| x.2.partial_cmp(&y.2).unwrap_or(std::cmp::Ordering::Equal))?;
+        best = Some(Penetration { depth: distance.max(T::zero()), normal });
+        let w = support(normal);
+        if w.dot(&normal) - distance <= tolerance * scale {
+            break;
+        }
+        polygon.insert(index + 1, w);
+    }
+    best
+}
+
+/// the unit normal of the face and how far its plane is from the origin along it, with an infinite
+/// distance for faces without area.
+fn face_plane<T: FloatingPoint>(vertices: &[Vector3<T>], face: &[usize; 3]) -> (Vector3<T>, T) {
+    let [p0, p1, p2] = face.map(|i| vertices[i]);
+    let normal = (p1 - p0).cross(p2 - p0);
+    let length = normal.length();
+    if length == T::zero() {
+        return (normal, T::infinity());
+    }
+    let normal = normal / length;
+    (normal, normal.dot(&p0))
+}
+
+/// # epa_3d
+///
+/// how far two overlapping convex shapes in 3 dimensions go into each other, starting from the simplex
+/// [`gjk_3d`] found around the origin. it grows a polyhedron inside of the minkowski difference a - b
+/// towards the side closest to the origin using the expanding polytope algorithm from
+/// [van den Bergen's Proximity Queries and Penetration Depth Computation on 3D Game Objects](https://www.gdcvault.com/play/1014080/).
+/// [`None`] when the shapes are flat and only touch.
+pub fn epa_3d<T: FloatingPoint, A: SupportMap<Vector3<T>> + ?Sized, B: SupportMap<Vector3<T>> + ?Sized>(a: &A, b: &B, simplex: &Simplex<Vector3<T>, 4>) -> Option<Penetration<Vector3<T>>> {
+    let tolerance = tolerance::<T>();
+    let (zero, one) = (T::zero(), T::one());
+    let support = |direction: Vector3<T>| a.support(direction) - b.support(direction * -one);
+    let axes = [
+        Vector3::new(one, zero, zero), Vector3::new(zero, one, zero), Vector3::new(zero, zero, one),
+        Vector3::new(-one, zero, zero), Vector3::new(zero, -one, zero), Vector3::new(zero, zero, -one),
+    ];
+    let mut vertices = simplex.to_vec();
+    let scale = vertices.iter().chain(axes.iter().map(|&axis| support(axis)).collect::<Vec<_>>().iter()).fold(zero, |scale, p| scale.max(p.length()));
+    let filled = fill_simplex(&mut vertices, 4, support,
+        |points| match points {
+            [p0, p1] => {
+                let edge = *p1 - *p0;
+                axes.iter().map(|&axis| edge.cross(axis)).chain(axes).collect()
+            }
+            [p0, p1, p2] => {
+                let normal = (*p1 - *p0).cross(*p2 - *p0);
+                [normal, normal * -one].into_iter().chain(axes).collect()
+            }
+            _ => axes.to_vec(),
+        },
+        |points, w| match points {
+            [p0] => (w - *p0).length() > tolerance * scale,
+            [p0, p1] => (*p1 - *p0).cross(w - *p0).length() > tolerance * scale * scale,
+            [p0, p1, p2] => (*p1 - *p0).cross(*p2 - *p0).dot(&(w - *p0)).abs() > tolerance * scale * scale * scale,
+            _ => false,
+        });
+    if !filled {
+        return None;
+    }
+
+    // the faces of the polyhedron go counter clockwise seen from outside
+    let mut faces: Vec<[usize; 3]> = vec![[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]];
+    let inside = (vertices[0] + vertices[1] + vertices[2] + vertices[3]) / (one + one + one + one);
+    for face in faces.iter_mut() {
+        let [p0, p1, p2] = face.map(|i| vertices[i]);
+        if (p1 - p0).cross(p2 - p0).dot(&(inside - p0)) > zero {
+            face.swap(1, 2);
+        }
+    }
+    let mut best = None;
+    for _ in 0..MAX_ITERATIONS {
+        let (normal, distance) = faces.iter().map(|face| face_plane(&vertices, face)).min_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(std::cmp::Ordering::Equal))?;
+        if !distance.is_finite() {
+            break;
+        }
+        best = Some(Penetration { depth: distance.max(zero), normal });
+        let w = support(normal);
+        if w.dot(&normal) - distance <= tolerance * scale {
+            break;
+        }
+        // take away every face w can see and fill the hole with faces from its edge to w
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            let (normal, distance) = face_plane(&vertices, face);
+            if distance.is_finite() && normal.dot(&w) - distance <= zero {
+                return true;
+            }
+            for (from, to) in [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+                match horizon.iter().position(|&edge| edge == (to, from)) {
+                    Some(shared) => { horizon.swap_remove(shared); }
+                    None => horizon.push((from, to)),
+                }
+            }
+            false
+        });
+        vertices.push(w);
+        let new = vertices.len() - 1;
+        faces.extend(horizon.into_iter().map(|(from, to)| [from, to, new]));
+    }
+    best
+}
@@ -0,0 +1,307 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, EuclideanGeometry};
+use crate::linear::{Segment, FlatSegment, LinearSegment, QuadraticSegment, CubicSegment, BoundingBox2D, RayCrossings, RayCrossing, Aabb2, Number, FloatingPoint};
+
+/// # Edge
+///
+/// a single segment of a [`Contour`], which can be of any degree.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Edge<T: Number> {
+    Linear(LinearSegment<Vector2<T>>),
+    Quadratic(QuadraticSegment<Vector2<T>>),
+    Cubic(CubicSegment<Vector2<T>>),
+}
+/// # FillRule
+///
+/// decides which points are inside of a shape from how many times its contours wind around them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// inside when the contours wind around the point at all.
+    #[default]
+    NonZero,
+    /// inside when the contours wind around the point an odd number of times.
+    EvenOdd,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+/// # Contour
+///
+/// a closed loop of edges where every edge starts where the previous one ends
+/// and the last edge ends where the first one starts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contour<T: Number> {
+    edges: Vec<Edge<T>>,
+}
+/// # Shape
+///
+/// a collection of contours filled together, like the outline of a glyph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shape<T: Number> {
+    contours: Vec<Contour<T>>,
+}
+
+impl FillRule {
+    pub fn is_inside(&self, winding_number: i32) -> bool {
+        match self {
+            FillRule::NonZero => winding_number != 0,
+            FillRule::EvenOdd => winding_number % 2 != 0,
+        }
+    }
+}
+
+impl<T: Number> From<LinearSegment<Vector2<T>>> for Edge<T> {
+    fn from(value: LinearSegment<Vector2<T>>) -> Self {
+        Edge::Linear(value)
+    }
+}
+impl<T: Number> From<QuadraticSegment<Vector2<T>>> for Edge<T> {
+    fn from(value: QuadraticSegment<Vector2<T>>) -> Self {
+        Edge::Quadratic(value)
+    }
+}
+impl<T: Number> From<CubicSegment<Vector2<T>>> for Edge<T> {
+    fn from(value: CubicSegment<Vector2<T>>) -> Self {
+        Edge::Cubic(value)
+    }
+}
+
+impl<T: Number> Edge<T> {
+    pub fn start(&self) -> Vector2<T> {
+        match self {
+            Edge::Linear(segment) => *segment.start(),
+            Edge::Quadratic(segment) => *segment.start(),
+            Edge::Cubic(segment) => *segment.start(),
+        }
+    }
+    pub fn end(&self) -> Vector2<T> {
+        match self {
+            Edge::Linear(segment) => *segment.end(),
+            Edge::Quadratic(segment) => *segment.end(),
+            Edge::Cubic(segment) => *segment.end(),
+        }
+    }
+    pub fn points(&self) -> &[Vector2<T>] {
+        match self {
+            Edge::Linear(segment) => segment.iter().as_slice(),
+            Edge::Quadratic(segment) => segment.iter().as_slice(),
+            Edge::Cubic(segment) => segment.iter().as_slice(),
+        }
+    }
+    pub fn reverse(&self) -> Self {
+        match self {
+            Edge::Linear(segment) => Edge::Linear(segment.reverse()),
+            Edge::Quadratic(segment) => Edge::Quadratic(segment.reverse()),
+            Edge::Cubic(segment) => Edge::Cubic(segment.reverse()),
+        }
+    }
+}
+impl<T: FloatingPoint> Edge<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    pub fn interpolate(&self, t: T) -> Vector2<T> {
+        match self {
+            Edge::Linear(segment) => segment.interpolate(t),
+            Edge::Quadratic(segment) => segment.interpolate(t),
+            Edge::Cubic(segment) => segment.interpolate(t),
+        }
+    }
+    pub fn direction(&self, t: T) -> Vector2<T> {
+        match self {
+            Edge::Linear(segment) => segment.direction(t),
+            Edge::Quadratic(segment) => segment.direction(t),
+            Edge::Cubic(segment) => segment.direction(t),
+        }
+    }
+    /// splits the edge at t into the edge before t and the edge after t.
+    pub fn split_at(&self, t: T) -> (Self, Self) {
+        match self {
+            Edge::Linear(segment) => { let (a, b) = segment.split_at(t); (Edge::Linear(a), Edge::Linear(b)) }
+            Edge::Quadratic(segment) => { let (a, b) = segment.split_at(t); (Edge::Quadratic(a), Edge::Quadratic(b)) }
+            Edge::Cubic(segment) => { let (a, b) = segment.split_at(t); (Edge::Cubic(a), Edge::Cubic(b)) }
+        }
+    }
+    /// the signed distance to p along with the parameter of the closest point, which is outside of
+    /// 0 to 1 when the closest point is one of the ends.
+    pub fn signed_distance_param(&self, p: Vector2<T>) -> (T, T) {
+        match self {
+            Edge::Linear(segment) => segment.signed_distance_param(p),
+            Edge::Quadratic(segment) => segment.signed_distance_param(p),
+            Edge::Cubic(segment) => segment.signed_distance_param(p),
+        }
+    }
+    /// see [`FlatSegment::signed_distance`].
+    pub fn signed_distance(&self, p: Vector2<T>) -> T {
+        match self {
+            Edge::Linear(segment) => segment.signed_distance(p),
+            Edge::Quadratic(segment) => segment.signed_distance(p),
+            Edge::Cubic(segment) => segment.signed_distance(p),
+        }
+    }
+    /// see [`FlatSegment::signed_pseudo_distance`].
+    pub fn signed_pseudo_distance(&self, p: Vector2<T>) -> T {
+        match self {
+            Edge::Linear(segment) => segment.signed_pseudo_distance(p),
+            Edge::Quadratic(segment) => segment.signed_pseudo_distance(p),
+            Edge::Cubic(segment) => segment.signed_pseudo_distance(p),
+        }
+    }
+    pub fn bbox(&self) -> Aabb2<T> {
+        match self {
+            Edge::Linear(segment) => segment.bbox(),
+            Edge::Quadratic(segment) => segment.bbox(),
+            Edge::Cubic(segment) => segment.bbox(),
+        }
+    }
+    /// see [`RayCrossings::ray_crossings`].
+    pub fn ray_crossings(&self, origin: Vector2<T>, direction: Vector2<T>) -> Vec<RayCrossing<T>> {
+        match self {
+            Edge::Linear(segment) => segment.ray_crossings(origin, direction),
+            Edge::Quadratic(segment) => segment.ray_crossings(origin, direction),
+            Edge::Cubic(segment) => segment.ray_crossings(origin, direction),
+        }
+    }
+    /// the signed area between the edge and the origin, half of the integral of x dy - y dx
+    /// over the edge. adding it up over a closed contour gives the area of the contour.
+    fn area(&self) -> T {
+        let points = self.points();
+        let cross = |i: usize, j: usize| points[i].cross(points[j]);
+        match self {
+            Edge::Linear(_) => cross(0, 1) * 0.5.as_(),
+            Edge::Quadratic(_) => (cross(0, 1) * 2.0.as_() + cross(1, 2) * 2.0.as_() + cross(0, 2)) / 6.0.as_(),
+            Edge::Cubic(_) => (
+                cross(0, 1) * 6.0.as_() + cross(0, 2) * 3.0.as_() + cross(0, 3) +
+                cross(1, 2) * 3.0.as_() + cross(1, 3) * 3.0.as_() + cross(2, 3) * 6.0.as_()
+            ) / 20.0.as_(),
+        }
+    }
+}
+
+impl<T: Number> Contour<T> {
+    pub fn new() -> Self {
+        Self { edges: Vec::new() }
+    }
+    pub fn from_edges(edges: Vec<Edge<T>>) -> Self {
+        Self { edges }
+    }
+    pub fn edges(&self) -> &[Edge<T>] {
+        &self.edges
+    }
+    pub fn edges_mut(&mut self) -> &mut Vec<Edge<T>> {
+        &mut self.edges
+    }
+    pub fn push(&mut self, edge: impl Into<Edge<T>>) {
+        self.edges.push(edge.into());
+    }
+    /// whether every edge starts where the previous one ends, including the first edge starting
+    /// where the last one ends.
+    pub fn is_closed(&self) -> bool {
+        let next = self.edges.iter().cycle().skip(1);
+        self.edges.iter().zip(next).all(|(edge, next)| edge.end() == next.start())
+    }
+    /// flips the direction the contour goes around in.
+    pub fn reverse(&mut self) {
+        self.edges.reverse();
+        for edge in self.edges.iter_mut() {
+            *edge = edge.reverse();
+        }
+    }
+}
+impl<T: FloatingPoint> Contour<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// how many times the contour goes counter clockwise around p, negative when it goes clockwise.
+    pub fn winding_number(&self, p: Vector2<T>) -> i32 {
+        let direction = Vector2::new(T::one(), T::zero());
+        self.edges.iter()
+            .flat_map(|edge| edge.ray_crossings(p, direction))
+            .map(|crossing| crossing.winding)
+            .sum()
+    }
+    pub fn contains(&self, p: Vector2<T>, fill_rule: FillRule) -> bool {
+        fill_rule.is_inside(self.winding_number(p))
+    }
+    /// the area inside of the contour, positive when it goes counter clockwise and negative when it
+    /// goes clockwise. parts of a contour crossing over itself cancel out when they go the other way.
+    pub fn signed_area(&self) -> T {
+        self.edges.iter().fold(T::zero(), |area, edge| area + edge.area())
+    }
+    pub fn orientation(&self) -> Orientation {
+        if self.signed_area() < T::zero() { Orientation::Clockwise } else { Orientation::CounterClockwise }
+    }
+    /// the smallest box containing every edge, or [`None`] when there are no edges.
+    pub fn bounds(&self) -> Option<Aabb2<T>> {
+        self.edges.iter().map(|edge| edge.bbox()).reduce(|bounds, bbox| bounds.union(&bbox))
+    }
+    /// the distance from p to the closest edge, see [`FlatSegment::signed_distance`]. the sign is taken
+    /// from the closest edge so it depends on the orientation of the contour, being positive inside of
+    /// clockwise contours.
+    pub fn signed_distance(&self, p: Vector2<T>) -> T {
+        self.edges.iter()
+            .map(|edge| edge.signed_distance(p))
+            .fold(T::infinity(), |closest, distance| if distance.abs() < closest.abs() { distance } else { closest })
+    }
+}
+
+impl<T: Number> Shape<T> {
+    pub fn new() -> Self {
+        Self { contours: Vec::new() }
+    }
+    pub fn from_contours(contours: Vec<Contour<T>>) -> Self {
+        Self { contours }
+    }
+    pub fn contours(&self) -> &[Contour<T>] {
+        &self.contours
+    }
+    pub fn contours_mut(&mut self) -> &mut Vec<Contour<T>> {
+        &mut self.contours
+    }
+    pub fn push(&mut self, contour: Contour<T>) {
+        self.contours.push(contour);
+    }
+    pub fn edges(&self) -> impl Iterator<Item = &Edge<T>> {
+        self.contours.iter().flat_map(|contour| contour.edges.iter())
+    }
+    pub fn is_closed(&self) -> bool {
+        self.contours.iter().all(|contour| contour.is_closed())
+    }
+}
+impl<T: FloatingPoint> Shape<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// the sum of the winding numbers of every contour around p.
+    pub fn winding_number(&self, p: Vector2<T>) -> i32 {
+        self.contours.iter().map(|contour| contour.winding_number(p)).sum()
+    }
+    pub fn contains(&self, p: Vector2<T>, fill_rule: FillRule) -> bool {
+        fill_rule.is_inside(self.winding_number(p))
+    }
+    /// the sum of the signed areas of every contour, see [`Contour::signed_area`].
+    pub fn signed_area(&self) -> T {
+        self.contours.iter().fold(T::zero(), |area, contour| area + contour.signed_area())
+    }
+    /// the smallest box containing every contour, or [`None`] when there are no edges.
+    pub fn bounds(&self) -> Option<Aabb2<T>> {
+        self.contours.iter().filter_map(|contour| contour.bounds()).reduce(|bounds, bbox| bounds.union(&bbox))
+    }
+    /// the distance from p to the closest edge of any contour, positive when p is inside of the
+    /// shape and negative when it's outside. unlike [`Contour::signed_distance`] this doesn't
+    /// depend on the orientation of the contours.
+    pub fn signed_distance(&self, p: Vector2<T>, fill_rule: FillRule) -> T {
+        let distance = self.edges()
+            .map(|edge| edge.signed_distance(p).abs())
+            .fold(T::infinity(), |closest, distance| closest.min(distance));
+        if self.contains(p, fill_rule) { distance } else { -distance }
+    }
+}
+
+impl<T: Number> Default for Contour<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Number> Default for Shape<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,200 @@
+use num_traits::{AsPrimitive, Float, One, Zero};
+
+use crate::linear::{Vector, Segment, QuadraticSegment, CubicSegment, FloatingPoint};
+
+/// the most lines a curve is ever flattened into, which is what a tolerance that's too small ends up with.
+const MAX_LINES: usize = 1 << MAX_DEPTH;
+/// how many times a cubic can be split in half, which bounds the stack of the flattening.
+const MAX_DEPTH: usize = 16;
+
+/// approximation of the integral of sqrt(1 + 4x²) scaled so the result is the amount of lines
+/// the matching part of a parabola needs. from Raph Levien's
+/// [flattening quadratic béziers](https://raphlinus.github.io/graphics/curves/2019/12/23/flatten-quadbez.html).
+fn approx_parabola_integral<T: FloatingPoint>(x: T) -> T
+    where f64: AsPrimitive<T> {
+    let d: T = 0.67.as_();
+    x / (T::one() - d + (d * d * d * d + 0.25.as_() * x * x).sqrt().sqrt())
+}
+/// approximation of the inverse of approx_parabola_integral.
+fn approx_parabola_inv_integral<T: FloatingPoint>(x: T) -> T
+    where f64: AsPrimitive<T> {
+    let b: T = 0.39.as_();
+    x * (T::one() - b + (b * b + 0.25.as_() * x * x).sqrt())
+}
+/// rounds the amount of lines up and keeps it between 1 and MAX_LINES.
+fn line_count<T: FloatingPoint>(lines: T) -> usize
+    where f64: AsPrimitive<T> {
+    if lines > T::one() {
+        lines.min((MAX_LINES as f64).as_()).ceil().to_usize().unwrap_or(1)
+    } else {
+        1
+    }
+}
+
+/// where along a parabola the points of a flattened quadratic are placed, spacing them so that
+/// every line has the same error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ParabolaParams<T> {
+    a0: T,
+    da: T,
+    u0: T,
+    uscale: T,
+}
+
+/// # QuadraticFlatten
+///
+/// the points of a polyline approximating a quadratic segment, starting with the start of the segment
+/// and ending with its end. made by [QuadraticSegment::flatten].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticFlatten<T: Vector> {
+    segment: QuadraticSegment<T>,
+    params: Option<ParabolaParams<T::Scalar>>,
+    lines: usize,
+    index: usize,
+}
+
+impl<T: Vector> QuadraticSegment<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// # flatten
+    ///
+    /// approximates the curve with the fewest lines that are never further than tolerance away from it,
+    /// returning the points of the polyline. every quadratic is part of a parabola, so the amount of lines
+    /// and where they go is worked out directly from the integral that measures how far a parabola bends
+    /// as described by Raph Levien's [flattening quadratic béziers](https://raphlinus.github.io/graphics/curves/2019/12/23/flatten-quadbez.html).
+    /// a tolerance that isn't positive flattens into the most lines possible.
+    pub fn flatten(&self, tolerance: T::Scalar) -> QuadraticFlatten<T> {
+        let (p0, p1, p2) = (*self.get_point(0), *self.get_point(1), *self.get_point(2));
+        let tolerance = tolerance.max(T::Scalar::min_positive_value());
+        let sqrt_tolerance = tolerance.sqrt();
+        let d01 = p1 - p0;
+        let d12 = p2 - p1;
+        let dd = d01 - d12;
+        // the size of the cross product of the chord and the second difference, which works in any dimension
+        let chord = p2 - p0;
+        let dd_squared = dd.dot(&dd);
+        let cross = (chord.dot(&chord) * dd_squared - chord.dot(&dd) * chord.dot(&dd)).max(T::Scalar::zero()).sqrt();
+        // map the curve onto the parabola y = x² where it runs from x0 to x2
+        let x0 = d01.dot(&dd) / cross;
+        let x2 = d12.dot(&dd) / cross;
+        let scale = cross / (dd_squared.sqrt() * (x2 - x0).abs());
+        if !(scale.is_finite() && scale > T::Scalar::zero()) {
+            // the control point is in line with the ends, split evenly so any part that doubles back is kept
+            let lines = (dd_squared.sqrt() / (tolerance * 4.0.as_())).sqrt();
+            return QuadraticFlatten { segment: *self, params: None, lines: line_count(lines), index: 0 };
+        }
+        let a0 = approx_parabola_integral(x0);
+        let a2 = approx_parabola_integral(x2);
+        let da = a2 - a0;
+        let sqrt_scale = scale.sqrt();
+        let value = if x0.signum() == x2.signum() {
+            da.abs() * sqrt_scale
+        } else {
+            // the curve goes around the tip of the parabola
+            let x_min = sqrt_tolerance / sqrt_scale;
+            sqrt_tolerance * da.abs() / approx_parabola_integral(x_min)
+        };
+        let u0 = approx_parabola_inv_integral(a0);
+        let u2 = approx_parabola_inv_integral(a2);
+        let params = ParabolaParams { a0, da, u0, uscale: T::Scalar::one() / (u2 - u0) };
+        QuadraticFlatten { segment: *self, params: Some(params), lines: line_count(value * 0.5.as_() / sqrt_tolerance), index: 0 }
+    }
+}
+
+impl<T: Vector> Iterator for QuadraticFlatten<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.index > self.lines {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        if index == 0 {
+            return Some(*self.segment.start());
+        }
+        if index == self.lines {
+            return Some(*self.segment.end());
+        }
+        let x: T::Scalar = (index as f64 / self.lines as f64).as_();
+        let t = match self.params {
+            Some(params) => (approx_parabola_inv_integral(params.a0 + params.da * x) - params.u0) * params.uscale,
+            None => x,
+        };
+        Some(self.segment.interpolate(t))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.lines + 1 - self.index.min(self.lines + 1);
+        (remaining, Some(remaining))
+    }
+}
+impl<T: Vector> ExactSizeIterator for QuadraticFlatten<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {}
+
+/// # CubicFlatten
+///
+/// the points of a polyline approximating a cubic segment, starting with the start of the segment
+/// and ending with its end. made by [CubicSegment::flatten].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicFlatten<T: Vector> {
+    /// the parts of the curve that still have to be flattened with how deep they were split, the
+    /// last one being the next part of the curve. splitting in half depth first never needs more
+    /// than one part for every level.
+    stack: [(CubicSegment<T>, usize); MAX_DEPTH + 1],
+    len: usize,
+    tolerance: T::Scalar,
+    started: bool,
+}
+
+impl<T: Vector> CubicSegment<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// # flatten
+    ///
+    /// approximates the curve with lines that are never further than tolerance away from it, returning
+    /// the points of the polyline. the curve is split in half until every part is flat enough, which is
+    /// when the control points are close enough to the chord that the error bound
+    /// ¼max(|3p1 - 2p0 - p3|, |3p2 - p0 - 2p3|) is within tolerance. no part is split more than 16 times
+    /// so a tolerance that isn't positive flattens into the most lines possible.
+    pub fn flatten(&self, tolerance: T::Scalar) -> CubicFlatten<T> {
+        CubicFlatten { stack: [(*self, 0); MAX_DEPTH + 1], len: 1, tolerance, started: false }
+    }
+}
+
+impl<T: Vector> CubicFlatten<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    fn is_flat(&self, segment: &CubicSegment<T>) -> bool {
+        let (p0, p1, p2, p3) = (*segment.get_point(0), *segment.get_point(1), *segment.get_point(2), *segment.get_point(3));
+        let three: T::Scalar = 3.0.as_();
+        let u = p1 * three - p0 - p0 - p3;
+        let v = p2 * three - p0 - p3 - p3;
+        let error = u.dot(&u).max(v.dot(&v));
+        let bound = self.tolerance * 4.0.as_();
+        self.tolerance >= T::Scalar::zero() && error <= bound * bound
+    }
+}
+
+impl<T: Vector> Iterator for CubicFlatten<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if !self.started {
+            self.started = true;
+            return Some(*self.stack[0].0.start());
+        }
+        while self.len > 0 {
+            let (segment, depth) = self.stack[self.len - 1];
+            if depth == MAX_DEPTH || self.is_flat(&segment) {
+                self.len -= 1;
+                return Some(*segment.end());
+            }
+            let (first, second) = segment.split_at(0.5.as_());
+            self.stack[self.len - 1] = (second, depth + 1);
+            self.stack[self.len] = (first, depth + 1);
+            self.len += 1;
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self.len + usize::from(!self.started);
+        (lower, Some(lower.max(1) * MAX_LINES))
+    }
+}
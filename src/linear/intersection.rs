@@ -0,0 +1,514 @@
+use std::cmp::Ordering;
+
+use num_traits::{AsPrimitive, Zero};
+
+use crate::{Vector2, EuclideanGeometry};
+use crate::linear::{Vector, Segment, LinearSegment, QuadraticSegment, CubicSegment, BoundingBox2D, Number, FloatingPoint};
+use crate::equations::CubicSolution;
+
+/// # SegmentIntersection
+///
+/// a point where two segments meet, along with the parameter of the point on each segment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SegmentIntersection<T: Number> {
+    /// the parameter of the intersection on the segment intersect was called on.
+    pub t0: T,
+    /// the parameter of the intersection on the other segment.
+    pub t1: T,
+    pub point: Vector2<T>,
+}
+/// # RayCrossing
+///
+/// a point where a segment meets a ray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayCrossing<T: Number> {
+    /// the parameter of the crossing on the segment.
+    pub t: T,
+    /// how far along the ray the crossing is, in multiples of the ray's direction.
+    pub distance: T,
+    pub point: Vector2<T>,
+    /// 1 when the segment crosses the ray from its right to its left (counter clockwise around the
+    /// origin of the ray), -1 when it crosses from left to right and 0 when it only touches the ray.
+    /// the windings of every crossing of a closed contour add up to its winding number around the origin.
+    pub winding: i32,
+}
+
+pub trait Intersect<Rhs = Self> {
+    type Scalar: Number;
+    /// every point where the two segments meet, sorted by the parameter on this segment.
+    /// when the segments overlap only the ends of the overlap are returned.
+    fn intersect(&self, other: &Rhs) -> Vec<SegmentIntersection<Self::Scalar>>;
+}
+pub trait RayCrossings {
+    type Scalar: Number;
+    /// every point where the ray starting at origin and going along direction meets
+    /// the segment, sorted by the parameter on the segment.
+    fn ray_crossings(&self, origin: Vector2<Self::Scalar>, direction: Vector2<Self::Scalar>) -> Vec<RayCrossing<Self::Scalar>>;
+}
+
+/// the coefficients of the segment written as c0 + c1 t + c2 t^2 + c3 t^3.
+trait PowerBasis<T: FloatingPoint> {
+    fn power_basis(&self) -> [Vector2<T>; 4];
+}
+impl<T: FloatingPoint> PowerBasis<T> for LinearSegment<Vector2<T>> {
+    fn power_basis(&self) -> [Vector2<T>; 4] {
+        let [p0, p1] = [*self.get_point(0), *self.get_point(1)];
+        [p0, p1 - p0, Vector2::zero(), Vector2::zero()]
+    }
+}
+impl<T: FloatingPoint> PowerBasis<T> for QuadraticSegment<Vector2<T>> {
+    fn power_basis(&self) -> [Vector2<T>; 4] {
+        let [p0, p1, p2] = [*self.get_point(0), *self.get_point(1), *self.get_point(2)];
+        [p0, (p1 - p0) * (T::one() + T::one()), p0 - p1 - p1 + p2, Vector2::zero()]
+    }
+}
+impl<T: FloatingPoint> PowerBasis<T> for CubicSegment<Vector2<T>> {
+    fn power_basis(&self) -> [Vector2<T>; 4] {
+        let [p0, p1, p2, p3] = [*self.get_point(0), *self.get_point(1), *self.get_point(2), *self.get_point(3)];
+        let three = T::one() + T::one() + T::one();
+        [p0, (p1 - p0) * three, (p0 - p1 - p1 + p2) * three, p3 - p0 + (p1 - p2) * three]
+    }
+}
+
+/// parameters closer than this to each other are treated as the same point.
+fn parameter_epsilon<T: FloatingPoint>() -> T {
+    T::epsilon().sqrt()
+}
+fn evaluate<T: FloatingPoint>(c: &[T; 4], t: T) -> T {
+    ((c[3] * t + c[2]) * t + c[1]) * t + c[0]
+}
+
+/// the roots between 0 and 1 of c0 + c1 t + c2 t^2 + c3 t^3 found with the cubic formula and polished with
+/// newton's method, or [`None`] when every coefficient is small compared to reference, making the
+/// polynomial 0 everywhere. roots just outside of 0 to 1 or close to either end are moved onto that end.
+fn unit_roots<T: FloatingPoint>(c: [T; 4], reference: T) -> Option<Vec<T>> {
+    let scale = c.iter().fold(T::zero(), |scale, c| scale.max(c.abs()));
+    if scale <= reference * T::epsilon() * (T::one() + T::one()).powi(6) {
+        return None;
+    }
+    // the cubic formula loses precision when the leading coefficients are tiny. dropping them
+    // barely changes the polynomial between 0 and 1 and polishing fixes the difference.
+    let tiny = scale * T::epsilon().sqrt() * T::epsilon().sqrt().sqrt();
+    let (a, b) = match (c[3].abs() <= tiny, c[2].abs() <= tiny) {
+        (true, true) => (T::zero(), T::zero()),
+        (true, false) => (T::zero(), c[2]),
+        _ => (c[3], c[2]),
+    };
+    let epsilon = parameter_epsilon::<T>();
+    let mut candidates = Vec::with_capacity(3);
+    match T::cubic_formula(a, b, c[1], c[0]) {
+        CubicSolution::OneReal(r) => candidates.push(r),
+        CubicSolution::TwoReal(r) => candidates.extend_from_slice(&r),
+        CubicSolution::ThreeReal(r) => candidates.extend_from_slice(&r),
+        CubicSolution::OneRealTwoComplex(r, complex) => {
+            candidates.push(r);
+            // a touching root can end up slightly complex from rounding errors
+            candidates.extend(complex.iter().filter(|root| root.im.abs() <= epsilon).map(|root| root.re));
+        }
+        CubicSolution::TwoComplex(complex) => {
+            candidates.extend(complex.iter().filter(|root| root.im.abs() <= epsilon).map(|root| root.re));
+        }
+        CubicSolution::None(_) | CubicSolution::All(_) => {}
+    }
+    let derivative = [c[1], c[2] + c[2], c[3] * (T::one() + T::one() + T::one()), T::zero()];
+    let mut roots: Vec<T> = candidates.into_iter().filter_map(|mut t| {
+        let mut ft = evaluate(&c, t);
+        for _ in 0..4 {
+            let df = evaluate(&derivative, t);
+            if df == T::zero() { break; }
+            let next = t - ft / df;
+            let f_next = evaluate(&c, next);
+            if f_next.abs() >= ft.abs() { break; }
+            t = next;
+            ft = f_next;
+        }
+        if t < -epsilon || t > T::one() + epsilon || !t.is_finite() {
+            return None;
+        }
+        // roots this close to the ends are usually the ends themselves
+        if t <= epsilon {
+            return Some(T::zero());
+        } else if t >= T::one() - epsilon {
+            return Some(T::one());
+        }
+        Some(t)
+    }).collect();
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    roots.dedup_by(|a, b| (*a - *b).abs() <= epsilon);
+    Some(roots)
+}
+
+/// the parameter of the projection of p onto the line from start going along direction.
+fn line_param<T: FloatingPoint>(start: Vector2<T>, direction: Vector2<T>, p: Vector2<T>) -> T {
+    (p - start).dot(&direction) / direction.dot(&direction)
+}
+/// the coefficients of cross(direction, c(t) - origin), which is 0 where the curve meets the line.
+fn side_coefficients<T: FloatingPoint>(basis: &[Vector2<T>; 4], origin: Vector2<T>, direction: Vector2<T>) -> [T; 4] {
+    [direction.cross(basis[0] - origin), direction.cross(basis[1]), direction.cross(basis[2]), direction.cross(basis[3])]
+}
+/// a size to compare the coefficients of the curve against.
+fn basis_reference<T: FloatingPoint>(basis: &[Vector2<T>; 4], origin: Vector2<T>, direction: Vector2<T>) -> T {
+    direction.length() * ((basis[0] - origin).length() + basis[1].length() + basis[2].length() + basis[3].length())
+}
+
+/// the intersections of the line segment from start to end with a curve, where t0 is the parameter on the line.
+fn line_curve<T: FloatingPoint, S: Segment<VectorType = Vector2<T>> + PowerBasis<T>>(start: Vector2<T>, end: Vector2<T>, curve: &S) -> Vec<SegmentIntersection<T>> {
+    let direction = end - start;
+    if direction == Vector2::zero() {
+        return Vec::new();
+    }
+    let basis = curve.power_basis();
+    let reference = basis_reference(&basis, start, direction);
+    let candidates = match unit_roots(side_coefficients(&basis, start, direction), reference) {
+        Some(roots) => roots,
+        None => {
+            // the curve lies on the line, so it can only start or stop overlapping the line at the ends of either
+            let mut candidates = vec![T::zero(), T::one()];
+            for p in [start, end] {
+                let along = [direction.dot(&(basis[0] - p)), direction.dot(&basis[1]), direction.dot(&basis[2]), direction.dot(&basis[3])];
+                candidates.extend(unit_roots(along, reference).unwrap_or_default());
+            }
+            candidates
+        }
+    };
+    let epsilon = parameter_epsilon::<T>();
+    let mut intersections: Vec<SegmentIntersection<T>> = candidates.into_iter().filter_map(|t| {
+        let point = curve.interpolate(t);
+        let s = line_param(start, direction, point);
+        if s < -epsilon || s > T::one() + epsilon {
+            return None;
+        }
+        Some(SegmentIntersection { t0: s.max(T::zero()).min(T::one()), t1: t, point })
+    }).collect();
+    sort_and_dedup(&mut intersections);
+    intersections
+}
+
+fn line_line<T: FloatingPoint>(a: &LinearSegment<Vector2<T>>, b: &LinearSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+    let (p, r) = (*a.get_point(0), *a.get_point(1) - *a.get_point(0));
+    let (q, s) = (*b.get_point(0), *b.get_point(1) - *b.get_point(0));
+    let denominator = r.cross(s);
+    let epsilon = parameter_epsilon::<T>();
+    if denominator.abs() <= T::epsilon() * r.length() * s.length() {
+        // parallel lines only meet when they lie on top of each other
+        return line_curve(p, p + r, b);
+    }
+    let t0 = (q - p).cross(s) / denominator;
+    let t1 = (q - p).cross(r) / denominator;
+    let range = -epsilon..=T::one() + epsilon;
+    if !range.contains(&t0) || !range.contains(&t1) {
+        return Vec::new();
+    }
+    let t0 = t0.max(T::zero()).min(T::one());
+    vec![SegmentIntersection { t0, t1: t1.max(T::zero()).min(T::one()), point: a.interpolate(t0) }]
+}
+
+fn swap<T: FloatingPoint>(mut intersections: Vec<SegmentIntersection<T>>) -> Vec<SegmentIntersection<T>> {
+    for intersection in intersections.iter_mut() {
+        std::mem::swap(&mut intersection.t0, &mut intersection.t1);
+    }
+    sort_and_dedup(&mut intersections);
+    intersections
+}
+fn sort_and_dedup<T: FloatingPoint>(intersections: &mut Vec<SegmentIntersection<T>>) {
+    let epsilon = parameter_epsilon::<T>();
+    // segments with coordinates that aren't numbers give parameters that can't be ordered
+    intersections.retain(|intersection| intersection.t0.is_finite() && intersection.t1.is_finite());
+    intersections.sort_by(|a, b| a.t0.partial_cmp(&b.t0).unwrap_or(Ordering::Equal));
+    intersections.dedup_by(|a, b| (a.t0 - b.t0).abs() <= epsilon && (a.t1 - b.t1).abs() <= epsilon);
+}
+
+/// the deepest bezier clipping goes before giving up on narrowing down an intersection.
+const CLIP_MAX_DEPTH: usize = 48;
+/// when clipping keeps more than this much of a curve the curve is split in half instead.
+const CLIP_MIN_REDUCTION: f64 = 0.8;
+
+/// the intersections of two cubics found with bezier clipping, from
+/// [Sederberg and Nishita's Curve intersection using Bézier clipping](https://doi.org/10.1016/0010-4485(90)90039-F).
+/// quadratics are elevated into cubics first, which keeps their parameters the same.
+fn curve_curve<T: FloatingPoint>(a: &CubicSegment<Vector2<T>>, b: &CubicSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    if !a.hull_bbox().overlaps(&b.hull_bbox()) {
+        return Vec::new();
+    }
+    let mut parameters = match overlap(a, b) {
+        Some(parameters) => parameters,
+        None => {
+            let mut parameters = Vec::new();
+            let range = (T::zero(), T::one());
+            clip(a, range, b, range, false, 0, &mut parameters);
+            parameters
+        }
+    };
+    parameters.retain(|(t0, t1)| t0.is_finite() && t1.is_finite());
+    let mut intersections: Vec<SegmentIntersection<T>> = parameters.into_iter()
+        .map(|(t0, t1)| SegmentIntersection { t0, t1, point: a.interpolate(t0) })
+        .collect();
+    sort_and_dedup(&mut intersections);
+    intersections
+}
+
+/// the ends of the part where the curves lie on top of each other, or [`None`] when they don't.
+fn overlap<T: FloatingPoint>(a: &CubicSegment<Vector2<T>>, b: &CubicSegment<Vector2<T>>) -> Option<Vec<(T, T)>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let size = a.hull_bbox().union(&b.hull_bbox()).size();
+    let tolerance = size.x.max(size.y) * parameter_epsilon::<T>();
+    let epsilon = parameter_epsilon::<T>();
+    let on = |curve: &CubicSegment<Vector2<T>>, p: Vector2<T>| {
+        let (distance, t) = curve.signed_distance_param(p);
+        if distance.abs() <= tolerance && t >= -epsilon && t <= T::one() + epsilon {
+            return Some(t.max(T::zero()).min(T::one()));
+        }
+        None
+    };
+    let mut ends = Vec::new();
+    for t in [T::zero(), T::one()] {
+        if let Some(u) = on(b, a.interpolate(t)) {
+            ends.push((t, u));
+        }
+        if let Some(u) = on(a, b.interpolate(t)) {
+            ends.push((u, t));
+        }
+    }
+    ends.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    let (first, last) = (*ends.first()?, *ends.last()?);
+    if last.0 - first.0 <= epsilon {
+        return None;
+    }
+    // the curves may only touch at the ends, so check that they stay together in between
+    const SAMPLES: usize = 8;
+    for i in 1..SAMPLES {
+        let s: T = (i as f32 / SAMPLES as f32).as_();
+        let p = a.interpolate(first.0 + (last.0 - first.0) * s);
+        let q = b.interpolate(first.1 + (last.1 - first.1) * s);
+        if on(b, p).is_none() || on(a, q).is_none() {
+            return None;
+        }
+    }
+    Some(vec![first, last])
+}
+
+/// the part of a that lies inside of the fat line of b, the band between two lines parallel
+/// to the line through the endpoints of b that contains all of b.
+fn fat_line_clip<T: FloatingPoint>(a: &CubicSegment<Vector2<T>>, b: &CubicSegment<Vector2<T>>) -> Option<(T, T)>
+    where f32: AsPrimitive<T> {
+    let [b0, b1, b2, b3] = [*b.get_point(0), *b.get_point(1), *b.get_point(2), *b.get_point(3)];
+    let mut baseline = b3 - b0;
+    let closed = baseline == Vector2::zero();
+    if closed {
+        baseline = [b1 - b0, b2 - b0].into_iter().find(|v| *v != Vector2::zero()).unwrap_or(Vector2::new(T::one(), T::zero()));
+    }
+    let length = baseline.length();
+    let distance = |p: Vector2<T>| baseline.cross(p - b0) / length;
+    let db = [T::zero(), distance(b1), distance(b2), distance(b3)];
+    let (low, high) = if closed {
+        db.iter().fold((T::zero(), T::zero()), |(low, high), d| (low.min(*d), high.max(*d)))
+    } else {
+        // tighter bounds for cubics from the paper
+        let factor: T = if db[1] * db[2] > T::zero() { 0.75.as_() } else { (4.0 / 9.0).as_() };
+        (factor * T::zero().min(db[1]).min(db[2]), factor * T::zero().max(db[1]).max(db[2]))
+    };
+    // the distances of the control points of a make a cubic over t whose convex hull
+    // contains it. the edges between every pair of points cover the hull.
+    let points: Vec<(T, T)> = a.iter().enumerate().map(|(i, p)| ((i as f32 / 3.0).as_(), distance(*p))).collect();
+    let mut range: Option<(T, T)> = None;
+    let mut include = |t: T| {
+        range = Some(match range {
+            Some((t_min, t_max)) => (t_min.min(t), t_max.max(t)),
+            None => (t, t),
+        });
+    };
+    for (i, &(ti, di)) in points.iter().enumerate() {
+        if di >= low && di <= high {
+            include(ti);
+        }
+        for &(tj, dj) in points.iter().skip(i + 1) {
+            for bound in [low, high] {
+                if (di - bound) * (dj - bound) < T::zero() {
+                    include(ti + (tj - ti) * (bound - di) / (dj - di));
+                }
+            }
+        }
+    }
+    range
+}
+
+/// clips a against b, then b against what is left of a and so on until both are small enough. a_range
+/// and b_range are the parts of the original curves a and b are and swapped is true when a is part of
+/// the second curve.
+fn clip<T: FloatingPoint>(a: &CubicSegment<Vector2<T>>, a_range: (T, T), b: &CubicSegment<Vector2<T>>, b_range: (T, T), swapped: bool, depth: usize, out: &mut Vec<(T, T)>)
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let half: T = 0.5.as_();
+    let middle = |range: (T, T)| (range.0 + range.1) * half;
+    let mut push = |a_range: (T, T), b_range: (T, T)| {
+        let (ta, tb) = (middle(a_range), middle(b_range));
+        out.push(if swapped { (tb, ta) } else { (ta, tb) });
+    };
+    if !a.hull_bbox().overlaps(&b.hull_bbox()) {
+        return;
+    }
+    if depth >= CLIP_MAX_DEPTH {
+        push(a_range, b_range);
+        return;
+    }
+    let (t_min, t_max) = match fat_line_clip(a, b) {
+        Some(range) => range,
+        None => return,
+    };
+    let clipped = a.subsegment(t_min, t_max);
+    let width = a_range.1 - a_range.0;
+    let clipped_range = (a_range.0 + width * t_min, a_range.0 + width * t_max);
+    let epsilon = T::epsilon() * (T::one() + T::one()).powi(10);
+    if clipped_range.1 - clipped_range.0 <= epsilon && b_range.1 - b_range.0 <= epsilon {
+        push(clipped_range, b_range);
+        return;
+    }
+    if t_max - t_min > CLIP_MIN_REDUCTION.as_() {
+        // clipping barely shrank the curve, so there may be several intersections. splitting
+        // the longer curve in half separates them.
+        if clipped_range.1 - clipped_range.0 > b_range.1 - b_range.0 {
+            let (first, second) = clipped.split_at(half);
+            let m = middle(clipped_range);
+            clip(b, b_range, &first, (clipped_range.0, m), !swapped, depth + 1, out);
+            clip(b, b_range, &second, (m, clipped_range.1), !swapped, depth + 1, out);
+        } else {
+            let (first, second) = b.split_at(half);
+            let m = middle(b_range);
+            clip(&clipped, clipped_range, &first, (b_range.0, m), swapped, depth + 1, out);
+            clip(&clipped, clipped_range, &second, (m, b_range.1), swapped, depth + 1, out);
+        }
+    } else {
+        clip(b, b_range, &clipped, clipped_range, !swapped, depth + 1, out);
+    }
+}
+
+/// the crossings of a ray with a curve. the crossing directions come from which side of the ray
+/// the curve is on between the crossings, with points on the ray counting as being on its left,
+/// so a contour passing through the ray at a shared endpoint is only counted once.
+fn ray_curve<T: FloatingPoint, S: Segment<VectorType = Vector2<T>> + PowerBasis<T>>(curve: &S, origin: Vector2<T>, direction: Vector2<T>) -> Vec<RayCrossing<T>>
+    where f32: AsPrimitive<T> {
+    if direction == Vector2::zero() {
+        return Vec::new();
+    }
+    let basis = curve.power_basis();
+    let side = side_coefficients(&basis, origin, direction);
+    let roots = match unit_roots(side, basis_reference(&basis, origin, direction)) {
+        Some(roots) => roots,
+        // a curve lying on the ray never changes sides
+        None => return Vec::new(),
+    };
+    // the ends are measured exactly so that segments sharing an end agree on its side
+    let end_side = direction.cross(*curve.end() - origin);
+    let left = |t: T| if t == T::one() { end_side >= T::zero() } else { evaluate(&side, t) >= T::zero() };
+    let half: T = 0.5.as_();
+    let mut points = vec![T::zero()];
+    points.extend(roots.iter().copied().filter(|t| *t > T::zero() && *t < T::one()));
+    points.push(T::one());
+    let mut crossings = Vec::new();
+    let mut before = left(T::zero());
+    for (i, &t) in points.iter().enumerate() {
+        let after = match points.get(i + 1) {
+            Some(next) => left((t + *next) * half),
+            None => left(T::one()),
+        };
+        // the sides at the ends are measured exactly at the ends, so there is no change there
+        // from the end to itself unless the end is on the ray
+        let winding = match (before, after) {
+            (false, true) => 1,
+            (true, false) => -1,
+            _ => 0,
+        };
+        before = after;
+        if winding == 0 && !roots.contains(&t) {
+            continue;
+        }
+        let point = curve.interpolate(t);
+        let distance = line_param(origin, direction, point);
+        if distance < T::zero() {
+            continue;
+        }
+        crossings.push(RayCrossing { t, distance, point, winding });
+    }
+    crossings
+}
+
+impl<T: FloatingPoint> Intersect for LinearSegment<Vector2<T>> {
+    type Scalar = T;
+    fn intersect(&self, other: &Self) -> Vec<SegmentIntersection<T>> {
+        line_line(self, other)
+    }
+}
+impl<T: FloatingPoint> Intersect<QuadraticSegment<Vector2<T>>> for LinearSegment<Vector2<T>> {
+    type Scalar = T;
+    fn intersect(&self, other: &QuadraticSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+        line_curve(*self.get_point(0), *self.get_point(1), other)
+    }
+}
+impl<T: FloatingPoint> Intersect<CubicSegment<Vector2<T>>> for LinearSegment<Vector2<T>> {
+    type Scalar = T;
+    fn intersect(&self, other: &CubicSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+        line_curve(*self.get_point(0), *self.get_point(1), other)
+    }
+}
+impl<T: FloatingPoint> Intersect<LinearSegment<Vector2<T>>> for QuadraticSegment<Vector2<T>> {
+    type Scalar = T;
+    fn intersect(&self, other: &LinearSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+        swap(other.intersect(self))
+    }
+}
+impl<T: FloatingPoint> Intersect for QuadraticSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    type Scalar = T;
+    fn intersect(&self, other: &Self) -> Vec<SegmentIntersection<T>> {
+        curve_curve(&self.elevate(), &other.elevate())
+    }
+}
+impl<T: FloatingPoint> Intersect<CubicSegment<Vector2<T>>> for QuadraticSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    type Scalar = T;
+    fn intersect(&self, other: &CubicSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+        curve_curve(&self.elevate(), other)
+    }
+}
+impl<T: FloatingPoint> Intersect<LinearSegment<Vector2<T>>> for CubicSegment<Vector2<T>> {
+    type Scalar = T;
+    fn intersect(&self, other: &LinearSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+        swap(other.intersect(self))
+    }
+}
+impl<T: FloatingPoint> Intersect<QuadraticSegment<Vector2<T>>> for CubicSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    type Scalar = T;
+    fn intersect(&self, other: &QuadraticSegment<Vector2<T>>) -> Vec<SegmentIntersection<T>> {
+        curve_curve(self, &other.elevate())
+    }
+}
+impl<T: FloatingPoint> Intersect for CubicSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    type Scalar = T;
+    fn intersect(&self, other: &Self) -> Vec<SegmentIntersection<T>> {
+        curve_curve(self, other)
+    }
+}
+
+impl<T: FloatingPoint> RayCrossings for LinearSegment<Vector2<T>>
+    where f32: AsPrimitive<T> {
+    type Scalar = T;
+    fn ray_crossings(&self, origin: Vector2<T>, direction: Vector2<T>) -> Vec<RayCrossing<T>> {
+        ray_curve(self, origin, direction)
+    }
+}
+impl<T: FloatingPoint> RayCrossings for QuadraticSegment<Vector2<T>>
+    where f32: AsPrimitive<T> {
+    type Scalar = T;
+    fn ray_crossings(&self, origin: Vector2<T>, direction: Vector2<T>) -> Vec<RayCrossing<T>> {
+        ray_curve(self, origin, direction)
+    }
+}
+impl<T: FloatingPoint> RayCrossings for CubicSegment<Vector2<T>>
+    where f32: AsPrimitive<T> {
+    type Scalar = T;
+    fn ray_crossings(&self, origin: Vector2<T>, direction: Vector2<T>) -> Vec<RayCrossing<T>> {
+        ray_curve(self, origin, direction)
+    }
+}
@@ -0,0 +1,151 @@
+use num_traits::AsPrimitive;
+
+use super::{Matrix4, Vector3, Vector4, Vector, EuclideanGeometry, traits::FloatingPoint};
+
+/// the range depth values are mapped to in normalized device coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthRange {
+    /// OpenGL style depth where the near plane maps to -1 and the far plane to 1.
+    NegativeOneToOne,
+    /// depth where the near plane maps to 0 and the far plane to 1, used by [`ClipSpace::VULKAN`] and [`ClipSpace::DIRECT3D`].
+    ZeroToOne,
+}
+
+/// # ClipSpace
+///
+/// describes the clip space conventions of the graphics api a projection matrix
+/// is built for. use [`ClipSpace::OPENGL`], [`ClipSpace::VULKAN`] or [`ClipSpace::DIRECT3D`] and optionally
+/// [`ClipSpace::reversed`] to swap the depth of the near and far planes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClipSpace {
+    pub depth: DepthRange,
+    /// flips the y axis so that it points down in normalized device coordinates.
+    pub flip_y: bool,
+    /// maps the near plane to the largest depth value and the far plane to the smallest,
+    /// which distributes floating point precision much more evenly across the depth buffer.
+    pub reversed_z: bool,
+}
+
+impl ClipSpace {
+    /// depth from -1 to 1 with the y axis pointing up.
+    pub const OPENGL: Self = Self { depth: DepthRange::NegativeOneToOne, flip_y: false, reversed_z: false };
+    /// depth from 0 to 1 with the y axis pointing down.
+    pub const VULKAN: Self = Self { depth: DepthRange::ZeroToOne, flip_y: true, reversed_z: false };
+    /// depth from 0 to 1 with the y axis pointing up.
+    pub const DIRECT3D: Self = Self { depth: DepthRange::ZeroToOne, flip_y: false, reversed_z: false };
+    pub fn reversed(self) -> Self {
+        Self { reversed_z: true, ..self }
+    }
+    /// the depth of the near and far planes in normalized device coordinates.
+    fn depth_bounds<T: FloatingPoint>(&self) -> (T, T) {
+        let (near, far) = match self.depth {
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+        };
+        if self.reversed_z { (far, near) } else { (near, far) }
+    }
+    fn y_sign<T: FloatingPoint>(&self) -> T {
+        if self.flip_y { -T::one() } else { T::one() }
+    }
+}
+
+///
+/// Projection and view matrices. All projections are right handed, meaning the camera
+/// looks down the negative z axis, which matches the view matrix made by [`Matrix4::look_at_rh`].
+///
+impl<T: FloatingPoint> Matrix4<T>
+    where f32: AsPrimitive<T> {
+    /// # perspective
+    ///
+    /// creates a perspective projection from the vertical field of view in radians,
+    /// the aspect ratio (width / height) and the distances to the near and far planes.
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let f = T::one() / (fovy * 0.5.as_()).tan();
+        let (depth_near, depth_far) = clip.depth_bounds::<T>();
+        // the depth is calculated as (a * z + b) / -z, a and b are solved so that
+        // z = -near lands on depth_near and z = -far lands on depth_far.
+        let a = (depth_near * near - depth_far * far) / (far - near);
+        let b = (depth_near + a) * near;
+        Self::perspective_from_depth(f / aspect, f * clip.y_sign(), a, b)
+    }
+    /// # perspective_infinite
+    ///
+    /// the same as [`Matrix4::perspective`] but with the far plane placed at infinity.
+    pub fn perspective_infinite(fovy: T, aspect: T, near: T, clip: ClipSpace) -> Self {
+        let f = T::one() / (fovy * 0.5.as_()).tan();
+        let (depth_near, depth_far) = clip.depth_bounds::<T>();
+        // the limit of the depth terms in perspective as far approaches infinity.
+        let a = -depth_far;
+        let b = (depth_near - depth_far) * near;
+        Self::perspective_from_depth(f / aspect, f * clip.y_sign(), a, b)
+    }
+    fn perspective_from_depth(x_scale: T, y_scale: T, a: T, b: T) -> Self {
+        Matrix4::new(
+            x_scale, T::zero(), T::zero(), T::zero(),
+            T::zero(), y_scale, T::zero(), T::zero(),
+            T::zero(), T::zero(), a, -T::one(),
+            T::zero(), T::zero(), b, T::zero(),
+        )
+    }
+    /// # frustum
+    ///
+    /// creates a perspective projection from the edges of the near plane,
+    /// which allows for off center projections.
+    pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let (depth_near, depth_far) = clip.depth_bounds::<T>();
+        let a = (depth_near * near - depth_far * far) / (far - near);
+        let b = (depth_near + a) * near;
+        let y_sign = clip.y_sign::<T>();
+        Matrix4::new(
+            2.0.as_() * near / (right - left), T::zero(), T::zero(), T::zero(),
+            T::zero(), y_sign * 2.0.as_() * near / (top - bottom), T::zero(), T::zero(),
+            (right + left) / (right - left), y_sign * (top + bottom) / (top - bottom), a, -T::one(),
+            T::zero(), T::zero(), b, T::zero(),
+        )
+    }
+    /// # orthographic
+    ///
+    /// creates a projection that maps the box made from the edges and the near and far
+    /// distances into clip space without any perspective division.
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let (depth_near, depth_far) = clip.depth_bounds::<T>();
+        // the depth is calculated as a * z + b.
+        let a = (depth_near - depth_far) / (far - near);
+        let b = depth_near + a * near;
+        let y_sign = clip.y_sign::<T>();
+        Matrix4::new(
+            2.0.as_() / (right - left), T::zero(), T::zero(), T::zero(),
+            T::zero(), y_sign * 2.0.as_() / (top - bottom), T::zero(), T::zero(),
+            T::zero(), T::zero(), a, T::zero(),
+            -(right + left) / (right - left), -y_sign * (top + bottom) / (top - bottom), b, T::one(),
+        )
+    }
+    /// # look_at_rh
+    ///
+    /// creates a right handed view matrix for a camera placed at eye looking towards target.
+    /// the camera looks down the negative z axis.
+    pub fn look_at_rh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let up = side.cross(forward);
+        Self::from_view_axes(eye, side, up, -forward)
+    }
+    /// # look_at_lh
+    ///
+    /// creates a left handed view matrix for a camera placed at eye looking towards target.
+    /// the camera looks down the positive z axis.
+    pub fn look_at_lh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = (target - eye).normalize();
+        let side = up.cross(forward).normalize();
+        let up = forward.cross(side);
+        Self::from_view_axes(eye, side, up, forward)
+    }
+    fn from_view_axes(eye: Vector3<T>, x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self {
+            x: Vector4 { x: x.x, y: y.x, z: z.x, w: T::zero() },
+            y: Vector4 { x: x.y, y: y.y, z: z.y, w: T::zero() },
+            z: Vector4 { x: x.z, y: y.z, z: z.z, w: T::zero() },
+            w: Vector4 { x: -x.dot(&eye), y: -y.dot(&eye), z: -z.dot(&eye), w: T::one() }
+        }
+    }
+}
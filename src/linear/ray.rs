@@ -0,0 +1,422 @@
+use num_traits::{AsPrimitive, Float, One, Zero};
+
+use super::{Vector, Vector2, Vector3, Aabb2, Aabb3, Triangle, Sphere, Capsule, Plane, Obb, Cylinder, EuclideanGeometry, traits::FloatingPoint};
+
+/// # Ray
+///
+/// a half line starting at origin and going on forever along direction. the direction doesn't
+/// need to be a unit vector, distances along the ray are measured in multiples of it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray<V: Vector> {
+    pub origin: V,
+    pub direction: V,
+}
+pub type Ray2<T> = Ray<Vector2<T>>;
+pub type Ray3<T> = Ray<Vector3<T>>;
+
+/// # RayHit
+///
+/// where a ray first meets a shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit<V: Vector> {
+    /// how far along the ray the hit is, in multiples of the ray's direction. never negative.
+    pub distance: V::Scalar,
+    pub point: V,
+    /// the unit normal of the surface at the point. it points out of solid shapes and towards the
+    /// origin of the ray for flat shapes like planes, discs and triangles in 3 dimensions.
+    pub normal: V,
+}
+
+pub trait RayCast<V: Vector> {
+    /// the first place the ray meets the shape. when the origin of the ray is inside of a solid shape
+    /// this is where the ray leaves it.
+    fn ray_cast(&self, ray: &Ray<V>) -> Option<RayHit<V>>;
+}
+
+/// where a ray crosses a surface, before the point is worked out.
+#[derive(Clone, Copy, Debug)]
+struct Bound<V: Vector> {
+    distance: V::Scalar,
+    normal: V,
+}
+/// the part of a ray inside of a convex solid, from where it enters to where it leaves. either end
+/// can be infinitely far away when the ray never crosses that side of the solid.
+#[derive(Clone, Copy, Debug)]
+struct Span<V: Vector> {
+    enter: Bound<V>,
+    exit: Bound<V>,
+}
+impl<V: Vector> Span<V> where V::Scalar: FloatingPoint {
+    fn unbounded(zero: V) -> Self {
+        let infinity = V::Scalar::infinity();
+        Self {
+            enter: Bound { distance: -infinity, normal: zero },
+            exit: Bound { distance: infinity, normal: zero },
+        }
+    }
+    /// the part of the ray inside of both solids.
+    fn intersection(self, other: Self) -> Option<Self> {
+        let enter = if other.enter.distance > self.enter.distance { other.enter } else { self.enter };
+        let exit = if other.exit.distance < self.exit.distance { other.exit } else { self.exit };
+        (enter.distance <= exit.distance).then_some(Self { enter, exit })
+    }
+    /// the part of the ray inside of either solid, which only makes sense when their union is convex.
+    fn union(self, other: Self) -> Self {
+        let enter = if other.enter.distance < self.enter.distance { other.enter } else { self.enter };
+        let exit = if other.exit.distance > self.exit.distance { other.exit } else { self.exit };
+        Self { enter, exit }
+    }
+}
+
+impl<V: Vector> Ray<V> {
+    pub fn new(origin: V, direction: V) -> Self {
+        Self { origin, direction }
+    }
+    /// the point distance multiples of the direction away from the origin.
+    pub fn at(&self, distance: V::Scalar) -> V {
+        self.origin + self.direction * distance
+    }
+}
+
+impl<V: Vector> Ray<V> where V::Scalar: FloatingPoint {
+    /// the first hit with whichever shape the ray meets first.
+    pub fn cast<S: RayCast<V>>(&self, shape: &S) -> Option<RayHit<V>> {
+        shape.ray_cast(self)
+    }
+    fn hit(&self, bound: Bound<V>) -> RayHit<V> {
+        RayHit { distance: bound.distance, point: self.at(bound.distance), normal: bound.normal }
+    }
+    /// where the ray enters the span, or leaves it when the origin is already inside.
+    fn first(&self, span: Span<V>) -> Option<RayHit<V>> {
+        let zero = V::Scalar::zero();
+        if span.enter.distance >= zero {
+            Some(self.hit(span.enter))
+        } else if span.exit.distance >= zero && span.exit.distance.is_finite() {
+            Some(self.hit(span.exit))
+        } else {
+            None
+        }
+    }
+    /// the unit vector facing against the direction of the ray out of normal and its opposite.
+    fn facing(&self, normal: V) -> V {
+        let normal = normal.normalize();
+        if normal.dot(&self.direction) > V::Scalar::zero() { normal * -V::Scalar::one() } else { normal }
+    }
+    /// # intersect_plane
+    ///
+    /// the hit with the plane through point facing normal, which is a line in 2 dimensions.
+    /// [`None`] when the ray is parallel to the plane or points away from it.
+    pub fn intersect_plane(&self, point: V, normal: V) -> Option<RayHit<V>> {
+        let denom = normal.dot(&self.direction);
+        if denom == V::Scalar::zero() {
+            return None;
+        }
+        let distance = (point - self.origin).dot(&normal) / denom;
+        (distance >= V::Scalar::zero()).then(|| self.hit(Bound { distance, normal: self.facing(normal) }))
+    }
+    /// the hit with the flat disc of radius around center facing normal.
+    pub fn intersect_disc(&self, center: V, normal: V, radius: V::Scalar) -> Option<RayHit<V>> {
+        let hit = self.intersect_plane(center, normal)?;
+        let offset = hit.point - center;
+        (offset.dot(&offset) <= radius * radius).then_some(hit)
+    }
+    fn sphere_span(&self, center: V, radius: V::Scalar) -> Option<Span<V>> {
+        let m = self.origin - center;
+        let a = self.direction.dot(&self.direction);
+        let b = m.dot(&self.direction);
+        let c = m.dot(&m) - radius * radius;
+        let h = b * b - a * c;
+        if a == V::Scalar::zero() || h < V::Scalar::zero() || radius <= V::Scalar::zero() {
+            return None;
+        }
+        let root = h.sqrt();
+        let bound = |distance: V::Scalar| Bound { distance, normal: (m + self.direction * distance) / radius };
+        Some(Span { enter: bound((-b - root) / a), exit: bound((-b + root) / a) })
+    }
+    /// the part of the ray inside of the cylinder from start to end with flat caps.
+    fn cylinder_span(&self, start: V, end: V, radius: V::Scalar) -> Option<Span<V>> {
+        let zero = V::Scalar::zero();
+        let axis = end - start;
+        let length = axis.dot(&axis);
+        if length == zero || radius <= zero {
+            return None;
+        }
+        let m = self.origin - start;
+        let (m_axis, d_axis) = (m.dot(&axis), self.direction.dot(&axis));
+        // the parts of the offset from the axis and the direction that go around the axis
+        let m_side = m - axis * (m_axis / length);
+        let d_side = self.direction - axis * (d_axis / length);
+
+        let a = d_side.dot(&d_side);
+        let b = m_side.dot(&d_side);
+        let c = m_side.dot(&m_side) - radius * radius;
+        let side = if a == zero {
+            if c > zero {
+                return None;
+            }
+            Span::unbounded(axis * zero)
+        } else {
+            let h = b * b - a * c;
+            if h < zero {
+                return None;
+            }
+            let root = h.sqrt();
+            let bound = |distance: V::Scalar| Bound { distance, normal: (m_side + d_side * distance) / radius };
+            Span { enter: bound((-b - root) / a), exit: bound((-b + root) / a) }
+        };
+        let caps = if d_axis == zero {
+            if m_axis < zero || m_axis > length {
+                return None;
+            }
+            Span::unbounded(axis * zero)
+        } else {
+            let out = axis / length.sqrt();
+            let bottom = Bound { distance: -m_axis / d_axis, normal: out * -V::Scalar::one() };
+            let top = Bound { distance: (length - m_axis) / d_axis, normal: out };
+            if d_axis > zero { Span { enter: bottom, exit: top } } else { Span { enter: top, exit: bottom } }
+        };
+        side.intersection(caps)
+    }
+    /// the hit with the sphere of radius around center, a circle in 2 dimensions.
+    pub fn intersect_sphere(&self, center: V, radius: V::Scalar) -> Option<RayHit<V>> {
+        self.first(self.sphere_span(center, radius)?)
+    }
+    /// the hit with the cylinder of radius around the line from start to end, closed off with flat
+    /// caps at both ends.
+    pub fn intersect_cylinder(&self, start: V, end: V, radius: V::Scalar) -> Option<RayHit<V>> {
+        self.first(self.cylinder_span(start, end, radius)?)
+    }
+    /// the hit with the capsule of every point within radius of the line from start to end, a
+    /// cylinder with half spheres on its ends.
+    pub fn intersect_capsule(&self, start: V, end: V, radius: V::Scalar) -> Option<RayHit<V>> {
+        // a capsule is the union of its cylinder and the spheres at its ends, and since it's convex
+        // the ray is inside of it from the first place it enters one of them to the last place it leaves
+        let span = [self.sphere_span(start, radius), self.sphere_span(end, radius), self.cylinder_span(start, end, radius)]
+            .into_iter()
+            .flatten()
+            .reduce(Span::union)?;
+        self.first(span)
+    }
+}
+
+/// the part of the ray inside of the box using the slab test, with the axis and side of the face
+/// the ray enters and leaves through. 1 is the side of max and -1 the side of min.
+fn slabs<T: FloatingPoint, const N: usize>(origin: [T; N], direction: [T; N], min: [T; N], max: [T; N]) -> Option<[(T, usize, T); 2]> {
+    let one = T::one();
+    let mut enter = (T::neg_infinity(), 0, -one);
+    let mut exit = (T::infinity(), 0, one);
+    for i in 0..N {
+        if direction[i] == T::zero() {
+            if origin[i] < min[i] || origin[i] > max[i] {
+                return None;
+            }
+            continue;
+        }
+        let to_min = ((min[i] - origin[i]) / direction[i], i, -one);
+        let to_max = ((max[i] - origin[i]) / direction[i], i, one);
+        let (near, far) = if direction[i] > T::zero() { (to_min, to_max) } else { (to_max, to_min) };
+        if near.0 > enter.0 {
+            enter = near;
+        }
+        if far.0 < exit.0 {
+            exit = far;
+        }
+    }
+    (enter.0 <= exit.0).then_some([enter, exit])
+}
+
+impl<T: FloatingPoint> RayCast<Vector2<T>> for Aabb2<T> {
+    fn ray_cast(&self, ray: &Ray2<T>) -> Option<RayHit<Vector2<T>>> {
+        let [o, d] = [ray.origin, ray.direction];
+        let [enter, exit] = slabs([o.x, o.y], [d.x, d.y], [self.min.x, self.min.y], [self.max.x, self.max.y])?;
+        let bound = |(distance, axis, side): (T, usize, T)| {
+            let mut normal = [T::zero(); 2];
+            normal[axis] = side;
+            Bound { distance, normal: Vector2::new(normal[0], normal[1]) }
+        };
+        ray.first(Span { enter: bound(enter), exit: bound(exit) })
+    }
+}
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Aabb3<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        let [o, d] = [ray.origin, ray.direction];
+        let [enter, exit] = slabs(
+            [o.x, o.y, o.z], [d.x, d.y, d.z],
+            [self.min.x, self.min.y, self.min.z], [self.max.x, self.max.y, self.max.z]
+        )?;
+        let bound = |(distance, axis, side): (T, usize, T)| {
+            let mut normal = [T::zero(); 3];
+            normal[axis] = side;
+            Bound { distance, normal: Vector3::new(normal[0], normal[1], normal[2]) }
+        };
+        ray.first(Span { enter: bound(enter), exit: bound(exit) })
+    }
+}
+
+/// boxes are hit by casting the ray in the space of the box.
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Obb<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        let local = Ray3::new(self.to_local(ray.origin), self.rotation * ray.direction);
+        let hit = Aabb3::new(-self.half_extents, self.half_extents).ray_cast(&local)?;
+        Some(RayHit { distance: hit.distance, point: ray.at(hit.distance), normal: hit.normal * self.rotation })
+    }
+}
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Sphere<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        ray.intersect_sphere(self.center, self.radius)
+    }
+}
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Capsule<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        ray.intersect_capsule(self.start, self.end, self.radius)
+    }
+}
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Cylinder<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        ray.intersect_cylinder(self.start, self.end, self.radius)
+    }
+}
+/// planes are hit from either side like a surface rather than the solid behind them.
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Plane<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        ray.intersect_plane(self.normal * self.d, self.normal)
+    }
+}
+
+fn component<T: Copy>(v: Vector3<T>, i: usize) -> T {
+    match i {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
+
+impl<T: FloatingPoint> Ray3<T> where f64: AsPrimitive<T> {
+    /// # intersect_triangle
+    ///
+    /// the hit with the triangle a, b, c from either side using
+    /// [Möller and Trumbore's Fast, Minimum Storage Ray/Triangle Intersection](https://doi.org/10.1080/10867651.1997.10487468).
+    /// it's fast but rays going through an edge shared by two triangles can slip between both of them,
+    /// see [`intersect_triangle_watertight`](Self::intersect_triangle_watertight) for that.
+    pub fn intersect_triangle(&self, a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<RayHit<Vector3<T>>> {
+        let zero = T::zero();
+        let (e1, e2) = (b - a, c - a);
+        let p = self.direction.cross(e2);
+        let det = e1.dot(&p);
+        // nearly parallel rays would hit the plane of the triangle somewhere meaningless
+        if det.abs() <= T::epsilon() * e1.length() * e2.length() * self.direction.length() {
+            return None;
+        }
+        let s = self.origin - a;
+        let u = s.dot(&p) / det;
+        if u < zero || u > T::one() {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.direction.dot(&q) / det;
+        if v < zero || u + v > T::one() {
+            return None;
+        }
+        let distance = e2.dot(&q) / det;
+        (distance >= zero).then(|| self.hit(Bound { distance, normal: self.facing(e1.cross(e2)) }))
+    }
+    /// # intersect_triangle_watertight
+    ///
+    /// the hit with the triangle a, b, c from either side using
+    /// [Woop, Benthin and Wald's Watertight Ray/Triangle Intersection](https://jcgt.org/published/0002/01/05/).
+    /// rays through an edge or corner shared by triangles of a mesh always hit at least one of them.
+    pub fn intersect_triangle_watertight(&self, a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<RayHit<Vector3<T>>> {
+        let zero = T::zero();
+        let d = self.direction;
+        // shear the triangle so the ray goes along the z axis from the origin, with z being the
+        // largest component of the direction so the shear is well behaved
+        let kz = if d.x.abs() > d.y.abs() {
+            if d.x.abs() > d.z.abs() { 0 } else { 2 }
+        } else if d.y.abs() > d.z.abs() { 1 } else { 2 };
+        let (mut kx, mut ky) = ((kz + 1) % 3, (kz + 2) % 3);
+        // keeps the winding of the triangle the same after the shear
+        if component(d, kz) < zero {
+            std::mem::swap(&mut kx, &mut ky);
+        }
+        let dz = component(d, kz);
+        if dz == zero {
+            return None;
+        }
+        let (sx, sy, sz) = (component(d, kx) / dz, component(d, ky) / dz, T::one() / dz);
+        let shear = |p: Vector3<T>| {
+            let p = p - self.origin;
+            let z = component(p, kz);
+            (component(p, kx) - sx * z, component(p, ky) - sy * z, sz * z)
+        };
+        let (ax, ay, az) = shear(a);
+        let (bx, by, bz) = shear(b);
+        let (cx, cy, cz) = shear(c);
+
+        let edge = |px: T, py: T, qx: T, qy: T| {
+            let value = px * qy - py * qx;
+            if value == zero {
+                // the edge goes right through the ray, so it's worked out again in double precision
+                // to be sure which side it's on
+                let (px, py, qx, qy): (f64, f64, f64, f64) = (px.as_(), py.as_(), qx.as_(), qy.as_());
+                (px * qy - py * qx).as_()
+            } else {
+                value
+            }
+        };
+        let u = edge(cx, cy, bx, by);
+        let v = edge(ax, ay, cx, cy);
+        let w = edge(bx, by, ax, ay);
+        if (u < zero || v < zero || w < zero) && (u > zero || v > zero || w > zero) {
+            return None;
+        }
+        let det = u + v + w;
+        if det == zero {
+            return None;
+        }
+        let scaled = u * az + v * bz + w * cz;
+        if (det < zero && scaled > zero) || (det > zero && scaled < zero) {
+            return None;
+        }
+        let distance = scaled / det;
+        Some(self.hit(Bound { distance, normal: self.facing((b - a).cross(c - a)) }))
+    }
+}
+
+/// triangles in 3 dimensions are hit from either side using the watertight test.
+impl<T: FloatingPoint> RayCast<Vector3<T>> for Triangle<Vector3<T>> where f64: AsPrimitive<T> {
+    fn ray_cast(&self, ray: &Ray3<T>) -> Option<RayHit<Vector3<T>>> {
+        let [a, b, c] = *self.points();
+        ray.intersect_triangle_watertight(a, b, c)
+    }
+}
+/// triangles in 2 dimensions are solid, the ray is clipped against the side of each edge.
+impl<T: FloatingPoint> RayCast<Vector2<T>> for Triangle<Vector2<T>> {
+    fn ray_cast(&self, ray: &Ray2<T>) -> Option<RayHit<Vector2<T>>> {
+        let zero = T::zero();
+        let area = self.signed_area();
+        if area == zero {
+            return None;
+        }
+        let mut span = Span::unbounded(ray.direction * zero);
+        for i in 0..3 {
+            let (from, to) = (self[i], self[(i + 1) % 3]);
+            let edge = to - from;
+            // the right side of the edges is outside for triangles going counter clockwise
+            let outward = Vector2::new(edge.y, -edge.x) * area.signum();
+            let (side, speed) = ((ray.origin - from).dot(&outward), ray.direction.dot(&outward));
+            let half = if speed == zero {
+                if side > zero {
+                    return None;
+                }
+                continue;
+            } else {
+                let bound = Bound { distance: -side / speed, normal: outward.normalize() };
+                let mut half = Span::unbounded(ray.direction * zero);
+                if speed < zero { half.enter = bound } else { half.exit = bound }
+                half
+            };
+            span = span.intersection(half)?;
+        }
+        ray.first(span)
+    }
+}
@@ -1,292 +1,775 @@
-use std::ops::Sub;
-
-use num_traits::{AsPrimitive, Float};
-
-use crate::{Vector2, Number, SignedNumber, EuclideanGeometry};
-use crate::linear::Vector;
-use super::non_zero_sign;
-use super::smoothing::lerp;
-use super::{Transform, traits::FloatingPoint};
-use crate::equations::{CubicFormula, CubicSolution, QuadraticSolution, QuadraticFormula, sd_bezier};
-
-fn solve_cubic_normalized<T: FloatingPoint>(a: T, b: T, c: T) -> (i32, [T; 3])
-    where f32: AsPrimitive<T> {
-    let a2 = a*a;
-    let mut q = T::one()/9.0.as_()*(a2-3.0.as_()*b);
-    let r = T::one()/54.0.as_()*(a*(2.0.as_()*a2-9.0.as_()*b)+27.0.as_()*c);
-    let r2 = r*r;
-    let q3 = q*q*q;
-    let a = a * (T::one()/3.0.as_());
-
-    let  (mut x0, mut x1, mut x2) = (T::zero(), T::zero(), T::zero());
-    if (r2 < q3) {
-        let mut t = r/q3.sqrt();
-        t = if t < -T::one() {-T::one()} else {T::one()};
-        t = t.acos();
-        q = -2.0.as_()*q.sqrt();
-        x0 = q*(T::one()/3.0.as_()*t).cos()-a;
-        x1 = q*(T::one()/3.0.as_()*(t+2.0.as_()*std::f32::consts::PI.as_())).cos()-a;
-        x2 = q*(T::one()/3.0.as_()*(t-2.0.as_()*std::f32::consts::PI.as_())).cos()-a;
-        return (3, [x0, x1, x2]);
-    } else {
-        let u = if r < T::zero() {
-            T::one()
-        } else {
-            -T::one()
-        } * (r.abs()+(r2-q3).sqrt()).powf(T::one()/3.0.as_());
-        let v = if u == T::zero() {T::zero()} else { q / u };
-        x0 = (u+v)-a;
-        if (u == v || (u-v).abs() < 1e-12.as_()*(u+v).abs()) {
-            x1 = -0.5.as_()*(u+v)-a;
-            return (2, [x0, x1, x2]);
-        }
-        return (1, [x0, x1, x2]);
-    }
-}
-
-fn solve_cubic<T: FloatingPoint>(a: T, b: T, c: T, d: T) -> (i32, [T; 3])
-    where f32: AsPrimitive<T> {
-    if a != T::zero() {
-        let bn = b/a;
-        if bn.abs() < 1e6.as_() {
-            return solve_cubic_normalized(bn, c/a, d/a);
-        } // Above this ratio, the numerical error gets larger than if we treated a as zero
-    }
-    match T::quadratic_formula(a, b, c) {
-        QuadraticSolution::All(n) => (1, [n, T::zero(), T::zero()]),
-        QuadraticSolution::OneReal(r) => (1, [r, T::zero(), T::zero()]),
-        QuadraticSolution::TwoReal(r) => (2, [r[0], r[1], T::zero()]),
-        _ => (0, [T::zero(); 3]),
-    }
-}
-
-
-// functions for n dimensional segments
-pub trait Segment {
-    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
-        where <Self::VectorType as Vector>::Scalar: FloatingPoint;
-    fn start(&self) -> &Self::VectorType;
-    fn end(&self) -> &Self::VectorType;
-    // fn transform<T: Transform>(&mut self, transform: &T);
-    fn get_point(&self, idx: usize) -> &Self::VectorType;
-    fn set_start(&mut self, value: &Self::VectorType);
-    fn set_end(&mut self, value: &Self::VectorType);
-    fn len(&self) -> usize;
-    fn iter(&self) -> std::slice::Iter<Self::VectorType>;
-    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType>;
-    // fn distance(&self, p: &Self::VectorType) -> <Self::VectorType as Vector>::Scalar
-    //     where <Self::VectorType as Vector>::Scalar: FloatingPoint;
-    type VectorType: Vector;
-}
-// functions for 2 dimensional segments
-pub trait FlatSegment: Segment {
-    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
-        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
-    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
-        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
-}
-
-pub struct LinearSegment<T: Vector> {
-    points: [T; 2]
-}
-pub struct QuadraticSegment<T: Vector> {
-    points: [T; 3]
-}
-pub struct CubicSegment<T: Vector> {
-    points: [T; 4]
-}
-
-impl<T: Vector> LinearSegment<T> {
-    pub fn new(v0: T, v1: T) -> Self {
-        Self { points: [v0, v1] }
-    }
-}
-impl<T: Vector> QuadraticSegment<T> {
-    pub fn new(v0: T, v1: T, v2: T) -> Self {
-        Self { points: [v0, v1, v2] }
-    }
-}
-impl<T: Vector> CubicSegment<T> {
-    pub fn new(v0: T, v1: T, v2: T, v3: T) -> Self {
-        Self { points: [v0, v1, v2, v3] }
-    }
-}
-
-impl<T: Vector> Segment for LinearSegment<T> {
-    fn start(&self) -> &Self::VectorType {
-        &self.points[0]
-    }
-    fn end(&self) -> &Self::VectorType {
-        &self.points[1]
-    }
-    fn get_point(&self, idx: usize) -> &Self::VectorType {
-        &self.points[idx]
-    }
-    fn set_start(&mut self, value: &Self::VectorType) {
-        self.points[0] = *value;
-    }
-    fn set_end(&mut self, value: &Self::VectorType) {
-        self.points[1] = *value;
-    }
-    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
-        where <Self::VectorType as Vector>::Scalar: FloatingPoint {
-        lerp(self.points[0], self.points[1], t)
-    }
-    fn len(&self) -> usize { 2 }
-    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
-    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType> { self.points.iter_mut() }
-    type VectorType = T;
-}
-impl<T: Vector> Segment for QuadraticSegment<T> {
-    fn start(&self) -> &Self::VectorType {
-        &self.points[0]
-    }
-    fn end(&self) -> &Self::VectorType {
-        &self.points[2]
-    }
-    fn get_point(&self, idx: usize) -> &Self::VectorType {
-        &self.points[idx]
-    }
-    fn set_start(&mut self, value: &Self::VectorType) {
-        self.points[0] = *value;
-    }
-    fn set_end(&mut self, value: &Self::VectorType) {
-        self.points[2] = *value;
-    }
-    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
-            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
-        lerp(lerp(self.points[0], self.points[1], t), lerp(self.points[1], self.points[2], t), t)
-    }
-    fn len(&self) -> usize { 3 }
-    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
-    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType> { self.points.iter_mut() }
-    type VectorType = T;
-}
-impl<T: Vector> Segment for CubicSegment<T> {
-    fn start(&self) -> &Self::VectorType {
-        &self.points[0]
-    }
-    fn end(&self) -> &Self::VectorType {
-        &self.points[3]
-    }
-    fn get_point(&self, idx: usize) -> &Self::VectorType {
-        &self.points[idx]
-    }
-    fn set_start(&mut self, value: &Self::VectorType) {
-        self.points[0] = *value;
-    }
-    fn set_end(&mut self, value: &Self::VectorType) {
-        self.points[3] = *value;
-    }
-    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
-            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
-        let p12 = lerp(self.points[1], self.points[2], t);
-        lerp(lerp(lerp(self.points[0], self.points[1], t), p12, t), lerp(p12, lerp(self.points[2], self.points[3], t), t), t)
-    }
-    fn len(&self) -> usize { 4 }
-    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
-    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType> { self.points.iter_mut() }
-    type VectorType = T;
-}
-impl<T: Number + 'static> FlatSegment for LinearSegment<Vector2<T>>
-    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
-    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
-        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-
-        let aq = p-*self.get_point(0);
-        let ab = *self.get_point(1)-*self.get_point(0);
-        let param = aq.dot(&ab)/ab.dot(&ab);
-        let eq = *self.get_point((param > 0.5.as_()) as usize)-p;
-        let endpoint_distance = eq.length();
-        if param > T::zero() && param < T::one() {
-            let ortho_distance = ab.get_orthonomal(false, false).dot(&aq);
-            if ortho_distance.abs() < endpoint_distance {
-                return ortho_distance;
-            }
-        }
-        return (non_zero_sign(aq.cross(ab))) * endpoint_distance;
-    }
-    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
-            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-        *self.end() - *self.start()
-    }
-}
-
-impl<T: Number + 'static> FlatSegment for QuadraticSegment<Vector2<T>>
-    where f32: AsPrimitive<T>,
-    f64: AsPrimitive<T>, {
-    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
-            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-        let tangent = lerp(self.points[1]-self.points[0], self.points[2]-self.points[1], t);
-        if (tangent.x == T::zero() && tangent.y == T::zero()) {
-            return self.points[2]-self.points[0];
-        }
-        tangent
-    }
-    #[cfg(not(feature = "experimental"))]
-    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
-        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-        let qa = *self.get_point(0)-p;
-        let ab = *self.get_point(1)-*self.get_point(0);
-        let br = *self.get_point(2)-*self.get_point(1)-ab;
-        let a = br.dot(&br);
-        let b = 3.0.as_()*ab.dot(&br);
-        let c = 2.0.as_()*ab.dot(&ab)+qa.dot(&br);
-        let d = qa.dot(&ab);
-        let (solutions, t) = solve_cubic(a, b, c, d);
-        let mut ep_dir = self.direction(T::zero());
-        let mut min_distance = non_zero_sign(ep_dir.cross(qa))*qa.length(); // distance from A
-        let mut param = -qa.dot(&ep_dir)/ep_dir.dot(&ep_dir);
-        {
-            ep_dir = self.direction(T::one());
-            let distance = (*self.get_point(2)-p).length(); // distance from B
-            if distance < min_distance.abs() {
-                let crossepdir = ep_dir.cross(*self.get_point(2)-p);
-                min_distance = (non_zero_sign(crossepdir))*distance;
-                param = (p-*self.get_point(1)).dot(&ep_dir)/ep_dir.dot(&ep_dir);
-            }
-        }
-        for i in 0..solutions as usize {
-            if t[i] > T::zero() && t[i] < T::one() {
-                let qe: Vector2<T> = qa + ab * 2.0.as_() * t[i] + br * t[i] * t[i];
-                let distance = qe.length();
-                if distance <= min_distance.abs() {
-                    min_distance = (non_zero_sign((ab+br*t[i]).cross(qe)))*distance;
-                    param = t[i];
-                }
-            }
-        }
-        if param >= T::zero() && param <= T::one() {
-            return min_distance;
-        }
-        if param < 0.5.as_() {
-            return min_distance;
-        }
-        else {
-            return min_distance;
-        }
-    }
-
-    #[cfg(feature = "experimental")]
-    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
-            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-        sd_bezier(p, self.points[0], self.points[1], self.points[2])// * 1.1.as_()
-    }
-}
-
-// impl<T: Number + 'static> FlatSegment for CubicSegment<Vector2<T>>
-//     where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
-//     fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
-//             where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-//         let tangent = lerp(lerp(self.points[1]-self.points[0], self.points[2]-self.points[1], t), lerp(self.points[2]-self.points[1], self.points[3]-self.points[2], t), t);
-//         if tangent.x == T::zero() && tangent.y == T::zero() {
-//             if t == T::zero() { return self.points[2]-self.points[0] }
-//             if t == T::one() { return self.points[3]-self.points[1] }
-//         }
-//         tangent
-//     }
-//     fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
-//             where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
-        
-//     }
-// }
\ No newline at end of file
+use std::ops::Sub;
+
+use num_traits::{AsPrimitive, Float, clamp, Zero, One};
+
+use crate::{Vector2, Vector3, Number, SignedNumber, EuclideanGeometry};
+use crate::linear::Vector;
+use super::non_zero_sign;
+use super::smoothing::lerp;
+use super::{Transform, traits::FloatingPoint};
+use crate::equations::{CubicFormula, CubicSolution, QuadraticSolution, QuadraticFormula, sd_bezier};
+
+/// the most quadratics a cubic is ever split into by [`CubicSegment::to_quadratics`]. the error
+/// shrinks with the cube of the amount of parts so this is already a billion times more accurate
+/// than a single quadratic.
+const MAX_QUADRATICS: usize = 1 << 10;
+
+fn solve_cubic_normalized<T: FloatingPoint>(a: T, b: T, c: T) -> (i32, [T; 3])
+    where f32: AsPrimitive<T> {
+    let a2 = a*a;
+    let mut q = T::one()/9.0.as_()*(a2-3.0.as_()*b);
+    let r = T::one()/54.0.as_()*(a*(2.0.as_()*a2-9.0.as_()*b)+27.0.as_()*c);
+    let r2 = r*r;
+    let q3 = q*q*q;
+    let a = a * (T::one()/3.0.as_());
+
+    let  (mut x0, mut x1, mut x2) = (T::zero(), T::zero(), T::zero());
+    if (r2 < q3) {
+        let mut t = r/q3.sqrt();
+        t = clamp(t, -T::one(), T::one());
+        t = t.acos();
+        q = -2.0.as_()*q.sqrt();
+        x0 = q*(T::one()/3.0.as_()*t).cos()-a;
+        x1 = q*(T::one()/3.0.as_()*(t+2.0.as_()*std::f32::consts::PI.as_())).cos()-a;
+        x2 = q*(T::one()/3.0.as_()*(t-2.0.as_()*std::f32::consts::PI.as_())).cos()-a;
+        return (3, [x0, x1, x2]);
+    } else {
+        let u = if r < T::zero() {
+            T::one()
+        } else {
+            -T::one()
+        } * (r.abs()+(r2-q3).sqrt()).powf(T::one()/3.0.as_());
+        let v = if u == T::zero() {T::zero()} else { q / u };
+        x0 = (u+v)-a;
+        if (u == v || (u-v).abs() < 1e-12.as_()*(u+v).abs()) {
+            x1 = -0.5.as_()*(u+v)-a;
+            return (2, [x0, x1, x2]);
+        }
+        return (1, [x0, x1, x2]);
+    }
+}
+
+fn solve_cubic<T: FloatingPoint>(a: T, b: T, c: T, d: T) -> (i32, [T; 3])
+    where f32: AsPrimitive<T> {
+    if a != T::zero() {
+        let bn = b/a;
+        if bn.abs() < 1e6.as_() {
+            return solve_cubic_normalized(bn, c/a, d/a);
+        } // Above this ratio, the numerical error gets larger than if we treated a as zero
+    }
+    match T::quadratic_formula(a, b, c) {
+        QuadraticSolution::All(n) => (1, [n, T::zero(), T::zero()]),
+        QuadraticSolution::OneReal(r) => (1, [r, T::zero(), T::zero()]),
+        QuadraticSolution::TwoReal(r) => (2, [r[0], r[1], T::zero()]),
+        _ => (0, [T::zero(); 3]),
+    }
+}
+
+
+// functions for n dimensional segments
+pub trait Segment {
+    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+        where <Self::VectorType as Vector>::Scalar: FloatingPoint;
+    fn start(&self) -> &Self::VectorType;
+    fn end(&self) -> &Self::VectorType;
+    // fn transform<T: Transform>(&mut self, transform: &T);
+    fn get_point(&self, idx: usize) -> &Self::VectorType;
+    fn set_start(&mut self, value: &Self::VectorType);
+    fn set_end(&mut self, value: &Self::VectorType);
+    fn len(&self) -> usize;
+    fn iter(&self) -> std::slice::Iter<Self::VectorType>;
+    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType>;
+    // fn distance(&self, p: &Self::VectorType) -> <Self::VectorType as Vector>::Scalar
+    //     where <Self::VectorType as Vector>::Scalar: FloatingPoint;
+    /// the velocity of the curve at t, which is the derivative of [`Segment::interpolate`].
+    fn derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+        where <Self::VectorType as Vector>::Scalar: FloatingPoint;
+    /// the acceleration of the curve at t.
+    fn second_derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+        where <Self::VectorType as Vector>::Scalar: FloatingPoint;
+    /// # arc_length
+    ///
+    /// the length of the curve, calculated by integrating the speed of the curve with
+    /// adaptive [Gauss–Legendre quadrature](https://en.wikipedia.org/wiki/Gauss%E2%80%93Legendre_quadrature).
+    /// intervals are halved until the result changes by less than tolerance.
+    fn arc_length(&self, tolerance: <Self::VectorType as Vector>::Scalar) -> <Self::VectorType as Vector>::Scalar
+        where <Self::VectorType as Vector>::Scalar: FloatingPoint,
+        f64: AsPrimitive<<Self::VectorType as Vector>::Scalar> {
+        let zero = <Self::VectorType as Vector>::Scalar::zero();
+        let one = <Self::VectorType as Vector>::Scalar::one();
+        length_between(self, zero, one, tolerance)
+    }
+    /// # t_at_length
+    ///
+    /// the value of t at which the curve has traveled the given distance from the start,
+    /// which allows moving along the curve at a constant speed. distances outside of the
+    /// curve are clamped to the start and end.
+    fn t_at_length(&self, length: <Self::VectorType as Vector>::Scalar) -> <Self::VectorType as Vector>::Scalar
+        where <Self::VectorType as Vector>::Scalar: FloatingPoint,
+        f64: AsPrimitive<<Self::VectorType as Vector>::Scalar> {
+        let zero = <Self::VectorType as Vector>::Scalar::zero();
+        let one = <Self::VectorType as Vector>::Scalar::one();
+        let epsilon = <Self::VectorType as Vector>::Scalar::epsilon();
+        let tolerance = epsilon * 64.0.as_();
+        let total = self.arc_length(tolerance);
+        if length <= zero || total == zero {
+            return zero;
+        }
+        if length >= total {
+            return one;
+        }
+        let tolerance = tolerance * total;
+        // newton's method on the length up to t, using bisection whenever a step leaves the
+        // interval known to contain the answer. lengths are accumulated between steps so
+        // every step only integrates the part of the curve it moved across.
+        let (mut low, mut high) = (zero, one);
+        let mut t = length / total;
+        let mut traveled = length_between(self, zero, t, tolerance);
+        for _ in 0..64 {
+            let error = traveled - length;
+            if error.abs() <= tolerance {
+                break;
+            }
+            if error < zero { low = t; } else { high = t; }
+            let speed = self.derivative(t).length();
+            let newton = t - error / speed;
+            let next = if speed > zero && newton > low && newton < high { newton } else { (low + high) * 0.5.as_() };
+            if next == t {
+                break;
+            }
+            traveled += length_between(self, t, next, tolerance);
+            t = next;
+        }
+        t
+    }
+    type VectorType: Vector;
+}
+
+/// nodes of the 5 point Gauss–Legendre quadrature on the interval -1 to 1.
+const GAUSS_LEGENDRE_NODES: [f64; 5] = [0.0, -0.5384693101056831, 0.5384693101056831, -0.906179845938664, 0.906179845938664];
+/// weights of the 5 point Gauss–Legendre quadrature matching [`GAUSS_LEGENDRE_NODES`].
+const GAUSS_LEGENDRE_WEIGHTS: [f64; 5] = [0.5688888888888889, 0.47862867049936647, 0.47862867049936647, 0.23692688505618908, 0.23692688505618908];
+/// the maximum amount of times an interval is halved while calculating arc lengths.
+const ARC_LENGTH_MAX_DEPTH: u32 = 20;
+
+/// the length of the segment between t0 and t1, negative when t1 is less than t0.
+fn length_between<S: Segment + ?Sized>(segment: &S, t0: <S::VectorType as Vector>::Scalar, t1: <S::VectorType as Vector>::Scalar, tolerance: <S::VectorType as Vector>::Scalar) -> <S::VectorType as Vector>::Scalar
+    where <S::VectorType as Vector>::Scalar: FloatingPoint,
+    f64: AsPrimitive<<S::VectorType as Vector>::Scalar> {
+    let whole = gauss_legendre_length(segment, t0, t1);
+    adaptive_length(segment, t0, t1, whole, tolerance, ARC_LENGTH_MAX_DEPTH)
+}
+fn adaptive_length<S: Segment + ?Sized>(segment: &S, t0: <S::VectorType as Vector>::Scalar, t1: <S::VectorType as Vector>::Scalar, whole: <S::VectorType as Vector>::Scalar, tolerance: <S::VectorType as Vector>::Scalar, depth: u32) -> <S::VectorType as Vector>::Scalar
+    where <S::VectorType as Vector>::Scalar: FloatingPoint,
+    f64: AsPrimitive<<S::VectorType as Vector>::Scalar> {
+    let middle = (t0 + t1) * 0.5.as_();
+    let left = gauss_legendre_length(segment, t0, middle);
+    let right = gauss_legendre_length(segment, middle, t1);
+    if depth == 0 || (left + right - whole).abs() <= tolerance {
+        return left + right;
+    }
+    let tolerance = tolerance * 0.5.as_();
+    adaptive_length(segment, t0, middle, left, tolerance, depth - 1) + adaptive_length(segment, middle, t1, right, tolerance, depth - 1)
+}
+fn gauss_legendre_length<S: Segment + ?Sized>(segment: &S, t0: <S::VectorType as Vector>::Scalar, t1: <S::VectorType as Vector>::Scalar) -> <S::VectorType as Vector>::Scalar
+    where <S::VectorType as Vector>::Scalar: FloatingPoint,
+    f64: AsPrimitive<<S::VectorType as Vector>::Scalar> {
+    let half_width = (t1 - t0) * 0.5.as_();
+    let center = (t0 + t1) * 0.5.as_();
+    let mut sum = <S::VectorType as Vector>::Scalar::zero();
+    for (node, weight) in GAUSS_LEGENDRE_NODES.iter().zip(GAUSS_LEGENDRE_WEIGHTS.iter()) {
+        let t = center + half_width * node.as_();
+        sum += segment.derivative(t).length() * weight.as_();
+    }
+    sum * half_width
+}
+// functions for 2 dimensional segments
+pub trait FlatSegment: Segment {
+    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+    /// the same as [`FlatSegment::signed_distance`] except that when the closest point is
+    /// one of the endpoints the distance is measured to the line extending the segment
+    /// along the direction at that endpoint instead, as long as that line is closer.
+    /// this is what keeps the corners of multi channel distance fields sharp.
+    fn signed_pseudo_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+    /// the signed curvature at t, which is positive when the curve turns counter clockwise.
+    /// the curvature is 0 wherever the curve stops moving.
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+    /// the unit normal at t, which is [`FlatSegment::direction`] rotated counter clockwise.
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+}
+// functions for 3 dimensional segments
+pub trait SolidSegment: Segment {
+    /// the curvature at t, which is the inverse of the radius of the circle that best
+    /// fits the curve. the curvature is 0 wherever the curve stops moving.
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+    /// the principal unit normal at t, pointing towards the center of curvature.
+    /// straight parts of the curve have no such direction so the zero vector is returned.
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint;
+}
+
+fn flat_curvature<T: FloatingPoint>(d1: Vector2<T>, d2: Vector2<T>) -> T {
+    let speed = d1.length();
+    if speed == T::zero() {
+        return T::zero();
+    }
+    d1.cross(d2)/(speed*speed*speed)
+}
+fn solid_curvature<T: FloatingPoint>(d1: Vector3<T>, d2: Vector3<T>) -> T {
+    let speed = d1.length();
+    if speed == T::zero() {
+        return T::zero();
+    }
+    d1.cross(d2).length()/(speed*speed*speed)
+}
+fn solid_normal<T: FloatingPoint>(d1: Vector3<T>, d2: Vector3<T>) -> Vector3<T> {
+    let zero = Vector3::new(T::zero(), T::zero(), T::zero());
+    let speed = d1.length();
+    if speed == T::zero() {
+        return zero;
+    }
+    // the part of the acceleration that changes the direction instead of the speed
+    let tangent = d1/speed;
+    let normal = d2-tangent*d2.dot(&tangent);
+    let length = normal.length();
+    if length <= T::epsilon()*d2.length() {
+        return zero;
+    }
+    normal/length
+}
+
+/// converts a signed distance into a pseudo distance using the parameter of the closest point,
+/// which lies outside of 0 to 1 when the closest point is one of the endpoints.
+fn distance_to_pseudo_distance<T: FloatingPoint, S: FlatSegment<VectorType = Vector2<T>>>(segment: &S, p: Vector2<T>, distance: T, param: T) -> T {
+    if param < T::zero() {
+        let dir = segment.direction(T::zero()).normalize();
+        let aq = p-*segment.start();
+        if aq.dot(&dir) < T::zero() {
+            let pseudo_distance = aq.cross(dir);
+            if pseudo_distance.abs() <= distance.abs() {
+                return pseudo_distance;
+            }
+        }
+    } else if param > T::one() {
+        let dir = segment.direction(T::one()).normalize();
+        let bq = p-*segment.end();
+        if bq.dot(&dir) > T::zero() {
+            let pseudo_distance = bq.cross(dir);
+            if pseudo_distance.abs() <= distance.abs() {
+                return pseudo_distance;
+            }
+        }
+    }
+    distance
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearSegment<T: Vector> {
+    points: [T; 2]
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticSegment<T: Vector> {
+    points: [T; 3]
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicSegment<T: Vector> {
+    points: [T; 4]
+}
+
+impl<T: Vector> LinearSegment<T> {
+    pub fn new(v0: T, v1: T) -> Self {
+        Self { points: [v0, v1] }
+    }
+    /// the same segment traveling from the end to the start.
+    pub fn reverse(&self) -> Self {
+        Self { points: [self.points[1], self.points[0]] }
+    }
+}
+impl<T: Vector> QuadraticSegment<T> {
+    pub fn new(v0: T, v1: T, v2: T) -> Self {
+        Self { points: [v0, v1, v2] }
+    }
+    /// the same segment traveling from the end to the start.
+    pub fn reverse(&self) -> Self {
+        Self { points: [self.points[2], self.points[1], self.points[0]] }
+    }
+}
+impl<T: Vector> CubicSegment<T> {
+    pub fn new(v0: T, v1: T, v2: T, v3: T) -> Self {
+        Self { points: [v0, v1, v2, v3] }
+    }
+    /// the same segment traveling from the end to the start.
+    pub fn reverse(&self) -> Self {
+        Self { points: [self.points[3], self.points[2], self.points[1], self.points[0]] }
+    }
+}
+
+impl<T: Vector> LinearSegment<T>
+    where T::Scalar: FloatingPoint {
+    /// splits the segment at t into the segment before t and the segment after t.
+    pub fn split_at(&self, t: T::Scalar) -> (Self, Self) {
+        let middle = lerp(self.points[0], self.points[1], t);
+        (Self::new(self.points[0], middle), Self::new(middle, self.points[1]))
+    }
+    /// the part of the segment between t0 and t1. when t0 is greater than t1 the
+    /// returned segment travels in the opposite direction.
+    pub fn subsegment(&self, t0: T::Scalar, t1: T::Scalar) -> Self {
+        Self::new(self.interpolate(t0), self.interpolate(t1))
+    }
+}
+impl<T: Vector> QuadraticSegment<T>
+    where T::Scalar: FloatingPoint {
+    /// splits the segment at t into the segment before t and the segment after t
+    /// using [de Casteljau's algorithm](https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm).
+    pub fn split_at(&self, t: T::Scalar) -> (Self, Self) {
+        let p01 = lerp(self.points[0], self.points[1], t);
+        let p12 = lerp(self.points[1], self.points[2], t);
+        let middle = lerp(p01, p12, t);
+        (Self::new(self.points[0], p01, middle), Self::new(middle, p12, self.points[2]))
+    }
+    /// the part of the segment between t0 and t1. when t0 is greater than t1 the
+    /// returned segment travels in the opposite direction.
+    pub fn subsegment(&self, t0: T::Scalar, t1: T::Scalar) -> Self {
+        Self::new(self.blossom(t0, t0), self.blossom(t0, t1), self.blossom(t1, t1))
+    }
+    /// de Casteljau's algorithm using a different t for every level, the control points
+    /// of any part of the curve can be made by picking the right values.
+    fn blossom(&self, u: T::Scalar, v: T::Scalar) -> T {
+        lerp(lerp(self.points[0], self.points[1], u), lerp(self.points[1], self.points[2], u), v)
+    }
+    /// # elevate
+    ///
+    /// creates a cubic segment that traces the exact same curve.
+    pub fn elevate(&self) -> CubicSegment<T>
+        where f64: AsPrimitive<T::Scalar> {
+        let two_thirds: T::Scalar = (2.0 / 3.0).as_();
+        CubicSegment::new(
+            self.points[0],
+            lerp(self.points[0], self.points[1], two_thirds),
+            lerp(self.points[2], self.points[1], two_thirds),
+            self.points[2],
+        )
+    }
+}
+impl<T: Vector> CubicSegment<T>
+    where T::Scalar: FloatingPoint {
+    /// splits the segment at t into the segment before t and the segment after t
+    /// using [de Casteljau's algorithm](https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm).
+    pub fn split_at(&self, t: T::Scalar) -> (Self, Self) {
+        let p01 = lerp(self.points[0], self.points[1], t);
+        let p12 = lerp(self.points[1], self.points[2], t);
+        let p23 = lerp(self.points[2], self.points[3], t);
+        let p012 = lerp(p01, p12, t);
+        let p123 = lerp(p12, p23, t);
+        let middle = lerp(p012, p123, t);
+        (Self::new(self.points[0], p01, p012, middle), Self::new(middle, p123, p23, self.points[3]))
+    }
+    /// the part of the segment between t0 and t1. when t0 is greater than t1 the
+    /// returned segment travels in the opposite direction.
+    pub fn subsegment(&self, t0: T::Scalar, t1: T::Scalar) -> Self {
+        Self::new(self.blossom(t0, t0, t0), self.blossom(t0, t0, t1), self.blossom(t0, t1, t1), self.blossom(t1, t1, t1))
+    }
+    /// de Casteljau's algorithm using a different t for every level, the control points
+    /// of any part of the curve can be made by picking the right values.
+    fn blossom(&self, u: T::Scalar, v: T::Scalar, w: T::Scalar) -> T {
+        let p01 = lerp(self.points[0], self.points[1], u);
+        let p12 = lerp(self.points[1], self.points[2], u);
+        let p23 = lerp(self.points[2], self.points[3], u);
+        lerp(lerp(p01, p12, v), lerp(p12, p23, v), w)
+    }
+    /// # to_quadratics
+    ///
+    /// approximates the curve with quadratic segments that are never further than tolerance
+    /// away from it. the curve is split into equal parts and every part is replaced by the
+    /// quadratic sharing its endpoints whose control point is the average of the two control
+    /// points extended along their tangents, which is the best fit when the endpoints are fixed.
+    /// the amount of parts comes from the error of that fit, which is (√3/36)|p3 - 3p2 + 3p1 - p0|
+    /// and shrinks with the cube of the amount of parts. the amount of parts is clamped to 1024
+    /// so a tolerance that is too small for the curve can't allocate without bound, and a
+    /// tolerance that isn't positive returns a single quadratic.
+    pub fn to_quadratics(&self, tolerance: T::Scalar) -> Vec<QuadraticSegment<T>>
+        where f64: AsPrimitive<T::Scalar> {
+        let [p0, p1, p2, p3] = self.points;
+        let three: T::Scalar = 3.0.as_();
+        let third_difference: T = p3 - p0 + (p1 - p2) * three;
+        let error: T::Scalar = third_difference.length() * (3.0f64.sqrt() / 36.0).as_();
+        let parts: f64 = (error / tolerance).cbrt().ceil().as_();
+        let count = if tolerance > T::Scalar::zero() && parts > 1.0 { parts.min(MAX_QUADRATICS as f64) as usize } else { 1 };
+        let n: T::Scalar = (count as f64).as_();
+        (0..count).map(|i| {
+            let t0 = (i as f64).as_() / n;
+            let t1 = ((i + 1) as f64).as_() / n;
+            let [q0, q1, q2, q3] = self.subsegment(t0, t1).points;
+            let control = ((q1 + q2) * three - q0 - q3) * 0.25.as_();
+            QuadraticSegment::new(q0, control, q3)
+        }).collect()
+    }
+}
+
+impl<T: Vector> Segment for LinearSegment<T> {
+    fn start(&self) -> &Self::VectorType {
+        &self.points[0]
+    }
+    fn end(&self) -> &Self::VectorType {
+        &self.points[1]
+    }
+    fn get_point(&self, idx: usize) -> &Self::VectorType {
+        &self.points[idx]
+    }
+    fn set_start(&mut self, value: &Self::VectorType) {
+        self.points[0] = *value;
+    }
+    fn set_end(&mut self, value: &Self::VectorType) {
+        self.points[1] = *value;
+    }
+    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+        where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        lerp(self.points[0], self.points[1], t)
+    }
+    fn derivative(&self, _t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        self.points[1]-self.points[0]
+    }
+    fn second_derivative(&self, _t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        (self.points[1]-self.points[0])*<Self::VectorType as Vector>::Scalar::zero()
+    }
+    fn len(&self) -> usize { 2 }
+    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
+    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType> { self.points.iter_mut() }
+    type VectorType = T;
+}
+impl<T: Vector> Segment for QuadraticSegment<T> {
+    fn start(&self) -> &Self::VectorType {
+        &self.points[0]
+    }
+    fn end(&self) -> &Self::VectorType {
+        &self.points[2]
+    }
+    fn get_point(&self, idx: usize) -> &Self::VectorType {
+        &self.points[idx]
+    }
+    fn set_start(&mut self, value: &Self::VectorType) {
+        self.points[0] = *value;
+    }
+    fn set_end(&mut self, value: &Self::VectorType) {
+        self.points[2] = *value;
+    }
+    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        lerp(lerp(self.points[0], self.points[1], t), lerp(self.points[1], self.points[2], t), t)
+    }
+    fn derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let two = <Self::VectorType as Vector>::Scalar::one()+<Self::VectorType as Vector>::Scalar::one();
+        lerp(self.points[1]-self.points[0], self.points[2]-self.points[1], t)*two
+    }
+    fn second_derivative(&self, _t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let two = <Self::VectorType as Vector>::Scalar::one()+<Self::VectorType as Vector>::Scalar::one();
+        ((self.points[2]-self.points[1])-(self.points[1]-self.points[0]))*two
+    }
+    fn len(&self) -> usize { 3 }
+    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
+    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType> { self.points.iter_mut() }
+    type VectorType = T;
+}
+impl<T: Vector> Segment for CubicSegment<T> {
+    fn start(&self) -> &Self::VectorType {
+        &self.points[0]
+    }
+    fn end(&self) -> &Self::VectorType {
+        &self.points[3]
+    }
+    fn get_point(&self, idx: usize) -> &Self::VectorType {
+        &self.points[idx]
+    }
+    fn set_start(&mut self, value: &Self::VectorType) {
+        self.points[0] = *value;
+    }
+    fn set_end(&mut self, value: &Self::VectorType) {
+        self.points[3] = *value;
+    }
+    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let p12 = lerp(self.points[1], self.points[2], t);
+        lerp(lerp(lerp(self.points[0], self.points[1], t), p12, t), lerp(p12, lerp(self.points[2], self.points[3], t), t), t)
+    }
+    fn derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let three = <Self::VectorType as Vector>::Scalar::one()+<Self::VectorType as Vector>::Scalar::one()+<Self::VectorType as Vector>::Scalar::one();
+        let d12 = self.points[2]-self.points[1];
+        lerp(lerp(self.points[1]-self.points[0], d12, t), lerp(d12, self.points[3]-self.points[2], t), t)*three
+    }
+    fn second_derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let three = <Self::VectorType as Vector>::Scalar::one()+<Self::VectorType as Vector>::Scalar::one()+<Self::VectorType as Vector>::Scalar::one();
+        let d01 = self.points[1]-self.points[0];
+        let d12 = self.points[2]-self.points[1];
+        let d23 = self.points[3]-self.points[2];
+        lerp(d12-d01, d23-d12, t)*(three+three)
+    }
+    fn len(&self) -> usize { 4 }
+    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
+    fn iter_mut(&mut self) -> std::slice::IterMut<Self::VectorType> { self.points.iter_mut() }
+    type VectorType = T;
+}
+impl<T: FloatingPoint> LinearSegment<Vector2<T>>
+    where f32: AsPrimitive<T> {
+    /// the signed distance to p along with the parameter of the closest point on the
+    /// line passing through the segment.
+    pub(crate) fn signed_distance_param(&self, p: Vector2<T>) -> (T, T) {
+        let aq = p-*self.get_point(0);
+        let ab = *self.get_point(1)-*self.get_point(0);
+        let param = aq.dot(&ab)/ab.dot(&ab);
+        let eq = *self.get_point((param > 0.5.as_()) as usize)-p;
+        let endpoint_distance = eq.length();
+        if param > T::zero() && param < T::one() {
+            let ortho_distance = ab.get_orthonomal(false, false).dot(&aq);
+            if ortho_distance.abs() < endpoint_distance {
+                return (ortho_distance, param);
+            }
+        }
+        ((non_zero_sign(aq.cross(ab))) * endpoint_distance, param)
+    }
+}
+impl<T: Number + 'static> FlatSegment for LinearSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        self.signed_distance_param(p).0
+    }
+    fn signed_pseudo_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        let (distance, param) = self.signed_distance_param(p);
+        distance_to_pseudo_distance(self, p, distance, param)
+    }
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        flat_curvature(self.derivative(t), self.second_derivative(t))
+    }
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        self.direction(t).get_orthonomal(true, false)
+    }
+    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        *self.end() - *self.start()
+    }
+}
+
+impl<T: FloatingPoint> QuadraticSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// the signed distance to p along with the parameter of the closest point,
+    /// which is outside of 0 to 1 when the closest point is one of the endpoints.
+    pub(crate) fn signed_distance_param(&self, p: Vector2<T>) -> (T, T) {
+        let qa = *self.get_point(0)-p;
+        let ab = *self.get_point(1)-*self.get_point(0);
+        let br = *self.get_point(2)-*self.get_point(1)-ab;
+        let a = br.dot(&br);
+        let b = 3.0.as_()*ab.dot(&br);
+        let c = 2.0.as_()*ab.dot(&ab)+qa.dot(&br);
+        let d = qa.dot(&ab);
+        let (solutions, t) = solve_cubic(a, b, c, d);
+        let mut ep_dir = self.direction(T::zero());
+        let mut min_distance = non_zero_sign(ep_dir.cross(qa))*qa.length(); // distance from A
+        let mut param = -qa.dot(&ep_dir)/ep_dir.dot(&ep_dir);
+        {
+            ep_dir = self.direction(T::one());
+            let distance = (*self.get_point(2)-p).length(); // distance from B
+            if distance < min_distance.abs() {
+                let crossepdir = ep_dir.cross(*self.get_point(2)-p);
+                min_distance = (non_zero_sign(crossepdir))*distance;
+                param = (p-*self.get_point(1)).dot(&ep_dir)/ep_dir.dot(&ep_dir);
+            }
+        }
+        for i in 0..solutions as usize {
+            if t[i] > T::zero() && t[i] < T::one() {
+                let qe: Vector2<T> = qa + ab * 2.0.as_() * t[i] + br * t[i] * t[i];
+                let distance = qe.length();
+                if distance <= min_distance.abs() {
+                    min_distance = (non_zero_sign((ab+br*t[i]).cross(qe)))*distance;
+                    param = t[i];
+                }
+            }
+        }
+        (min_distance, param)
+    }
+}
+impl<T: Number + 'static> FlatSegment for QuadraticSegment<Vector2<T>>
+    where f32: AsPrimitive<T>,
+    f64: AsPrimitive<T>, {
+    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        let tangent = lerp(self.points[1]-self.points[0], self.points[2]-self.points[1], t);
+        if (tangent.x == T::zero() && tangent.y == T::zero()) {
+            return self.points[2]-self.points[0];
+        }
+        tangent
+    }
+    #[cfg(not(feature = "experimental"))]
+    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        self.signed_distance_param(p).0
+    }
+    fn signed_pseudo_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        let (distance, param) = self.signed_distance_param(p);
+        distance_to_pseudo_distance(self, p, distance, param)
+    }
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        flat_curvature(self.derivative(t), self.second_derivative(t))
+    }
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        self.direction(t).get_orthonomal(true, false)
+    }
+
+    #[cfg(feature = "experimental")]
+    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        sd_bezier(p, self.points[0], self.points[1], self.points[2])// * 1.1.as_()
+    }
+}
+
+/// the amount of evenly spaced starting parameters used to search for the closest point on a cubic.
+const CUBIC_SEARCH_STARTS: usize = 4;
+/// the amount of newton iterations done from each starting parameter.
+const CUBIC_SEARCH_STEPS: usize = 8;
+
+impl<T: FloatingPoint> CubicSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// the signed distance to p along with the parameter of the closest point,
+    /// which is outside of 0 to 1 when the closest point is one of the endpoints.
+    /// the closest point is found by running newton's method on the derivative of the
+    /// squared distance from several starting points, similar to *signedDistance* in [msdfgen](https://github.com/Chlumsky/msdfgen/blob/master/core/edge-segments.cpp).
+    pub(crate) fn signed_distance_param(&self, p: Vector2<T>) -> (T, T) {
+        let qa = *self.get_point(0)-p;
+        let ab = *self.get_point(1)-*self.get_point(0);
+        let br = *self.get_point(2)-*self.get_point(1)-ab;
+        let as_ = (*self.get_point(3)-*self.get_point(2))-(*self.get_point(2)-*self.get_point(1))-br;
+
+        let mut ep_dir = self.direction(T::zero());
+        let mut min_distance = non_zero_sign(ep_dir.cross(qa))*qa.length(); // distance from A
+        let mut param = projected_param(-qa, ep_dir);
+        {
+            ep_dir = self.direction(T::one());
+            let bq = p-*self.get_point(3);
+            let distance = bq.length(); // distance from B
+            if distance < min_distance.abs() {
+                min_distance = non_zero_sign(bq.cross(ep_dir))*distance;
+                param = T::one()+projected_param(bq, ep_dir);
+            }
+        }
+        // the distance to the curve is minimized where qe is perpendicular to the tangent
+        for i in 0..=CUBIC_SEARCH_STARTS {
+            let mut t: T = (i as f32 / CUBIC_SEARCH_STARTS as f32).as_();
+            let mut qe: Vector2<T> = qa+ab*3.0.as_()*t+br*3.0.as_()*t*t+as_*t*t*t;
+            for _ in 0..CUBIC_SEARCH_STEPS {
+                let d1: Vector2<T> = ab*3.0.as_()+br*6.0.as_()*t+as_*3.0.as_()*t*t;
+                let d2: Vector2<T> = br*6.0.as_()+as_*6.0.as_()*t;
+                let denominator = d1.dot(&d1)+qe.dot(&d2);
+                if denominator == T::zero() {
+                    break;
+                }
+                t -= qe.dot(&d1)/denominator;
+                if t <= T::zero() || t >= T::one() {
+                    break;
+                }
+                qe = qa+ab*3.0.as_()*t+br*3.0.as_()*t*t+as_*t*t*t;
+                let distance = qe.length();
+                if distance < min_distance.abs() {
+                    min_distance = non_zero_sign(self.direction(t).cross(qe))*distance;
+                    param = t;
+                }
+            }
+        }
+        (min_distance, param)
+    }
+}
+
+/// the parameter of the projection of v onto dir, 0 when dir has no length.
+fn projected_param<T: FloatingPoint>(v: Vector2<T>, dir: Vector2<T>) -> T {
+    let length_squared = dir.dot(&dir);
+    if length_squared == T::zero() {
+        return T::zero();
+    }
+    v.dot(&dir)/length_squared
+}
+
+impl<T: Number + 'static> FlatSegment for CubicSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// the tangent of the curve at t. when control points overlap an endpoint the tangent
+    /// there has no length, in which case the direction towards the next distinct point is used.
+    fn direction(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        let tangent = lerp(lerp(self.points[1]-self.points[0], self.points[2]-self.points[1], t), lerp(self.points[2]-self.points[1], self.points[3]-self.points[2], t), t);
+        if tangent.x == T::zero() && tangent.y == T::zero() {
+            if t == T::zero() {
+                let dir = self.points[2]-self.points[0];
+                return if dir.x == T::zero() && dir.y == T::zero() { self.points[3]-self.points[0] } else { dir };
+            }
+            if t == T::one() {
+                let dir = self.points[3]-self.points[1];
+                return if dir.x == T::zero() && dir.y == T::zero() { self.points[3]-self.points[0] } else { dir };
+            }
+        }
+        tangent
+    }
+    fn signed_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+            where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        self.signed_distance_param(p).0
+    }
+    fn signed_pseudo_distance(&self, p: <Self as Segment>::VectorType) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        let (distance, param) = self.signed_distance_param(p);
+        distance_to_pseudo_distance(self, p, distance, param)
+    }
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        flat_curvature(self.derivative(t), self.second_derivative(t))
+    }
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        self.direction(t).get_orthonomal(true, false)
+    }
+}
+
+impl<T: Number + 'static> SolidSegment for LinearSegment<Vector3<T>> {
+    fn curvature(&self, _t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        T::zero()
+    }
+    fn normal(&self, _t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        Vector3::new(T::zero(), T::zero(), T::zero())
+    }
+}
+impl<T: Number + 'static> SolidSegment for QuadraticSegment<Vector3<T>> {
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        solid_curvature(self.derivative(t), self.second_derivative(t))
+    }
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        solid_normal(self.derivative(t), self.second_derivative(t))
+    }
+}
+impl<T: Number + 'static> SolidSegment for CubicSegment<Vector3<T>> {
+    fn curvature(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <<Self as Segment>::VectorType as Vector>::Scalar
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        solid_curvature(self.derivative(t), self.second_derivative(t))
+    }
+    fn normal(&self, t: <<Self as Segment>::VectorType as Vector>::Scalar) -> <Self as Segment>::VectorType
+        where <<Self as Segment>::VectorType as Vector>::Scalar: FloatingPoint {
+        solid_normal(self.derivative(t), self.second_derivative(t))
+    }
+}
@@ -1,89 +1,575 @@
-use std::ops::{Index, IndexMut};
-
-use num_traits::Zero;
-
-use crate::linear::vector::Vector;
-
-use super::{FVec3, traits::Number, vector::Vector2};
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Triangle<T: Vector> {
-    points: [T; 3]
-}
-impl<T: Vector> Triangle<T> {
-    fn new(p0: T, p1: T, p2: T) -> Self {
-        Self { points: [p0, p1, p2] }
-    }
-}
-impl<T: Vector> Index<usize> for Triangle<T> {
-    fn index(&self, index: usize) -> &Self::Output {
-        debug_assert!(index > 2, "index for a triangle can not exceed index 2 since it only has 3 points");
-        &self.points[index]
-    }
-    type Output = T;
-}
-
-impl<T: Vector> IndexMut<usize> for Triangle<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        debug_assert!(index > 2, "index for a triangle can not exceed index 2 since it only has 3 points");
-        &mut self.points[index]
-    }
-}
-
-pub struct Rectangle<T: Number> {
-    top_left: Vector2<T>,
-    top_right: Vector2<T>,
-    bottom_left: Vector2<T>,
-    bottom_right: Vector2<T>,
-}
-impl<T: Number> Rectangle<T>  {
-    pub fn new(top_left: Vector2<T>, top_right: Vector2<T>, bottom_left: Vector2<T>, bottom_right: Vector2<T>) -> Self {
-        Self { top_left, top_right, bottom_left, bottom_right }
-    }
-}
-pub struct Cube<T: Number> {
-    front: Rectangle<T>,
-    back: Rectangle<T>,
-}
-impl<T: Number> Cube<T>  {
-    pub fn new(front: Rectangle<T>, back: Rectangle<T>) -> Self {
-        Self { front, back }
-    }
-}
-
-// Simplex Code
-#[derive(Clone, Copy)]
-pub struct Simplex<T: Vector + Zero, const N: usize> {
-    pub points: [T; N],
-    pub size: usize,
-}
-
-impl<T: Vector + Zero, const N: usize> Simplex<T, N> {
-    pub fn new() -> Self {
-        Self { points: [T::zero(); N], size: 0 }
-    }
-    pub fn push(&mut self, point: T) {
-        for i in (1..N).rev() {
-            self.points[i] = self.points[i - 1];
-        }
-        self.points[0] = point;
-        self.size = std::cmp::min(self.size + 1, N);
-    }
-    pub fn from_slice(slice: &[T]) -> Self {
-        let mut points: [T; N] = [T::zero(); N];
-        for (i, s) in slice.iter().enumerate() {
-            if i > N { break; }
-            points[i] = *s;
-        }
-        let size = slice.len();
-        Self { points, size }
-    }
-    pub fn initialize(&mut self, list: Vec<T>) {
-        for (i, v) in list.iter().enumerate() { self.points[i] = *v; }
-        self.size = list.len();
-    }
-    pub fn to_vec(&self) -> Vec<T> {
-        (0..self.size).into_iter().map(|i|{ self.points[i] }).collect()
-    }
+use std::ops::{Index, IndexMut};
+
+use num_traits::{AsPrimitive, Float, Zero};
+
+use crate::linear::vector::Vector;
+
+use crate::complex::quaternion::Quaternion;
+
+use super::{FVec3, traits::{Number, FloatingPoint}, vector::{Vector2, Vector3}, geometry::EuclideanGeometry, barycentric_coordinates, Aabb3, Matrix3, SquareMatrix};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Triangle<T: Vector> {
+    points: [T; 3]
+}
+impl<T: Vector> Triangle<T> {
+    pub fn new(p0: T, p1: T, p2: T) -> Self {
+        Self { points: [p0, p1, p2] }
+    }
+    pub fn points(&self) -> &[T; 3] {
+        &self.points
+    }
+}
+impl<T: Vector> From<[T; 3]> for Triangle<T> {
+    fn from(points: [T; 3]) -> Self {
+        Self { points }
+    }
+}
+impl<T: Vector> Index<usize> for Triangle<T> {
+    fn index(&self, index: usize) -> &Self::Output {
+        debug_assert!(index < 3, "index for a triangle can not exceed index 2 since it only has 3 points");
+        &self.points[index]
+    }
+    type Output = T;
+}
+
+impl<T: Vector> IndexMut<usize> for Triangle<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        debug_assert!(index < 3, "index for a triangle can not exceed index 2 since it only has 3 points");
+        &mut self.points[index]
+    }
+}
+
+impl<T: Vector> Triangle<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// the area of the triangle, half the length of the cross product of two of its sides which is
+    /// worked out from dot products so it works in any dimension.
+    pub fn area(&self) -> T::Scalar {
+        let [a, b, c] = self.points;
+        let (ab, ac) = (b - a, c - a);
+        let squared = ab.dot(&ab) * ac.dot(&ac) - ab.dot(&ac) * ab.dot(&ac);
+        squared.max(T::Scalar::zero()).sqrt() * 0.5.as_()
+    }
+    /// the average of the points, where the triangle balances.
+    pub fn centroid(&self) -> T {
+        let [a, b, c] = self.points;
+        (a + b + c) / 3.0.as_()
+    }
+    /// # circumcenter
+    ///
+    /// the center of the circle going through all three points, which is the same distance from
+    /// each of them. [`None`] when the points are on a line and there's no such circle.
+    pub fn circumcenter(&self) -> Option<T> {
+        let [a, b, c] = self.points;
+        let (ab, ac) = (b - a, c - a);
+        let (ab2, ac2, dot) = (ab.dot(&ab), ac.dot(&ac), ab.dot(&ac));
+        let det = (ab2 * ac2 - dot * dot) * 2.0.as_();
+        if det.abs() <= T::Scalar::epsilon() * ab2 * ac2 {
+            return None;
+        }
+        // the center is where a + s·ab + t·ac is as far from a as from b and c
+        let s = ac2 * (ab2 - dot) / det;
+        let t = ab2 * (ac2 - dot) / det;
+        Some(a + ab * s + ac * t)
+    }
+    /// the center of the circle that touches all three sides from the inside, the average of the
+    /// points weighted by the length of the side across from them.
+    pub fn incenter(&self) -> T {
+        let [a, b, c] = self.points;
+        let (la, lb, lc) = ((c - b).length(), (a - c).length(), (b - a).length());
+        let perimeter = la + lb + lc;
+        if perimeter == T::Scalar::zero() {
+            return a;
+        }
+        (a * la + b * lb + c * lc) / perimeter
+    }
+    /// # barycentric
+    ///
+    /// the weights of the three points that add up to p, in the same order as the points and
+    /// summing to 1. see [`barycentric_coordinates`], points off the plane of the triangle get the
+    /// weights of where they project onto it.
+    pub fn barycentric(&self, p: T) -> Vector3<T::Scalar> {
+        let [a, b, c] = self.points;
+        let weights = barycentric_coordinates(a, b, c, p);
+        Vector3::new(weights.z, weights.y, weights.x)
+    }
+    /// the point at the weights of [`Triangle::barycentric`].
+    pub fn interpolate(&self, weights: Vector3<T::Scalar>) -> T {
+        let [a, b, c] = self.points;
+        a * weights.x + b * weights.y + c * weights.z
+    }
+    /// whether p is inside the triangle or on its sides. points off the plane of a triangle in
+    /// 3d are tested by where they project onto it, and triangles without area contain nothing.
+    pub fn contains(&self, p: T) -> bool {
+        let weights = self.barycentric(p);
+        let zero = T::Scalar::zero();
+        weights.x >= zero && weights.y >= zero && weights.z >= zero
+    }
+    /// # closest_point
+    ///
+    /// the point of the triangle closest to p, found by checking which corner, side or the inside
+    /// p is nearest to from [Christer Ericson's Real-Time Collision Detection](https://realtimecollisiondetection.net/).
+    pub fn closest_point(&self, p: T) -> T {
+        let [a, b, c] = self.points;
+        let zero = T::Scalar::zero();
+        let (ab, ac, ap) = (b - a, c - a, p - a);
+        let (d1, d2) = (ab.dot(&ap), ac.dot(&ap));
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+        let bp = p - b;
+        let (d3, d4) = (ab.dot(&bp), ac.dot(&bp));
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = p - c;
+        let (d5, d6) = (ab.dot(&cp), ac.dot(&cp));
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denom = va + vb + vc;
+        a + ab * (vb / denom) + ac * (vc / denom)
+    }
+}
+impl<T: FloatingPoint> Triangle<Vector2<T>> {
+    /// the area of the triangle, positive when the points go counter clockwise.
+    pub fn signed_area(&self) -> T {
+        let [a, b, c] = self.points;
+        (b - a).cross(c - a) / (T::one() + T::one())
+    }
+}
+impl<T: FloatingPoint> Triangle<Vector3<T>> {
+    /// the unit vector facing away from the side the points go counter clockwise around.
+    pub fn normal(&self) -> Vector3<T> {
+        let [a, b, c] = self.points;
+        (b - a).cross(c - a).normalize()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rectangle<T: Number> {
+    top_left: Vector2<T>,
+    top_right: Vector2<T>,
+    bottom_left: Vector2<T>,
+    bottom_right: Vector2<T>,
+}
+impl<T: Number> Rectangle<T>  {
+    pub fn new(top_left: Vector2<T>, top_right: Vector2<T>, bottom_left: Vector2<T>, bottom_right: Vector2<T>) -> Self {
+        Self { top_left, top_right, bottom_left, bottom_right }
+    }
+    pub fn top_left(&self) -> Vector2<T> {
+        self.top_left
+    }
+    pub fn top_right(&self) -> Vector2<T> {
+        self.top_right
+    }
+    pub fn bottom_left(&self) -> Vector2<T> {
+        self.bottom_left
+    }
+    pub fn bottom_right(&self) -> Vector2<T> {
+        self.bottom_right
+    }
+}
+/// a cube made from the rectangles on its front and back faces along with
+/// the depth on the z axis at which each face is placed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cube<T: Number> {
+    front: Rectangle<T>,
+    back: Rectangle<T>,
+    front_depth: T,
+    back_depth: T,
+}
+impl<T: Number> Cube<T>  {
+    /// both faces are placed at a depth of 0, use [`Cube::with_depth`] to place them.
+    pub fn new(front: Rectangle<T>, back: Rectangle<T>) -> Self {
+        Self { front, back, front_depth: T::zero(), back_depth: T::zero() }
+    }
+    pub fn with_depth(front: Rectangle<T>, front_depth: T, back: Rectangle<T>, back_depth: T) -> Self {
+        Self { front, back, front_depth, back_depth }
+    }
+    pub fn front(&self) -> &Rectangle<T> {
+        &self.front
+    }
+    pub fn back(&self) -> &Rectangle<T> {
+        &self.back
+    }
+    pub fn front_depth(&self) -> T {
+        self.front_depth
+    }
+    pub fn back_depth(&self) -> T {
+        self.back_depth
+    }
+}
+
+/// the point of the line segment from start to end closest to p.
+fn closest_on_segment<T: FloatingPoint>(start: Vector3<T>, end: Vector3<T>, p: Vector3<T>) -> Vector3<T> {
+    let axis = end - start;
+    let length = axis.dot(&axis);
+    if length == T::zero() {
+        return start;
+    }
+    let t = (p - start).dot(&axis) / length;
+    start + axis * t.max(T::zero()).min(T::one())
+}
+/// how far a disc of radius facing axis reaches along each of the x, y and z axes.
+fn disc_extents<T: FloatingPoint>(axis: Vector3<T>, radius: T) -> Vector3<T> {
+    let axis = axis.normalize();
+    let reach = |a: T| radius * (T::one() - a * a).max(T::zero()).sqrt();
+    Vector3::new(reach(axis.x), reach(axis.y), reach(axis.z))
+}
+/// splits the offset of p from start into how far along axis it is, as a multiple of axis, and the
+/// part of it going around axis.
+fn split_offset<T: FloatingPoint>(start: Vector3<T>, axis: Vector3<T>, p: Vector3<T>) -> (T, Vector3<T>) {
+    let offset = p - start;
+    let t = offset.dot(&axis) / axis.dot(&axis);
+    (t, offset - axis * t)
+}
+
+/// # Sphere
+///
+/// every point within radius of center.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere<T: Number> {
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+impl<T: Number> Sphere<T> {
+    pub fn new(center: Vector3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+impl<T: FloatingPoint> Sphere<T> where f64: AsPrimitive<T> {
+    pub fn bounds(&self) -> Aabb3<T> {
+        Aabb3::new(self.center - self.radius, self.center + self.radius)
+    }
+    pub fn volume(&self) -> T {
+        std::f64::consts::PI.as_() * 4.0.as_() / 3.0.as_() * self.radius * self.radius * self.radius
+    }
+    pub fn surface_area(&self) -> T {
+        std::f64::consts::PI.as_() * 4.0.as_() * self.radius * self.radius
+    }
+    /// whether the point is inside of the sphere or on its surface.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let offset = point - self.center;
+        offset.dot(&offset) <= self.radius * self.radius
+    }
+    /// the point inside of the sphere closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let offset = point - self.center;
+        let distance = offset.length();
+        if distance <= self.radius {
+            return point;
+        }
+        self.center + offset * (self.radius / distance)
+    }
+}
+
+/// # Capsule
+///
+/// every point within radius of the line segment from start to end, a cylinder with half spheres
+/// on its ends.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Capsule<T: Number> {
+    pub start: Vector3<T>,
+    pub end: Vector3<T>,
+    pub radius: T,
+}
+impl<T: Number> Capsule<T> {
+    pub fn new(start: Vector3<T>, end: Vector3<T>, radius: T) -> Self {
+        Self { start, end, radius }
+    }
+}
+impl<T: FloatingPoint> Capsule<T> where f64: AsPrimitive<T> {
+    pub fn bounds(&self) -> Aabb3<T> {
+        Aabb3::from_point(self.start).include(self.end).expand(self.radius)
+    }
+    pub fn volume(&self) -> T {
+        let (r, h) = (self.radius, (self.end - self.start).length());
+        std::f64::consts::PI.as_() * r * r * (h + r * 4.0.as_() / 3.0.as_())
+    }
+    pub fn surface_area(&self) -> T {
+        let (r, h) = (self.radius, (self.end - self.start).length());
+        std::f64::consts::PI.as_() * 2.0.as_() * r * (h + r * 2.0.as_())
+    }
+    /// whether the point is inside of the capsule or on its surface.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let offset = point - closest_on_segment(self.start, self.end, point);
+        offset.dot(&offset) <= self.radius * self.radius
+    }
+    /// the point inside of the capsule closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        Sphere::new(closest_on_segment(self.start, self.end, point), self.radius).closest_point(point)
+    }
+}
+
+/// # Plane
+///
+/// every point p where normal · p = d. the normal is a unit vector so d is how far the plane is from
+/// the origin along it. the side the normal faces is in front of the plane and the side behind it is
+/// solid, which is what [`Plane::contains_point`] tests. a plane goes on forever so it has no bounds,
+/// volume or area.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Plane<T: Number> {
+    pub normal: Vector3<T>,
+    pub d: T,
+}
+impl<T: Number> Plane<T> {
+    pub fn new(normal: Vector3<T>, d: T) -> Self {
+        Self { normal, d }
+    }
+}
+impl<T: FloatingPoint> Plane<T> {
+    /// the plane through point facing normal, which doesn't need to be a unit vector.
+    pub fn from_point(point: Vector3<T>, normal: Vector3<T>) -> Self {
+        let normal = normal.normalize();
+        Self { normal, d: normal.dot(&point) }
+    }
+    /// the plane through all three points facing the side they go counter clockwise around, or
+    /// [`None`] when the points are on a line.
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        if normal.dot(&normal) == T::zero() {
+            return None;
+        }
+        Some(Self::from_point(a, normal))
+    }
+    /// the same plane facing the other way.
+    pub fn flip(&self) -> Self {
+        Self { normal: -self.normal, d: -self.d }
+    }
+    /// how far in front of the plane the point is, negative when it's behind.
+    pub fn signed_distance(&self, point: Vector3<T>) -> T {
+        self.normal.dot(&point) - self.d
+    }
+    /// the point on the plane closest to point.
+    pub fn project(&self, point: Vector3<T>) -> Vector3<T> {
+        point - self.normal * self.signed_distance(point)
+    }
+    /// whether the point is behind the plane or on it.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.signed_distance(point) <= T::zero()
+    }
+    /// the point behind the plane closest to point, which is point itself when it's behind.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        if self.contains_point(point) { point } else { self.project(point) }
+    }
+}
+
+/// # Obb
+///
+/// an oriented bounding box, a box turned by rotation around its center. the x, y and z of rotation
+/// are the directions of the sides of the box, so `rotation * (p - center)` takes a point into the
+/// space of the box and `center + p * rotation` takes it back out. every component of half_extents
+/// is the distance from the center to the sides along that direction.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Obb<T: Number> {
+    pub center: Vector3<T>,
+    pub half_extents: Vector3<T>,
+    pub rotation: Matrix3<T>,
+}
+impl<T: Number> Obb<T> {
+    pub fn new(center: Vector3<T>, half_extents: Vector3<T>, rotation: Matrix3<T>) -> Self {
+        Self { center, half_extents, rotation }
+    }
+    /// a box turned by a unit quaternion.
+    pub fn from_quaternion(center: Vector3<T>, half_extents: Vector3<T>, rotation: Quaternion<T>) -> Self {
+        Self { center, half_extents, rotation: Matrix3::from(rotation) }
+    }
+    /// the same box as the aabb without any rotation.
+    pub fn from_aabb(aabb: &Aabb3<T>) -> Self {
+        Self { center: aabb.center(), half_extents: aabb.extents(), rotation: Matrix3::identity() }
+    }
+    /// the unit vectors along the sides of the box.
+    pub fn axes(&self) -> [Vector3<T>; 3] {
+        [self.rotation.x, self.rotation.y, self.rotation.z]
+    }
+    /// the point in the space of the box, with the center at the origin and the sides along the axes.
+    pub fn to_local(&self, point: Vector3<T>) -> Vector3<T> {
+        self.rotation * (point - self.center)
+    }
+    /// the point in the space of the box back in world space.
+    pub fn to_world(&self, point: Vector3<T>) -> Vector3<T> {
+        self.center + point * self.rotation
+    }
+    /// the eight corners of the box, going through the signs of the half extents like binary numbers
+    /// with x changing fastest.
+    pub fn corners(&self) -> [Vector3<T>; 8] {
+        let e = self.half_extents;
+        std::array::from_fn(|i| {
+            let pick = |bit: usize, extent: T| if i & bit == 0 { T::zero() - extent } else { extent };
+            self.to_world(Vector3::new(pick(1, e.x), pick(2, e.y), pick(4, e.z)))
+        })
+    }
+    pub fn volume(&self) -> T {
+        let e = self.half_extents;
+        let two = T::one() + T::one();
+        e.x * e.y * e.z * two * two * two
+    }
+    pub fn surface_area(&self) -> T {
+        let e = self.half_extents;
+        let two = T::one() + T::one();
+        (e.x * e.y + e.y * e.z + e.z * e.x) * two * two * two
+    }
+}
+impl<T: FloatingPoint> Obb<T> {
+    pub fn bounds(&self) -> Aabb3<T> {
+        let abs = |v: Vector3<T>| Vector3::new(v.x.abs(), v.y.abs(), v.z.abs());
+        let e = self.half_extents;
+        let reach = abs(self.rotation.x) * e.x + abs(self.rotation.y) * e.y + abs(self.rotation.z) * e.z;
+        Aabb3::new(self.center - reach, self.center + reach)
+    }
+    /// whether the point is inside of the box or on its faces.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let (local, e) = (self.to_local(point), self.half_extents);
+        local.x.abs() <= e.x && local.y.abs() <= e.y && local.z.abs() <= e.z
+    }
+    /// the point inside of the box closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let e = self.half_extents;
+        let local = Aabb3::new(-e, e).closest_point(self.to_local(point));
+        self.to_world(local)
+    }
+}
+
+/// # Cylinder
+///
+/// every point within radius of the line segment from start to end, with flat caps at both ends.
+/// start and end should be different points.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Cylinder<T: Number> {
+    pub start: Vector3<T>,
+    pub end: Vector3<T>,
+    pub radius: T,
+}
+impl<T: Number> Cylinder<T> {
+    pub fn new(start: Vector3<T>, end: Vector3<T>, radius: T) -> Self {
+        Self { start, end, radius }
+    }
+}
+impl<T: FloatingPoint> Cylinder<T> where f64: AsPrimitive<T> {
+    pub fn bounds(&self) -> Aabb3<T> {
+        let reach = disc_extents(self.end - self.start, self.radius);
+        let aabb = Aabb3::from_point(self.start).include(self.end);
+        Aabb3::new(aabb.min - reach, aabb.max + reach)
+    }
+    pub fn volume(&self) -> T {
+        std::f64::consts::PI.as_() * self.radius * self.radius * (self.end - self.start).length()
+    }
+    pub fn surface_area(&self) -> T {
+        let (r, h) = (self.radius, (self.end - self.start).length());
+        std::f64::consts::PI.as_() * 2.0.as_() * r * (h + r)
+    }
+    /// whether the point is inside of the cylinder or on its surface.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let (t, around) = split_offset(self.start, self.end - self.start, point);
+        t >= T::zero() && t <= T::one() && around.dot(&around) <= self.radius * self.radius
+    }
+    /// the point inside of the cylinder closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let axis = self.end - self.start;
+        let (t, around) = split_offset(self.start, axis, point);
+        let distance = around.length();
+        let around = if distance > self.radius { around * (self.radius / distance) } else { around };
+        self.start + axis * t.max(T::zero()).min(T::one()) + around
+    }
+}
+
+/// # Cone
+///
+/// a cone with its point at apex and a flat round base of radius around base. apex and base should
+/// be different points.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Cone<T: Number> {
+    pub apex: Vector3<T>,
+    pub base: Vector3<T>,
+    pub radius: T,
+}
+impl<T: Number> Cone<T> {
+    pub fn new(apex: Vector3<T>, base: Vector3<T>, radius: T) -> Self {
+        Self { apex, base, radius }
+    }
+}
+impl<T: FloatingPoint> Cone<T> where f64: AsPrimitive<T> {
+    pub fn height(&self) -> T {
+        (self.base - self.apex).length()
+    }
+    pub fn bounds(&self) -> Aabb3<T> {
+        let reach = disc_extents(self.base - self.apex, self.radius);
+        Aabb3::new(self.base - reach, self.base + reach).include(self.apex)
+    }
+    pub fn volume(&self) -> T {
+        std::f64::consts::PI.as_() * self.radius * self.radius * self.height() / 3.0.as_()
+    }
+    pub fn surface_area(&self) -> T {
+        let (r, h) = (self.radius, self.height());
+        std::f64::consts::PI.as_() * r * (r + (h * h + r * r).sqrt())
+    }
+    /// whether the point is inside of the cone or on its surface.
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        let (t, around) = split_offset(self.apex, self.base - self.apex, point);
+        let radius = self.radius * t;
+        t >= T::zero() && t <= T::one() && around.dot(&around) <= radius * radius
+    }
+    /// the point inside of the cone closest to point, which is point itself when it's inside.
+    pub fn closest_point(&self, point: Vector3<T>) -> Vector3<T> {
+        // the cone is the triangle from the apex to the middle and the edge of the base turned around
+        // its axis, so the closest point is in the same half plane through the axis as point
+        let axis = self.base - self.apex;
+        let height = axis.length();
+        let (t, around) = split_offset(self.apex, axis, point);
+        let distance = around.length();
+        let triangle = Triangle::new(Vector2::zero(), Vector2::new(height, T::zero()), Vector2::new(height, self.radius));
+        let closest = triangle.closest_point(Vector2::new(t * height, distance));
+        let outward = if distance > T::zero() { around / distance } else { around };
+        self.apex + axis * (closest.x / height) + outward * closest.y
+    }
+}
+
+// Simplex Code
+#[derive(Clone, Copy, Debug)]
+pub struct Simplex<T: Vector + Zero, const N: usize> {
+    pub points: [T; N],
+    pub size: usize,
+}
+
+impl<T: Vector + Zero, const N: usize> Simplex<T, N> {
+    pub fn new() -> Self {
+        Self { points: [T::zero(); N], size: 0 }
+    }
+    pub fn push(&mut self, point: T) {
+        for i in (1..N).rev() {
+            self.points[i] = self.points[i - 1];
+        }
+        self.points[0] = point;
+        self.size = std::cmp::min(self.size + 1, N);
+    }
+    pub fn from_slice(slice: &[T]) -> Self {
+        let mut points: [T; N] = [T::zero(); N];
+        for (i, s) in slice.iter().enumerate() {
+            if i >= N { break; }
+            points[i] = *s;
+        }
+        let size = std::cmp::min(slice.len(), N);
+        Self { points, size }
+    }
+    pub fn initialize(&mut self, list: Vec<T>) {
+        for (i, v) in list.iter().enumerate() { self.points[i] = *v; }
+        self.size = list.len();
+    }
+    pub fn to_vec(&self) -> Vec<T> {
+        (0..self.size).into_iter().map(|i|{ self.points[i] }).collect()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,466 @@
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::{AsPrimitive, Float, One, Zero};
+
+use crate::linear::{Vector, Segment, LinearSegment, QuadraticSegment, CubicSegment, FloatingPoint};
+
+/// # HermiteSegment
+///
+/// a cubic segment given by its endpoints and the velocity of the curve at each of them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HermiteSegment<T: Vector> {
+    points: [T; 2],
+    tangents: [T; 2],
+}
+
+impl<T: Vector> HermiteSegment<T> {
+    pub fn new(p0: T, m0: T, p1: T, m1: T) -> Self {
+        Self { points: [p0, p1], tangents: [m0, m1] }
+    }
+    /// the velocity at the start and at the end.
+    pub fn tangents(&self) -> &[T; 2] {
+        &self.tangents
+    }
+    pub fn reverse(&self) -> Self
+        where T: std::ops::Neg<Output = T> {
+        Self::new(self.points[1], -self.tangents[1], self.points[0], -self.tangents[0])
+    }
+}
+impl<T: Vector> HermiteSegment<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// the bézier form of the segment, which traces the exact same curve.
+    pub fn to_cubic(&self) -> CubicSegment<T> {
+        let third: T::Scalar = (1.0 / 3.0).as_();
+        CubicSegment::new(self.points[0], self.points[0] + self.tangents[0] * third, self.points[1] - self.tangents[1] * third, self.points[1])
+    }
+}
+impl<T: Vector> From<CubicSegment<T>> for HermiteSegment<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    fn from(cubic: CubicSegment<T>) -> Self {
+        let three: T::Scalar = 3.0.as_();
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| *cubic.get_point(i));
+        Self::new(p0, (p1 - p0) * three, p3, (p3 - p2) * three)
+    }
+}
+impl<T: Vector> From<HermiteSegment<T>> for CubicSegment<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    fn from(hermite: HermiteSegment<T>) -> Self {
+        hermite.to_cubic()
+    }
+}
+
+impl<T: Vector> Segment for HermiteSegment<T> {
+    fn start(&self) -> &Self::VectorType {
+        &self.points[0]
+    }
+    fn end(&self) -> &Self::VectorType {
+        &self.points[1]
+    }
+    fn get_point(&self, idx: usize) -> &Self::VectorType {
+        &self.points[idx]
+    }
+    fn set_start(&mut self, value: &Self::VectorType) {
+        self.points[0] = *value;
+    }
+    fn set_end(&mut self, value: &Self::VectorType) {
+        self.points[1] = *value;
+    }
+    /// evaluates the hermite basis functions, h00 = 2t³ - 3t² + 1, h10 = t³ - 2t² + t,
+    /// h01 = -2t³ + 3t² and h11 = t³ - t².
+    fn interpolate(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let one = T::Scalar::one();
+        let two = one + one;
+        let three = two + one;
+        let (t2, t3) = (t * t, t * t * t);
+        let h00 = two * t3 - three * t2 + one;
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+        self.points[0] * h00 + self.tangents[0] * h10 + self.points[1] * h01 + self.tangents[1] * h11
+    }
+    fn derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let one = T::Scalar::one();
+        let (two, three) = (one + one, one + one + one);
+        let (four, six) = (two + two, three + three);
+        let t2 = t * t;
+        (self.points[0] - self.points[1]) * (six * t2 - six * t)
+            + self.tangents[0] * (three * t2 - four * t + one)
+            + self.tangents[1] * (three * t2 - two * t)
+    }
+    fn second_derivative(&self, t: <Self::VectorType as Vector>::Scalar) -> Self::VectorType
+            where <Self::VectorType as Vector>::Scalar: FloatingPoint {
+        let one = T::Scalar::one();
+        let (two, three) = (one + one, one + one + one);
+        let (four, six) = (two + two, three + three);
+        (self.points[0] - self.points[1]) * (six * (t + t) - six)
+            + self.tangents[0] * (six * t - four)
+            + self.tangents[1] * (six * t - two)
+    }
+    fn len(&self) -> usize { 2 }
+    fn iter(&self) -> std::slice::Iter<'_, T> { self.points.iter() }
+    fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> { self.points.iter_mut() }
+    type VectorType = T;
+}
+
+/// how the knots of a [`CatmullRomSpline`] are spaced, every knot is the distance between
+/// consecutive points raised to the power of alpha after the previous one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CatmullRomParameterization {
+    /// alpha of 0, the classic spline which can form cusps and loops when points are unevenly spaced.
+    Uniform,
+    /// alpha of 0.5, which never forms cusps or loops inside a segment.
+    #[default]
+    Centripetal,
+    /// alpha of 1, which follows the points more tightly.
+    Chordal,
+}
+
+/// # CatmullRomSpline
+///
+/// a curve through every point but the first and the last, which only shape the tangents at the
+/// ends. every segment between two points is a cubic, see [Yuksel et al.'s parameterization and
+/// applications of catmull-rom curves](https://www.cemyuksel.com/research/catmullrom_param/).
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRomSpline<T: Vector> {
+    points: Vec<T>,
+    parameterization: CatmullRomParameterization,
+}
+
+impl<T: Vector> CatmullRomSpline<T> {
+    pub fn new(points: Vec<T>, parameterization: CatmullRomParameterization) -> Self {
+        Self { points, parameterization }
+    }
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+    pub fn parameterization(&self) -> CatmullRomParameterization {
+        self.parameterization
+    }
+    /// the amount of cubic segments, which is 3 less than the amount of points.
+    pub fn segment_count(&self) -> usize {
+        self.points.len().saturating_sub(3)
+    }
+}
+impl<T: Vector> CatmullRomSpline<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// the segment from point i + 1 to point i + 2. the tangents come from the knots of the four
+    /// points around the segment, which makes it the same curve as the recursive definition of
+    /// Barry and Goldman. points on top of each other are given a knot spacing of 1.
+    pub fn segment(&self, i: usize) -> HermiteSegment<T> {
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|j| self.points[i + j]);
+        let alpha: T::Scalar = match self.parameterization {
+            CatmullRomParameterization::Uniform => 0.0,
+            CatmullRomParameterization::Centripetal => 0.5,
+            CatmullRomParameterization::Chordal => 1.0,
+        }.as_();
+        let spacing = |a: T, b: T| {
+            let distance = (b - a).length().powf(alpha);
+            if distance > T::Scalar::zero() { distance } else { T::Scalar::one() }
+        };
+        let (d0, d1, d2) = (spacing(p0, p1), spacing(p1, p2), spacing(p2, p3));
+        let m1 = ((p1 - p0) / d0 - (p2 - p0) / (d0 + d1) + (p2 - p1) / d1) * d1;
+        let m2 = ((p2 - p1) / d1 - (p3 - p1) / (d1 + d2) + (p3 - p2) / d2) * d1;
+        HermiteSegment::new(p1, m1, p2, m2)
+    }
+    /// every segment of the spline as a cubic.
+    pub fn segments(&self) -> impl Iterator<Item = CubicSegment<T>> + '_ {
+        (0..self.segment_count()).map(|i| self.segment(i).to_cubic())
+    }
+    /// the point at t, which goes from 0 at the second point to 1 at the second to last point with
+    /// every segment taking up the same amount of t. [`None`] when there are less than 4 points.
+    pub fn interpolate(&self, t: T::Scalar) -> Option<T> {
+        let (segment, t) = split_parameter(t, self.segment_count())?;
+        Some(self.segment(segment).interpolate(t))
+    }
+    /// the velocity at t, see [`CatmullRomSpline::interpolate`].
+    pub fn derivative(&self, t: T::Scalar) -> Option<T> {
+        let count = self.segment_count();
+        let (segment, local) = split_parameter(t, count)?;
+        Some(self.segment(segment).derivative(local) * (count as f64).as_())
+    }
+}
+/// splits t between 0 and 1 into the segment it's on and how far along that segment it is.
+fn split_parameter<S: FloatingPoint>(t: S, count: usize) -> Option<(usize, S)>
+    where f64: AsPrimitive<S> {
+    if count == 0 {
+        return None;
+    }
+    let scaled = t.max(S::zero()).min(S::one()) * (count as f64).as_();
+    let segment = scaled.floor().to_usize().unwrap_or(0).min(count - 1);
+    Some((segment, scaled - (segment as f64).as_()))
+}
+
+/// de Boor's algorithm on the degree + 1 values of the span starting at the knot at index span,
+/// with a separate parameter for every level so it can evaluate the blossom of the span. giving
+/// every level the same parameter evaluates the spline there.
+fn de_boor<S: FloatingPoint, V: Copy + Add<Output = V> + Mul<S, Output = V>>(knots: &[S], degree: usize, span: usize, values: impl Fn(usize) -> V, parameters: &[S]) -> V {
+    let mut d: Vec<V> = (0..=degree).map(|j| values(j + span - degree)).collect();
+    for r in 1..=degree {
+        let u = parameters[r - 1];
+        for j in (r..=degree).rev() {
+            let left = knots[j + span - degree];
+            let right = knots[j + 1 + span - r];
+            let alpha = if right > left { (u - left) / (right - left) } else { S::zero() };
+            d[j] = d[j - 1] * (S::one() - alpha) + d[j] * alpha;
+        }
+    }
+    d[degree]
+}
+
+/// the control values of the derivative of a spline, p(v[i + 1] - v[i]) / (t[i + p + 1] - t[i + 1]),
+/// which is a spline of degree p - 1 over the same knots without the first and the last.
+fn hodograph_values<S: FloatingPoint, V: Copy + Sub<Output = V> + Mul<S, Output = V>>(knots: &[S], degree: usize, values: &[V], zero: V) -> Vec<V>
+    where f64: AsPrimitive<S> {
+    let p: S = (degree as f64).as_();
+    values.windows(2).enumerate().map(|(i, pair)| {
+        let width = knots[i + degree + 1] - knots[i + 1];
+        if width > S::zero() { (pair[1] - pair[0]) * (p / width) } else { zero }
+    }).collect()
+}
+
+/// # BSpline
+///
+/// a piecewise polynomial curve of any degree shaped by control points and a knot vector, which has
+/// degree + 1 more knots than control points. the curve is defined from the knot at index degree to the
+/// knot at index n where n is the amount of control points. with the same knot repeated degree + 1
+/// times at both ends the curve starts and ends at the first and last control points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BSpline<T: Vector> {
+    degree: usize,
+    control_points: Vec<T>,
+    knots: Vec<T::Scalar>,
+}
+
+impl<T: Vector> BSpline<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// [`None`] when there aren't degree + 1 more knots than control points, there are fewer control
+    /// points than degree + 1 or the knots ever decrease or aren't finite.
+    pub fn new(degree: usize, control_points: Vec<T>, knots: Vec<T::Scalar>) -> Option<Self> {
+        if control_points.len() <= degree || knots.len() != control_points.len() + degree + 1 {
+            return None;
+        }
+        if knots.iter().any(|k| !k.is_finite()) || knots.windows(2).any(|pair| pair[1] < pair[0]) {
+            return None;
+        }
+        Some(Self { degree, control_points, knots })
+    }
+    /// a spline with the knots 0, 1, 2, ... which doesn't go through its first and last control points.
+    pub fn uniform(degree: usize, control_points: Vec<T>) -> Option<Self> {
+        let knots = (0..control_points.len() + degree + 1).map(|i| (i as f64).as_()).collect();
+        Self::new(degree, control_points, knots)
+    }
+    /// a spline going from 0 to 1 with evenly spaced knots inside and degree + 1 knots at each
+    /// end, so it starts at the first control point and ends at the last.
+    pub fn clamped(degree: usize, control_points: Vec<T>) -> Option<Self> {
+        if control_points.len() <= degree {
+            return None;
+        }
+        let spans = control_points.len() - degree;
+        let knots = (0..control_points.len() + degree + 1).map(|i| {
+            let i = i.saturating_sub(degree).min(spans);
+            (i as f64 / spans as f64).as_()
+        }).collect();
+        Self::new(degree, control_points, knots)
+    }
+    /// a clamped cubic spline tracing the cubics, which have to be connected end to start. every
+    /// join gets a knot of multiplicity 3 so the spans are exactly the cubics, with the knots
+    /// counting up by one for every cubic. [`None`] when there are no cubics or they aren't connected.
+    pub fn from_cubics(cubics: &[CubicSegment<T>]) -> Option<Self> {
+        if cubics.is_empty() || cubics.windows(2).any(|pair| pair[0].end() != pair[1].start()) {
+            return None;
+        }
+        let mut control_points = vec![*cubics[0].start()];
+        let mut knots = vec![T::Scalar::zero(); 4];
+        for (i, cubic) in cubics.iter().enumerate() {
+            control_points.extend([1, 2, 3].map(|j| *cubic.get_point(j)));
+            let knot: T::Scalar = ((i + 1) as f64).as_();
+            knots.extend(std::iter::repeat_n(knot, if i + 1 == cubics.len() { 4 } else { 3 }));
+        }
+        Self::new(3, control_points, knots)
+    }
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+    pub fn control_points(&self) -> &[T] {
+        &self.control_points
+    }
+    pub fn knots(&self) -> &[T::Scalar] {
+        &self.knots
+    }
+    /// the first and last parameter the curve is defined at.
+    pub fn domain(&self) -> (T::Scalar, T::Scalar) {
+        (self.knots[self.degree], self.knots[self.control_points.len()])
+    }
+    /// the index of the knot starting the span that u is in, where u is clamped to the domain. the end
+    /// of the domain belongs to the last span that isn't empty.
+    pub fn span(&self, u: T::Scalar) -> usize {
+        let (start, end) = self.domain();
+        let u = u.max(start).min(end);
+        let last = self.control_points.len() - 1;
+        let mut span = self.degree;
+        while span < last && self.knots[span + 1] <= u && self.knots[span + 1] < end {
+            span += 1;
+        }
+        span
+    }
+    /// the point at u using [de Boor's algorithm](https://en.wikipedia.org/wiki/De_Boor%27s_algorithm).
+    pub fn interpolate(&self, u: T::Scalar) -> T {
+        let span = self.span(u);
+        let (start, end) = self.domain();
+        let u = u.max(start).min(end);
+        de_boor(&self.knots, self.degree, span, |i| self.control_points[i], &vec![u; self.degree])
+    }
+    /// the spline whose points are the velocity of this one, a degree lower with the first and last
+    /// knots left out. only valid for splines of degree 1 and up.
+    fn hodograph(&self) -> Self {
+        let control_points = hodograph_values(&self.knots, self.degree, &self.control_points, self.control_points[0] * T::Scalar::zero());
+        Self { degree: self.degree - 1, control_points, knots: self.knots[1..self.knots.len() - 1].to_vec() }
+    }
+    /// the velocity of the curve at u.
+    pub fn derivative(&self, u: T::Scalar) -> T {
+        if self.degree == 0 {
+            return self.control_points[0] * T::Scalar::zero();
+        }
+        self.hodograph().interpolate(u)
+    }
+    /// inserts the knot u without changing the curve using [Boehm's algorithm](https://en.wikipedia.org/wiki/Boehm%27s_algorithm),
+    /// adding a control point. knots outside of the domain are ignored.
+    pub fn insert_knot(&mut self, u: T::Scalar) {
+        let (start, end) = self.domain();
+        if u < start || u > end {
+            return;
+        }
+        let span = self.span(u);
+        let p = self.degree;
+        let mut points = Vec::with_capacity(self.control_points.len() + 1);
+        points.extend_from_slice(&self.control_points[..=span - p]);
+        for i in span - p + 1..=span {
+            let (left, right) = (self.knots[i], self.knots[i + p]);
+            let alpha = if right > left { (u - left) / (right - left) } else { T::Scalar::zero() };
+            points.push(self.control_points[i - 1] * (T::Scalar::one() - alpha) + self.control_points[i] * alpha);
+        }
+        points.extend_from_slice(&self.control_points[span..]);
+        self.control_points = points;
+        self.knots.insert(span + 1, u);
+    }
+    /// the bézier control points of the span starting at the knot at index span, which are the
+    /// blossom of the span at its two ends.
+    fn bezier_points(&self, span: usize) -> Vec<T> {
+        let (a, b) = (self.knots[span], self.knots[span + 1]);
+        (0..=self.degree).map(|j| {
+            let parameters: Vec<T::Scalar> = (0..self.degree).map(|k| if k < self.degree - j { a } else { b }).collect();
+            de_boor(&self.knots, self.degree, span, |i| self.control_points[i], &parameters)
+        }).collect()
+    }
+    /// # to_cubics
+    ///
+    /// every span of the domain that isn't empty as the cubic that traces it exactly, with splines of a
+    /// lower degree elevated to cubics. [`None`] when the degree is higher than 3 or lower than 1.
+    pub fn to_cubics(&self) -> Option<Vec<CubicSegment<T>>> {
+        if self.degree == 0 || self.degree > 3 {
+            return None;
+        }
+        let third: T::Scalar = (1.0 / 3.0).as_();
+        let cubics = (self.degree..self.control_points.len())
+            .filter(|&span| self.knots[span] < self.knots[span + 1])
+            .map(|span| {
+                let points = self.bezier_points(span);
+                match self.degree {
+                    1 => {
+                        let line = LinearSegment::new(points[0], points[1]);
+                        CubicSegment::new(points[0], line.interpolate(third), line.interpolate(third + third), points[1])
+                    }
+                    2 => QuadraticSegment::new(points[0], points[1], points[2]).elevate(),
+                    _ => CubicSegment::new(points[0], points[1], points[2], points[3]),
+                }
+            }).collect();
+        Some(cubics)
+    }
+}
+
+/// # Nurbs
+///
+/// a non-uniform rational b-spline, which gives every control point of a [`BSpline`] a weight pulling
+/// the curve towards it. unlike polynomial splines they can trace conic sections like circles exactly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nurbs<T: Vector> {
+    spline: BSpline<T>,
+    weights: Vec<T::Scalar>,
+}
+
+impl<T: Vector> Nurbs<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    /// [`None`] when the spline wouldn't be valid, see [`BSpline::new`], when there isn't a weight for
+    /// every control point or when a weight isn't positive.
+    pub fn new(degree: usize, control_points: Vec<T>, weights: Vec<T::Scalar>, knots: Vec<T::Scalar>) -> Option<Self> {
+        if weights.len() != control_points.len() || weights.iter().any(|&w| !w.is_finite() || w <= T::Scalar::zero()) {
+            return None;
+        }
+        Some(Self { spline: BSpline::new(degree, control_points, knots)?, weights })
+    }
+    pub fn degree(&self) -> usize {
+        self.spline.degree
+    }
+    pub fn control_points(&self) -> &[T] {
+        &self.spline.control_points
+    }
+    pub fn weights(&self) -> &[T::Scalar] {
+        &self.weights
+    }
+    pub fn knots(&self) -> &[T::Scalar] {
+        &self.spline.knots
+    }
+    pub fn domain(&self) -> (T::Scalar, T::Scalar) {
+        self.spline.domain()
+    }
+    /// the weighted sum of the control points and the sum of the weights at u, the point being their quotient.
+    fn homogeneous(&self, u: T::Scalar) -> (T, T::Scalar) {
+        let spline = &self.spline;
+        let span = spline.span(u);
+        let (start, end) = spline.domain();
+        let parameters = vec![u.max(start).min(end); spline.degree];
+        let point = de_boor(&spline.knots, spline.degree, span, |i| spline.control_points[i] * self.weights[i], &parameters);
+        let weight = de_boor(&spline.knots, spline.degree, span, |i| self.weights[i], &parameters);
+        (point, weight)
+    }
+    /// the point at u, evaluating the weighted control points and the weights with de Boor's algorithm
+    /// and dividing one by the other.
+    pub fn interpolate(&self, u: T::Scalar) -> T {
+        let (point, weight) = self.homogeneous(u);
+        point / weight
+    }
+    /// the velocity at u from the quotient rule, (A' - w'C) / w where A is the curve of the weighted
+    /// control points, w the curve of the weights and C the point at u.
+    pub fn derivative(&self, u: T::Scalar) -> T {
+        let (point, weight) = self.homogeneous(u);
+        let spline = &self.spline;
+        if spline.degree == 0 {
+            return point * T::Scalar::zero();
+        }
+        let weighted: Vec<T> = spline.control_points.iter().zip(&self.weights).map(|(&p, &w)| p * w).collect();
+        let hodograph = BSpline {
+            degree: spline.degree - 1,
+            control_points: hodograph_values(&spline.knots, spline.degree, &weighted, point * T::Scalar::zero()),
+            knots: spline.knots[1..spline.knots.len() - 1].to_vec(),
+        };
+        let weight_velocities = hodograph_values(&spline.knots, spline.degree, &self.weights, T::Scalar::zero());
+        let (start, end) = spline.domain();
+        let u = u.max(start).min(end);
+        let weight_velocity = de_boor(&hodograph.knots, hodograph.degree, hodograph.span(u), |i| weight_velocities[i], &vec![u; hodograph.degree]);
+        (hodograph.interpolate(u) - point / weight * weight_velocity) / weight
+    }
+    /// the spline without weights, [`None`] when the weights aren't all the same as only then is it
+    /// the same curve.
+    pub fn to_bspline(&self) -> Option<BSpline<T>> {
+        self.weights.iter().all(|&w| w == self.weights[0]).then(|| self.spline.clone())
+    }
+}
+impl<T: Vector> From<BSpline<T>> for Nurbs<T>
+    where T::Scalar: FloatingPoint, f64: AsPrimitive<T::Scalar> {
+    fn from(spline: BSpline<T>) -> Self {
+        let weights = vec![T::Scalar::one(); spline.control_points.len()];
+        Self { spline, weights }
+    }
+}
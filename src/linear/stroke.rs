@@ -0,0 +1,449 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, EuclideanGeometry};
+use crate::linear::{Vector, Segment, FlatSegment, LinearSegment, QuadraticSegment, CubicSegment, Edge, Contour, Shape, Number, FloatingPoint};
+
+/// how many times a curve can be split in half while offsetting it.
+const OFFSET_MAX_DEPTH: u32 = 12;
+/// the parameters the error of an offset curve is measured at.
+const OFFSET_SAMPLES: [f64; 5] = [0.1, 0.3, 0.5, 0.7, 0.9];
+
+/// # LineJoin
+///
+/// how the outside of a stroke is filled where two edges meet at an angle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineJoin<T: Number> {
+    /// extends the outlines until they meet in a point, unless the point is further from the corner than
+    /// the limit times the width of the stroke in which case the corner is beveled.
+    Miter(T),
+    /// a circular arc around the corner.
+    Round,
+    /// cuts the corner off with a line.
+    Bevel,
+}
+/// # LineCap
+///
+/// how the ends of an open stroke are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// stops exactly at the end.
+    #[default]
+    Butt,
+    /// a half circle around the end.
+    Round,
+    /// a half square that goes past the end by half the width.
+    Square,
+}
+
+/// # StrokeStyle
+///
+/// how [`Contour::stroke`] and [`Shape::stroke`] outline a path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrokeStyle<T: Number> {
+    /// the full width of the stroke, half of it is on each side of the path.
+    pub width: T,
+    pub join: LineJoin<T>,
+    pub cap: LineCap,
+    /// the lengths of the dashes and the gaps between them, starting with a dash. a pattern with an odd
+    /// amount of lengths is repeated twice like in SVG. an empty pattern, one with negative lengths or one
+    /// adding up to nothing draws a solid stroke.
+    pub dashes: Vec<T>,
+    /// how far into the dash pattern the start of the path is.
+    pub dash_offset: T,
+    /// the furthest offset curves are allowed to be from their exact offset.
+    pub tolerance: T,
+}
+
+impl<T: FloatingPoint> StrokeStyle<T>
+    where f64: AsPrimitive<T> {
+    /// a solid stroke with butt caps and miter joins with a limit of 4 like in SVG, offset to within
+    /// a thousandth of the width.
+    pub fn new(width: T) -> Self {
+        Self { width, join: LineJoin::Miter(4.0.as_()), cap: LineCap::Butt, dashes: Vec::new(), dash_offset: T::zero(), tolerance: width * 0.001.as_() }
+    }
+}
+
+fn left_normal<T: FloatingPoint>(direction: Vector2<T>) -> Vector2<T> {
+    direction.get_orthonomal(true, false)
+}
+
+impl<T: FloatingPoint> LinearSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// # offset
+    ///
+    /// the segment moved distance to its left, or to its right when distance is negative.
+    pub fn offset(&self, distance: T) -> Self {
+        let normal = left_normal(*self.end() - *self.start()) * distance;
+        Self::new(*self.start() + normal, *self.end() + normal)
+    }
+}
+impl<T: FloatingPoint> QuadraticSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// # offset
+    ///
+    /// approximates the curve moved distance to its left, or to its right when distance is negative,
+    /// with cubics that are within tolerance of it. see [`CubicSegment::offset`].
+    pub fn offset(&self, distance: T, tolerance: T) -> Vec<CubicSegment<Vector2<T>>> {
+        self.elevate().offset(distance, tolerance)
+    }
+}
+impl<T: FloatingPoint> CubicSegment<Vector2<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// # offset
+    ///
+    /// approximates the curve moved distance to its left, or to its right when distance is negative,
+    /// with cubics that are within tolerance of it. every cubic starts and ends exactly on the offset
+    /// with the same tangents as the curve, with the lengths of its control arms picked so that its
+    /// middle is on the offset too. parts whose distance to the curve is off by more than tolerance are
+    /// split in half, at most 12 times. where the curve bends tighter than the distance the offset
+    /// loops back on itself, and across a cusp the offset is joined with a straight cubic.
+    pub fn offset(&self, distance: T, tolerance: T) -> Vec<CubicSegment<Vector2<T>>> {
+        let mut pieces = Vec::new();
+        offset_cubic(self, distance, tolerance, 0, &mut pieces);
+        let mut result: Vec<CubicSegment<Vector2<T>>> = Vec::with_capacity(pieces.len());
+        for mut piece in pieces {
+            if let Some(previous) = result.last() {
+                let end = *previous.end();
+                if (*piece.start() - end).length() <= tolerance {
+                    piece.set_start(&end);
+                } else {
+                    // the curve has a cusp here, which the offset jumps across
+                    let third: T = (1.0 / 3.0).as_();
+                    let gap = *piece.start() - end;
+                    result.push(CubicSegment::new(end, end + gap * third, *piece.start() - gap * third, *piece.start()));
+                }
+            }
+            result.push(piece);
+        }
+        result
+    }
+}
+
+/// the cubic through the offset ends of the segment with the same tangents, whose middle matches
+/// the middle of the offset. [`None`] when that needs control arms pointing backwards.
+fn fit_offset<T: FloatingPoint>(segment: &CubicSegment<Vector2<T>>, distance: T) -> Option<CubicSegment<Vector2<T>>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| *segment.get_point(i));
+    let t0 = segment.direction(T::zero()).normalize();
+    let t3 = segment.direction(T::one()).normalize();
+    let q0 = p0 + left_normal(t0) * distance;
+    let q3 = p3 + left_normal(t3) * distance;
+    let half: T = 0.5.as_();
+    let middle = segment.interpolate(half) + left_normal(segment.direction(half)) * distance;
+    // the middle of a cubic is (q0 + 3(q0 + a t0) + 3(q3 - b t3) + q3) / 8, solved for a and b
+    let (three, four, eight): (T, T, T) = (3.0.as_(), 4.0.as_(), 8.0.as_());
+    let target = (middle * eight - q0 * four - q3 * four) / three;
+    let determinant = t0.cross(-t3);
+    let (a, b) = if determinant.abs() > 1e-6.as_() {
+        (target.cross(-t3) / determinant, t0.cross(target) / determinant)
+    } else if (t0 - t3).length() > T::one() {
+        // the tangents point in opposite directions, give both arms the same length
+        let arm = target.dot(&(t0 - t3)) / (t0 - t3).dot(&(t0 - t3));
+        (arm, arm)
+    } else {
+        // the tangents are the same, scale the arms of the curve along with its chord
+        let chord = (p3 - p0).length();
+        let scale = if chord > T::zero() { (q3 - q0).length() / chord } else { T::one() };
+        ((p1 - p0).length() * scale, (p3 - p2).length() * scale)
+    };
+    if !(a >= T::zero() && b >= T::zero()) {
+        return None;
+    }
+    Some(CubicSegment::new(q0, q0 + t0 * a, q3 - t3 * b, q3))
+}
+/// how far the distance from the offset to the segment is from the distance it should be.
+fn offset_error<T: FloatingPoint>(segment: &CubicSegment<Vector2<T>>, offset: &CubicSegment<Vector2<T>>, distance: T) -> T
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    OFFSET_SAMPLES.iter().fold(T::zero(), |error, &t| {
+        let p = offset.interpolate(t.as_());
+        error.max((segment.signed_distance_param(p).0.abs() - distance.abs()).abs())
+    })
+}
+fn offset_cubic<T: FloatingPoint>(segment: &CubicSegment<Vector2<T>>, distance: T, tolerance: T, depth: u32, result: &mut Vec<CubicSegment<Vector2<T>>>)
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let fit = fit_offset(segment, distance);
+    let good = fit.is_some_and(|fit| offset_error(segment, &fit, distance) <= tolerance);
+    if !good && depth < OFFSET_MAX_DEPTH {
+        let (first, second) = segment.split_at(0.5.as_());
+        offset_cubic(&first, distance, tolerance, depth + 1, result);
+        offset_cubic(&second, distance, tolerance, depth + 1, result);
+        return;
+    }
+    result.push(fit.unwrap_or_else(|| {
+        // too small to split any further, keep the control arms of the curve
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| *segment.get_point(i));
+        let q0 = p0 + left_normal(segment.direction(T::zero())) * distance;
+        let q3 = p3 + left_normal(segment.direction(T::one())) * distance;
+        CubicSegment::new(q0, q0 + (p1 - p0), q3 + (p2 - p3), q3)
+    }));
+}
+
+impl<T: FloatingPoint> Edge<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// # offset
+    ///
+    /// the edge moved distance to its left, or to its right when distance is negative. lines stay
+    /// lines while curves are approximated with cubics that are within tolerance of the offset.
+    pub fn offset(&self, distance: T, tolerance: T) -> Vec<Edge<T>> {
+        match self {
+            Edge::Linear(segment) => vec![Edge::Linear(segment.offset(distance))],
+            Edge::Quadratic(segment) => segment.offset(distance, tolerance).into_iter().map(Edge::Cubic).collect(),
+            Edge::Cubic(segment) => segment.offset(distance, tolerance).into_iter().map(Edge::Cubic).collect(),
+        }
+    }
+    fn arc_length(&self, tolerance: T) -> T {
+        match self {
+            Edge::Linear(segment) => segment.arc_length(tolerance),
+            Edge::Quadratic(segment) => segment.arc_length(tolerance),
+            Edge::Cubic(segment) => segment.arc_length(tolerance),
+        }
+    }
+    fn t_at_length(&self, length: T) -> T {
+        match self {
+            Edge::Linear(segment) => segment.t_at_length(length),
+            Edge::Quadratic(segment) => segment.t_at_length(length),
+            Edge::Cubic(segment) => segment.t_at_length(length),
+        }
+    }
+    fn subsegment(&self, t0: T, t1: T) -> Self {
+        match self {
+            Edge::Linear(segment) => Edge::Linear(segment.subsegment(t0, t1)),
+            Edge::Quadratic(segment) => Edge::Quadratic(segment.subsegment(t0, t1)),
+            Edge::Cubic(segment) => Edge::Cubic(segment.subsegment(t0, t1)),
+        }
+    }
+    /// whether all the points of the edge are the same, which leaves it without a direction.
+    fn is_degenerate(&self) -> bool {
+        let points = self.points();
+        points.iter().all(|p| *p == points[0])
+    }
+}
+
+/// adds a circular arc around center from `from` to `to` turning by sweep radians, counter clockwise
+/// when positive, made of cubics covering at most a quarter turn each.
+fn push_arc<T: FloatingPoint>(edges: &mut Vec<Edge<T>>, center: Vector2<T>, from: Vector2<T>, to: Vector2<T>, sweep: T)
+    where f64: AsPrimitive<T> {
+    let quarter: T = std::f64::consts::FRAC_PI_2.as_();
+    let count = (sweep.abs() / quarter).ceil().max(T::one()).to_usize().unwrap_or(1);
+    let step = sweep / (count as f64).as_();
+    // the control points of a cubic approximating a circular arc are this far along the tangents
+    let k = (step / 4.0.as_()).tan() * (4.0 / 3.0).as_();
+    let (sin, cos) = step.sin_cos();
+    let rotate = |v: Vector2<T>| Vector2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos);
+    let perpendicular = |v: Vector2<T>| Vector2::new(-v.y, v.x);
+    let mut radius = from - center;
+    let mut start = from;
+    for i in 0..count {
+        let next = rotate(radius);
+        let end = if i + 1 == count { to } else { center + next };
+        edges.push(CubicSegment::new(start, start + perpendicular(radius) * k, end - perpendicular(next) * k, end).into());
+        radius = next;
+        start = end;
+    }
+}
+fn push_line<T: FloatingPoint>(edges: &mut Vec<Edge<T>>, from: Vector2<T>, to: Vector2<T>) {
+    if from != to {
+        edges.push(LinearSegment::new(from, to).into());
+    }
+}
+
+/// outlines one side of a stroke, which is the left side of the edges. every edge is offset and
+/// consecutive offsets are joined, around the corner on the outside and through the corner on the
+/// inside so that the overlap there still fills with the non zero rule.
+fn stroke_side<T: FloatingPoint>(edges: &[Edge<T>], closed: bool, style: &StrokeStyle<T>) -> Vec<Edge<T>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let half = style.width * 0.5.as_();
+    let offsets: Vec<Vec<Edge<T>>> = edges.iter().map(|edge| edge.offset(half, style.tolerance)).collect();
+    let mut result = Vec::new();
+    for i in 0..edges.len() {
+        result.extend_from_slice(&offsets[i]);
+        if i + 1 < edges.len() || closed {
+            let j = (i + 1) % edges.len();
+            let from = offsets[i][offsets[i].len() - 1].end();
+            let to = offsets[j][0].start();
+            push_join(&mut result, &edges[i], &edges[j], from, to, style);
+        }
+    }
+    result
+}
+fn push_join<T: FloatingPoint>(edges: &mut Vec<Edge<T>>, incoming: &Edge<T>, outgoing: &Edge<T>, from: Vector2<T>, to: Vector2<T>, style: &StrokeStyle<T>)
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    if from == to {
+        return;
+    }
+    let corner = outgoing.start();
+    let d0 = incoming.direction(T::one()).normalize();
+    let d1 = outgoing.direction(T::zero()).normalize();
+    if d0.cross(d1) > T::zero() {
+        // turning left puts this side on the inside of the corner
+        push_line(edges, from, corner);
+        push_line(edges, corner, to);
+        return;
+    }
+    let (n0, n1) = (left_normal(d0), left_normal(d1));
+    let cos = n0.dot(&n1);
+    let two: T = 2.0.as_();
+    match style.join {
+        LineJoin::Miter(limit) if T::one() + cos > T::epsilon() && (two / (T::one() + cos)).sqrt() <= limit => {
+            let miter = corner + (n0 + n1) * (style.width * 0.5.as_() / (T::one() + cos));
+            push_line(edges, from, miter);
+            push_line(edges, miter, to);
+        }
+        LineJoin::Round => push_arc(edges, corner, from, to, n0.cross(n1).atan2(cos)),
+        _ => push_line(edges, from, to),
+    }
+}
+/// closes the stroke around the end of the edge going from the left side at `from` to the right side at `to`.
+fn push_cap<T: FloatingPoint>(edges: &mut Vec<Edge<T>>, last: &Edge<T>, from: Vector2<T>, to: Vector2<T>, style: &StrokeStyle<T>)
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    match style.cap {
+        LineCap::Butt => push_line(edges, from, to),
+        LineCap::Round => push_arc(edges, last.end(), from, to, (-std::f64::consts::PI).as_()),
+        LineCap::Square => {
+            let extend = last.direction(T::one()).normalize() * (style.width * 0.5.as_());
+            push_line(edges, from, from + extend);
+            push_line(edges, from + extend, to + extend);
+            push_line(edges, to + extend, to);
+        }
+    }
+}
+/// a dot for a path without any length, which only round and square caps draw.
+fn push_dot<T: FloatingPoint>(contours: &mut Vec<Contour<T>>, center: Vector2<T>, style: &StrokeStyle<T>)
+    where f64: AsPrimitive<T> {
+    let half = style.width * 0.5.as_();
+    let mut edges = Vec::new();
+    match style.cap {
+        LineCap::Butt => return,
+        LineCap::Round => {
+            let start = center + Vector2::new(half, T::zero());
+            push_arc(&mut edges, center, start, start, std::f64::consts::TAU.as_());
+        }
+        LineCap::Square => {
+            let corners = [(-half, -half), (half, -half), (half, half), (-half, half)].map(|(x, y)| center + Vector2::new(x, y));
+            for i in 0..4 {
+                push_line(&mut edges, corners[i], corners[(i + 1) % 4]);
+            }
+        }
+    }
+    contours.push(Contour::from_edges(edges));
+}
+fn stroke_path<T: FloatingPoint>(contours: &mut Vec<Contour<T>>, edges: &[Edge<T>], closed: bool, style: &StrokeStyle<T>)
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let reversed: Vec<Edge<T>> = edges.iter().rev().map(Edge::reverse).collect();
+    let mut left = stroke_side(edges, closed, style);
+    let right = stroke_side(&reversed, closed, style);
+    if closed {
+        contours.push(Contour::from_edges(left));
+        contours.push(Contour::from_edges(right));
+        return;
+    }
+    let (left_end, right_start) = (left[left.len() - 1].end(), right[0].start());
+    let (right_end, left_start) = (right[right.len() - 1].end(), left[0].start());
+    push_cap(&mut left, &edges[edges.len() - 1], left_end, right_start, style);
+    left.extend(right);
+    push_cap(&mut left, &reversed[reversed.len() - 1], right_end, left_start, style);
+    contours.push(Contour::from_edges(left));
+}
+
+/// cuts the edges into the dashes of the pattern, along with whether each dash is a closed loop. [`None`]
+/// when the pattern doesn't dash the path.
+fn dash<T: FloatingPoint>(edges: &[Edge<T>], closed: bool, style: &StrokeStyle<T>) -> Option<Vec<(Vec<Edge<T>>, bool)>>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut pattern = style.dashes.clone();
+    if pattern.len() % 2 == 1 {
+        pattern.extend_from_slice(&style.dashes);
+    }
+    let total = pattern.iter().fold(T::zero(), |total, &length| total + length);
+    if pattern.is_empty() || pattern.iter().any(|&length| length < T::zero()) || !total.is_finite() || total <= T::zero() {
+        return None;
+    }
+    // find where in the pattern the path starts
+    let mut offset = style.dash_offset % total;
+    if offset < T::zero() {
+        offset += total;
+    }
+    let mut index = 0;
+    while offset >= pattern[index] {
+        offset -= pattern[index];
+        index = (index + 1) % pattern.len();
+    }
+    let mut remaining = pattern[index] - offset;
+    let starts_on = index % 2 == 0;
+    let mut dashes = Vec::new();
+    let mut current = Vec::new();
+    for edge in edges {
+        let length = edge.arc_length(style.tolerance);
+        let mut position = T::zero();
+        while position < length {
+            let step = remaining.min(length - position);
+            if index % 2 == 0 && step > T::zero() {
+                let (t0, t1) = (edge.t_at_length(position), edge.t_at_length(position + step));
+                current.push(edge.subsegment(t0, t1));
+            }
+            position += step;
+            remaining -= step;
+            if remaining <= T::zero() {
+                if !current.is_empty() {
+                    dashes.push((std::mem::take(&mut current), false));
+                }
+                index = (index + 1) % pattern.len();
+                remaining = pattern[index];
+            }
+        }
+    }
+    if !current.is_empty() {
+        if closed && starts_on && dashes.is_empty() {
+            // the dash never ended, so the path is drawn as a whole
+            dashes.push((current, true));
+        } else if closed && starts_on {
+            // the dash over the end of a closed path carries on into the first dash
+            current.append(&mut dashes[0].0);
+            dashes[0].0 = current;
+        } else {
+            dashes.push((current, false));
+        }
+    }
+    Some(dashes)
+}
+
+impl<T: FloatingPoint> Contour<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// # stroke
+    ///
+    /// the outline of the contour drawn with a pen of the given style, made of contours to be filled
+    /// with [`FillRule::NonZero`](crate::FillRule::NonZero). closed contours are outlined on both sides
+    /// while open ones, and every dash, are capped at their ends. edges without any length are skipped.
+    pub fn stroke(&self, style: &StrokeStyle<T>) -> Shape<T> {
+        let mut contours = Vec::new();
+        let edges: Vec<Edge<T>> = self.edges().iter().filter(|edge| !edge.is_degenerate()).copied().collect();
+        if edges.is_empty() {
+            if let Some(edge) = self.edges().first() {
+                push_dot(&mut contours, edge.start(), style);
+            }
+            return Shape::from_contours(contours);
+        }
+        let closed = self.is_closed();
+        match dash(&edges, closed, style) {
+            Some(dashes) => {
+                for (dash, closed) in dashes {
+                    stroke_path(&mut contours, &dash, closed, style);
+                }
+            }
+            None => stroke_path(&mut contours, &edges, closed, style),
+        }
+        Shape::from_contours(contours)
+    }
+}
+impl<T: FloatingPoint> Shape<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    /// # stroke
+    ///
+    /// the outlines of every contour of the shape, see [`Contour::stroke`].
+    pub fn stroke(&self, style: &StrokeStyle<T>) -> Shape<T> {
+        let mut contours = Vec::new();
+        for contour in self.contours() {
+            contours.append(contour.stroke(style).contours_mut());
+        }
+        Shape::from_contours(contours)
+    }
+}
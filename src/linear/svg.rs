@@ -0,0 +1,400 @@
+use std::fmt::{self, Display};
+
+use num_traits::{AsPrimitive, Zero};
+
+use crate::Vector2;
+use crate::linear::{Segment, LinearSegment, QuadraticSegment, CubicSegment, Edge, Contour, Shape, FloatingPoint};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SvgPathErrorKind {
+    /// a character that isn't a command where a command was expected.
+    InvalidCommand(char),
+    /// the command needs more numbers than were given.
+    ExpectedNumber,
+    /// the large arc and sweep flags of an arc have to be 0 or 1.
+    ExpectedFlag,
+    /// the path has to start with a move to.
+    MissingMoveTo,
+}
+/// # SvgPathError
+///
+/// why path data couldn't be parsed, along with the byte offset into the data where it went wrong.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SvgPathError {
+    pub offset: usize,
+    pub kind: SvgPathErrorKind,
+}
+
+impl Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            SvgPathErrorKind::InvalidCommand(c) => write!(f, "invalid path command {:?}", c)?,
+            SvgPathErrorKind::ExpectedNumber => write!(f, "expected a number")?,
+            SvgPathErrorKind::ExpectedFlag => write!(f, "expected an arc flag of 0 or 1")?,
+            SvgPathErrorKind::MissingMoveTo => write!(f, "path data has to start with a move to")?,
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+impl std::error::Error for SvgPathError {}
+
+/// reads the numbers and flags of path data, skipping the whitespace and commas between them.
+struct Lexer<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+impl<'a> Lexer<'a> {
+    fn skip_separators(&mut self) {
+        while self.offset < self.data.len() && matches!(self.data[self.offset], b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' | b',') {
+            self.offset += 1;
+        }
+    }
+    fn error(&self, kind: SvgPathErrorKind) -> SvgPathError {
+        SvgPathError { offset: self.offset, kind }
+    }
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.data.get(self.offset).copied()
+    }
+    /// whether a number comes next, which repeats the previous command.
+    fn at_number(&mut self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+    }
+    fn number(&mut self) -> Result<f64, SvgPathError> {
+        self.skip_separators();
+        let start = self.offset;
+        let digits = |lexer: &mut Self| {
+            let start = lexer.offset;
+            while lexer.offset < lexer.data.len() && lexer.data[lexer.offset].is_ascii_digit() {
+                lexer.offset += 1;
+            }
+            lexer.offset > start
+        };
+        if matches!(self.data.get(self.offset), Some(b'-' | b'+')) {
+            self.offset += 1;
+        }
+        let mut has_digits = digits(self);
+        if self.data.get(self.offset) == Some(&b'.') {
+            self.offset += 1;
+            has_digits |= digits(self);
+        }
+        if !has_digits {
+            self.offset = start;
+            return Err(self.error(SvgPathErrorKind::ExpectedNumber));
+        }
+        // only treat an e as an exponent when digits follow, so that it can't swallow anything else
+        if matches!(self.data.get(self.offset), Some(b'e' | b'E')) {
+            let mantissa_end = self.offset;
+            self.offset += 1;
+            if matches!(self.data.get(self.offset), Some(b'-' | b'+')) {
+                self.offset += 1;
+            }
+            if !digits(self) {
+                self.offset = mantissa_end;
+            }
+        }
+        // the bytes are all ascii so they are always valid utf8
+        let text = std::str::from_utf8(&self.data[start..self.offset]).unwrap();
+        text.parse().map_err(|_| SvgPathError { offset: start, kind: SvgPathErrorKind::ExpectedNumber })
+    }
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        match self.peek() {
+            Some(b'0') => { self.offset += 1; Ok(false) }
+            Some(b'1') => { self.offset += 1; Ok(true) }
+            _ => Err(self.error(SvgPathErrorKind::ExpectedFlag)),
+        }
+    }
+}
+
+/// builds up the contours of the path as it's parsed.
+struct PathBuilder<T: FloatingPoint> {
+    contours: Vec<Contour<T>>,
+    current: Contour<T>,
+    start: Vector2<T>,
+    point: Vector2<T>,
+}
+impl<T: FloatingPoint> PathBuilder<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    fn finish_contour(&mut self) {
+        if !self.current.edges().is_empty() {
+            self.contours.push(std::mem::take(&mut self.current));
+        }
+    }
+    fn move_to(&mut self, point: Vector2<T>) {
+        self.finish_contour();
+        self.start = point;
+        self.point = point;
+    }
+    fn push(&mut self, edge: impl Into<Edge<T>>) {
+        let edge = edge.into();
+        self.point = edge.end();
+        self.current.push(edge);
+    }
+    fn close(&mut self) {
+        if self.point != self.start {
+            self.push(LinearSegment::new(self.point, self.start));
+        }
+        self.finish_contour();
+        self.point = self.start;
+    }
+    /// adds an elliptical arc, converting it into cubics of at most a quarter turn each as described in
+    /// [the implementation notes of the SVG specification](https://www.w3.org/TR/SVG11/implnote.html#ArcImplementationNotes).
+    fn arc(&mut self, radii: Vector2<T>, rotation: T, large_arc: bool, sweep: bool, end: Vector2<T>) {
+        let start = self.point;
+        if start == end {
+            return;
+        }
+        let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+        if rx == T::zero() || ry == T::zero() {
+            self.push(LinearSegment::new(start, end));
+            return;
+        }
+        let two: T = 2.0.as_();
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        // the start point in the coordinates of the ellipse, relative to the middle of the chord
+        let half = (start - end) / two;
+        let x1 = cos * half.x + sin * half.y;
+        let y1 = -sin * half.x + cos * half.y;
+        // radii too small to reach the end are scaled up until they just do
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > T::one() {
+            let scale = lambda.sqrt();
+            rx *= scale;
+            ry *= scale;
+        }
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut factor = (numerator / denominator).max(T::zero()).sqrt();
+        if large_arc == sweep {
+            factor = -factor;
+        }
+        let cx1 = factor * rx * y1 / ry;
+        let cy1 = -factor * ry * x1 / rx;
+        let middle = (start + end) / two;
+        let center = Vector2::new(cos * cx1 - sin * cy1 + middle.x, sin * cx1 + cos * cy1 + middle.y);
+        let angle = |ux: T, uy: T, vx: T, vy: T| {
+            let sign = if ux * vy - uy * vx < T::zero() { -T::one() } else { T::one() };
+            let dot = (ux * vx + uy * vy) / ((ux * ux + uy * uy).sqrt() * (vx * vx + vy * vy).sqrt());
+            sign * dot.max(-T::one()).min(T::one()).acos()
+        };
+        let theta = angle(T::one(), T::zero(), (x1 - cx1) / rx, (y1 - cy1) / ry);
+        let mut delta = angle((x1 - cx1) / rx, (y1 - cy1) / ry, (-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        let tau: T = std::f64::consts::TAU.as_();
+        if !sweep && delta > T::zero() {
+            delta -= tau;
+        } else if sweep && delta < T::zero() {
+            delta += tau;
+        }
+        let quarter: T = std::f64::consts::FRAC_PI_2.as_();
+        let count = (delta.abs() / quarter).ceil().max(T::one());
+        let step = delta / count;
+        // the control points of a cubic approximating a circular arc are this far along the tangents
+        let k = (step / 4.0.as_()).tan() * (4.0 / 3.0).as_();
+        let ellipse = |t: T| {
+            let (sin_t, cos_t) = t.sin_cos();
+            let point = Vector2::new(cos * rx * cos_t - sin * ry * sin_t, sin * rx * cos_t + cos * ry * sin_t) + center;
+            let tangent = Vector2::new(-cos * rx * sin_t - sin * ry * cos_t, -sin * rx * sin_t + cos * ry * cos_t);
+            (point, tangent)
+        };
+        let count = count.to_usize().unwrap_or(1);
+        let mut t = theta;
+        for i in 0..count {
+            let (p0, d0) = ellipse(t);
+            t += step;
+            let (p3, d3) = ellipse(t);
+            let p0 = if i == 0 { start } else { p0 };
+            let p3 = if i + 1 == count { end } else { p3 };
+            self.push(CubicSegment::new(p0, p0 + d0 * k, p3 - d3 * k, p3));
+        }
+    }
+}
+
+/// parses the path data of an SVG path, the d attribute, into a shape with a contour for every subpath.
+/// every command of the [path grammar](https://www.w3.org/TR/SVG11/paths.html#PathDataBNF) is supported
+/// in both absolute and relative form, with arcs converted into cubics. subpaths that aren't closed with
+/// Z stay open and subpaths without any edges are left out.
+pub fn parse_svg_path<T: FloatingPoint>(data: &str) -> Result<Shape<T>, SvgPathError>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut lexer = Lexer { data: data.as_bytes(), offset: 0 };
+    let mut path = PathBuilder { contours: Vec::new(), current: Contour::new(), start: Vector2::zero(), point: Vector2::zero() };
+    // the second control point of the previous cubic or the control point of the previous quadratic,
+    // which S and T reflect
+    let mut previous_cubic: Option<Vector2<T>> = None;
+    let mut previous_quadratic: Option<Vector2<T>> = None;
+    let mut command: Option<u8> = None;
+    while let Some(next) = lexer.peek() {
+        let offset = lexer.offset;
+        if command.is_none() && !matches!(next, b'M' | b'm') {
+            return Err(SvgPathError { offset, kind: SvgPathErrorKind::MissingMoveTo });
+        }
+        let current = if next.is_ascii_alphabetic() {
+            lexer.offset += 1;
+            next
+        } else {
+            match command {
+                // numbers after a command repeat it, with a move to turning into a line to
+                Some(b'M') if lexer.at_number() => b'L',
+                Some(b'm') if lexer.at_number() => b'l',
+                Some(command) if command != b'Z' && command != b'z' && lexer.at_number() => command,
+                _ => {
+                    let c = data[offset..].chars().next().unwrap();
+                    return Err(SvgPathError { offset, kind: SvgPathErrorKind::InvalidCommand(c) });
+                }
+            }
+        };
+        let relative = current.is_ascii_lowercase();
+        let origin = if relative { path.point } else { Vector2::zero() };
+        let number = |lexer: &mut Lexer| -> Result<T, SvgPathError> { Ok(lexer.number()?.as_()) };
+        let point = |lexer: &mut Lexer| -> Result<Vector2<T>, SvgPathError> {
+            let x = number(lexer)?;
+            let y = number(lexer)?;
+            Ok(Vector2::new(x, y) + origin)
+        };
+        let (mut cubic, mut quadratic) = (None, None);
+        match current.to_ascii_uppercase() {
+            b'M' => {
+                let p = point(&mut lexer)?;
+                path.move_to(p);
+            }
+            b'L' => {
+                let p = point(&mut lexer)?;
+                path.push(LinearSegment::new(path.point, p));
+            }
+            b'H' => {
+                let x: T = lexer.number()?.as_();
+                let p = Vector2::new(x + origin.x, path.point.y);
+                path.push(LinearSegment::new(path.point, p));
+            }
+            b'V' => {
+                let y: T = lexer.number()?.as_();
+                let p = Vector2::new(path.point.x, y + origin.y);
+                path.push(LinearSegment::new(path.point, p));
+            }
+            b'C' => {
+                let (c1, c2, p) = (point(&mut lexer)?, point(&mut lexer)?, point(&mut lexer)?);
+                path.push(CubicSegment::new(path.point, c1, c2, p));
+                cubic = Some(c2);
+            }
+            b'S' => {
+                let c1 = previous_cubic.map(|c| path.point * 2.0.as_() - c).unwrap_or(path.point);
+                let (c2, p) = (point(&mut lexer)?, point(&mut lexer)?);
+                path.push(CubicSegment::new(path.point, c1, c2, p));
+                cubic = Some(c2);
+            }
+            b'Q' => {
+                let (c, p) = (point(&mut lexer)?, point(&mut lexer)?);
+                path.push(QuadraticSegment::new(path.point, c, p));
+                quadratic = Some(c);
+            }
+            b'T' => {
+                let c = previous_quadratic.map(|c| path.point * 2.0.as_() - c).unwrap_or(path.point);
+                let p = point(&mut lexer)?;
+                path.push(QuadraticSegment::new(path.point, c, p));
+                quadratic = Some(c);
+            }
+            b'A' => {
+                let rx: T = lexer.number()?.as_();
+                let ry: T = lexer.number()?.as_();
+                let rotation: T = lexer.number()?.as_();
+                let large_arc = lexer.flag()?;
+                let sweep = lexer.flag()?;
+                let p = point(&mut lexer)?;
+                path.arc(Vector2::new(rx, ry), rotation, large_arc, sweep, p);
+            }
+            b'Z' => path.close(),
+            _ => return Err(SvgPathError { offset, kind: SvgPathErrorKind::InvalidCommand(current as char) }),
+        }
+        previous_cubic = cubic;
+        previous_quadratic = quadratic;
+        command = Some(current);
+    }
+    path.finish_contour();
+    Ok(Shape::from_contours(path.contours))
+}
+
+/// writes a number as briefly as possible, without the 0 before the decimal point. the number is
+/// written with its own precision so f32 coordinates don't pick up digits from being widened.
+fn write_number<T: FloatingPoint + Display>(out: &mut String, value: T) {
+    let text = format!("{}", if value == T::zero() { T::zero() } else { value });
+    let text = text.strip_prefix("0.").map(|rest| format!(".{}", rest))
+        .or_else(|| text.strip_prefix("-0.").map(|rest| format!("-.{}", rest)))
+        .unwrap_or(text);
+    // a separator is only needed where the number would otherwise run into the previous one
+    let needs_separator = match (out.chars().last(), text.chars().next()) {
+        (Some(last), Some(first)) if last.is_ascii_digit() || last == '.' => {
+            first.is_ascii_digit() || (first == '.' && !last_number(out).contains('.'))
+        }
+        _ => false,
+    };
+    if needs_separator {
+        out.push(' ');
+    }
+    out.push_str(&text);
+}
+fn last_number(out: &str) -> &str {
+    let start = out.rfind(|c: char| !(c.is_ascii_digit() || c == '.')).map(|i| i + 1).unwrap_or(0);
+    &out[start..]
+}
+
+/// writes the shape as compact SVG path data using absolute commands, leaving out repeated commands and
+/// any separators that aren't needed. closed contours end with Z, which replaces a final line back to the start.
+pub fn to_svg_path<T: FloatingPoint + Display>(shape: &Shape<T>) -> String {
+    let mut out = String::new();
+    for contour in shape.contours() {
+        let edges = contour.edges();
+        let first = match edges.first() {
+            Some(first) => first,
+            None => continue,
+        };
+        let closed = contour.is_closed();
+        let mut command = 'M';
+        out.push('M');
+        let point = |out: &mut String, p: &Vector2<T>| {
+            write_number(out, p.x);
+            write_number(out, p.y);
+        };
+        point(&mut out, &first.start());
+        let mut current = first.start();
+        for (i, edge) in edges.iter().enumerate() {
+            let is_last = i + 1 == edges.len();
+            if closed && is_last && matches!(edge, Edge::Linear(_)) {
+                break;
+            }
+            let mut next = |out: &mut String, letter: char| {
+                // a line after a move to doesn't need its command
+                if letter != command && !(letter == 'L' && command == 'M') {
+                    out.push(letter);
+                }
+                command = letter;
+            };
+            match edge {
+                Edge::Linear(segment) => {
+                    let end = *segment.end();
+                    if end.y == current.y && end.x != current.x {
+                        next(&mut out, 'H');
+                        write_number(&mut out, end.x);
+                    } else if end.x == current.x && end.y != current.y {
+                        next(&mut out, 'V');
+                        write_number(&mut out, end.y);
+                    } else {
+                        next(&mut out, 'L');
+                        point(&mut out, &end);
+                    }
+                }
+                Edge::Quadratic(segment) => {
+                    next(&mut out, 'Q');
+                    segment.iter().skip(1).for_each(|p| point(&mut out, p));
+                }
+                Edge::Cubic(segment) => {
+                    next(&mut out, 'C');
+                    segment.iter().skip(1).for_each(|p| point(&mut out, p));
+                }
+            }
+            current = edge.end();
+        }
+        if closed {
+            out.push('Z');
+        }
+    }
+    out
+}
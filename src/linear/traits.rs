@@ -1,90 +1,100 @@
-use core::fmt;
-use std::ops::{SubAssign, MulAssign, DivAssign, RemAssign, AddAssign};
-
-use num_traits::{Num, NumCast, Float, Signed, Unsigned, Bounded, AsPrimitive};
-
-use crate::equations::{QuadraticFormula, CubicFormula};
-
-use super::{transform, matrix::Matrix4};
-
-
-pub trait Number:
-    Copy
-    + Clone
-    + fmt::Debug
-    + Num
-    + NumCast
-    + PartialOrd
-    + AddAssign
-    + SubAssign
-    + MulAssign
-    + DivAssign
-    + RemAssign
-    + PartialEq
-    + PartialOrd
-    + Bounded
-{
-}
-
-impl<T> Number for T where
-    T: Copy
-        + Clone
-        + fmt::Debug
-        + Num
-        + NumCast
-        + PartialOrd
-        + AddAssign
-        + SubAssign
-        + MulAssign
-        + DivAssign
-        + RemAssign
-        + PartialEq
-        + PartialOrd
-        + Bounded
-{
-}
-pub trait SignedNumber: 
-    Signed 
-    + Number {
-    
-}
-impl<T> SignedNumber for T where
-    T: Signed 
-    + Number
-{
-
-}
-
-pub trait UnsignedNumber: 
-    Unsigned
-    + Number {
-    
-}
-impl<T> UnsignedNumber for T where
-    T: Unsigned 
-    + Number
-{
-
-}
-
-/// Base floating point types
-pub trait FloatingPoint:
-    SignedNumber
-    + Float
-    + AsPrimitive<f32>
-    + AsPrimitive<f64>
-    + QuadraticFormula
-    + CubicFormula
-{
-}
-
-impl<T> FloatingPoint for T where
-    T: SignedNumber
-        + Float
-        + AsPrimitive<T>
-        + AsPrimitive<f32>
-        + AsPrimitive<f64>
-        + QuadraticFormula
-        + CubicFormula
-{
+use core::fmt;
+use std::ops::{SubAssign, MulAssign, DivAssign, RemAssign, AddAssign};
+
+use num_traits::{Num, NumCast, Float, Signed, Unsigned, Bounded, AsPrimitive};
+
+use crate::equations::{QuadraticFormula, CubicFormula, QuarticFormula};
+
+use super::{transform, matrix::Matrix4};
+
+
+pub trait Number:
+    Copy
+    + Clone
+    + fmt::Debug
+    + Num
+    + NumCast
+    + PartialOrd
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + PartialEq
+    + PartialOrd
+    + Bounded
+{
+}
+
+impl<T> Number for T where
+    T: Copy
+        + Clone
+        + fmt::Debug
+        + Num
+        + NumCast
+        + PartialOrd
+        + AddAssign
+        + SubAssign
+        + MulAssign
+        + DivAssign
+        + RemAssign
+        + PartialEq
+        + PartialOrd
+        + Bounded
+{
+}
+/// the smaller of two numbers. unlike [`Float::min`] this works for every number.
+pub(crate) fn min<T: Number>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+/// the larger of two numbers. unlike [`Float::max`] this works for every number.
+pub(crate) fn max<T: Number>(a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+pub trait SignedNumber: 
+    Signed 
+    + Number {
+    
+}
+impl<T> SignedNumber for T where
+    T: Signed 
+    + Number
+{
+
+}
+
+pub trait UnsignedNumber: 
+    Unsigned
+    + Number {
+    
+}
+impl<T> UnsignedNumber for T where
+    T: Unsigned 
+    + Number
+{
+
+}
+
+/// Base floating point types
+pub trait FloatingPoint:
+    SignedNumber
+    + Float
+    + AsPrimitive<f32>
+    + AsPrimitive<f64>
+    + QuadraticFormula
+    + CubicFormula
+    + QuarticFormula
+{
+}
+
+impl<T> FloatingPoint for T where
+    T: SignedNumber
+        + Float
+        + AsPrimitive<T>
+        + AsPrimitive<f32>
+        + AsPrimitive<f64>
+        + QuadraticFormula
+        + CubicFormula
+        + QuarticFormula
+{
 }
\ No newline at end of file
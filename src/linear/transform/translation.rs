@@ -1,3 +1,3 @@
-pub trait Translation<M> {
-    fn translate(&self, value: &mut M);
-}
+pub trait Translation<M> {
+    fn translate(&self, value: &mut M);
+}
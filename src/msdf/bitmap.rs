@@ -0,0 +1,44 @@
+/// # Bitmap
+///
+/// a grid of pixels stored row by row in a plain [`Vec`]. the first row is the bottom
+/// of the image, so y goes up like it does in the shapes the fields are made from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bitmap<P> {
+    width: usize,
+    height: usize,
+    pixels: Vec<P>,
+}
+
+impl<P: Copy + Default> Bitmap<P> {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height, pixels: vec![P::default(); width * height] }
+    }
+}
+impl<P: Copy> Bitmap<P> {
+    /// wraps existing pixels, panicking when there aren't exactly width * height of them.
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<P>) -> Self {
+        assert_eq!(pixels.len(), width * height, "a {}x{} bitmap needs {} pixels", width, height, width * height);
+        Self { width, height, pixels }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn get(&self, x: usize, y: usize) -> P {
+        self.pixels[y * self.width + x]
+    }
+    pub fn set(&mut self, x: usize, y: usize, pixel: P) {
+        self.pixels[y * self.width + x] = pixel;
+    }
+    pub fn pixels(&self) -> &[P] {
+        &self.pixels
+    }
+    pub fn pixels_mut(&mut self) -> &mut [P] {
+        &mut self.pixels
+    }
+    pub fn into_pixels(self) -> Vec<P> {
+        self.pixels
+    }
+}
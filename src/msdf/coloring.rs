@@ -0,0 +1,195 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, Vector, EuclideanGeometry, Shape, Contour, Edge, Number, FloatingPoint};
+
+/// # EdgeColor
+///
+/// the channels of a multi-channel distance field an edge contributes to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum EdgeColor {
+    Black = 0,
+    Red = 1,
+    Green = 2,
+    Yellow = 3,
+    Blue = 4,
+    Magenta = 5,
+    Cyan = 6,
+    White = 7,
+}
+
+impl EdgeColor {
+    pub fn from_bits(bits: u8) -> Self {
+        match bits & 7 {
+            0 => EdgeColor::Black,
+            1 => EdgeColor::Red,
+            2 => EdgeColor::Green,
+            3 => EdgeColor::Yellow,
+            4 => EdgeColor::Blue,
+            5 => EdgeColor::Magenta,
+            6 => EdgeColor::Cyan,
+            _ => EdgeColor::White,
+        }
+    }
+    pub fn bits(&self) -> u8 {
+        *self as u8
+    }
+    /// whether the color includes the channel, where 0 is red, 1 is green and 2 is blue.
+    pub fn has_channel(&self, channel: usize) -> bool {
+        self.bits() & (1 << channel) != 0
+    }
+}
+
+/// # ColoredShape
+///
+/// a shape along with the color of each of its edges, which are indexed the same way as the edges
+/// of the contours.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColoredShape<T: Number> {
+    shape: Shape<T>,
+    colors: Vec<Vec<EdgeColor>>,
+}
+
+impl<T: Number> ColoredShape<T> {
+    /// panics when there isn't exactly one color for every edge.
+    pub fn new(shape: Shape<T>, colors: Vec<Vec<EdgeColor>>) -> Self {
+        assert_eq!(shape.contours().len(), colors.len(), "every contour needs its own colors");
+        for (contour, colors) in shape.contours().iter().zip(colors.iter()) {
+            assert_eq!(contour.edges().len(), colors.len(), "every edge needs a color");
+        }
+        Self { shape, colors }
+    }
+    pub fn shape(&self) -> &Shape<T> {
+        &self.shape
+    }
+    pub fn colors(&self) -> &[Vec<EdgeColor>] {
+        &self.colors
+    }
+    /// every edge of every contour along with its color.
+    pub fn edges(&self) -> impl Iterator<Item = (&Edge<T>, EdgeColor)> {
+        self.shape.contours().iter().zip(self.colors.iter())
+            .flat_map(|(contour, colors)| contour.edges().iter().zip(colors.iter().copied()))
+    }
+}
+
+/// picks the next color for an edge so that two edges meeting at a corner never share more than one
+/// channel, avoiding the channels in banned when possible. follows *switchColor* from [msdfgen](https://github.com/Chlumsky/msdfgen/blob/master/core/edge-coloring.cpp).
+fn switch_color(color: EdgeColor, seed: &mut u64, banned: EdgeColor) -> EdgeColor {
+    let combined = color.bits() & banned.bits();
+    if combined == EdgeColor::Red.bits() || combined == EdgeColor::Green.bits() || combined == EdgeColor::Blue.bits() {
+        return EdgeColor::from_bits(combined ^ EdgeColor::White.bits());
+    }
+    if color == EdgeColor::Black || color == EdgeColor::White {
+        return initial_color(seed);
+    }
+    let shifted = color.bits() << (1 + (*seed & 1));
+    *seed >>= 1;
+    EdgeColor::from_bits(shifted | shifted >> 3)
+}
+fn initial_color(seed: &mut u64) -> EdgeColor {
+    const START: [EdgeColor; 3] = [EdgeColor::Cyan, EdgeColor::Magenta, EdgeColor::Yellow];
+    let color = START[(*seed % 3) as usize];
+    *seed /= 3;
+    color
+}
+/// spreads n edges over 3 colors so that the middle color is in the middle of the edges,
+/// returning -1, 0 or 1.
+fn symmetrical_trichotomy(position: usize, n: usize) -> i32 {
+    (3.0 + 2.875 * position as f64 / (n - 1) as f64 - 1.4375 + 0.5) as i32 - 3
+}
+fn is_corner<T: FloatingPoint>(a: Vector2<T>, b: Vector2<T>, cross_threshold: T) -> bool {
+    a.dot(&b) <= T::zero() || a.cross(b).abs() > cross_threshold
+}
+
+/// colors the edges of a shape for a multi-channel distance field so that edges meeting at a corner
+/// sharper than angle_threshold (in radians) have different colors. contours with one or no corners
+/// are colored so that the distance field stays smooth. contours with a single corner and fewer than
+/// 3 edges have their edges split into thirds so there are enough of them to color. the seed picks
+/// between the possible colorings. this is *edgeColoringSimple* from [msdfgen](https://github.com/Chlumsky/msdfgen/blob/master/core/edge-coloring.cpp).
+pub fn color_edges<T: FloatingPoint>(shape: &Shape<T>, angle_threshold: T, mut seed: u64) -> ColoredShape<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let cross_threshold = angle_threshold.sin();
+    let mut color = initial_color(&mut seed);
+    let mut contours = Vec::with_capacity(shape.contours().len());
+    let mut colors = Vec::with_capacity(shape.contours().len());
+    for contour in shape.contours() {
+        let mut edges = contour.edges().to_vec();
+        if edges.is_empty() {
+            contours.push(Contour::from_edges(edges));
+            colors.push(Vec::new());
+            continue;
+        }
+        let mut corners = Vec::new();
+        let mut previous = edges[edges.len() - 1].direction(T::one());
+        for (i, edge) in edges.iter().enumerate() {
+            if is_corner(previous.normalize(), edge.direction(T::zero()).normalize(), cross_threshold) {
+                corners.push(i);
+            }
+            previous = edge.direction(T::one());
+        }
+        let edge_colors = match corners.len() {
+            // a smooth contour
+            0 => {
+                color = switch_color(color, &mut seed, EdgeColor::Black);
+                vec![color; edges.len()]
+            }
+            // a teardrop with a single corner
+            1 => {
+                color = switch_color(color, &mut seed, EdgeColor::Black);
+                let first = color;
+                color = switch_color(color, &mut seed, EdgeColor::Black);
+                let teardrop = [first, EdgeColor::White, color];
+                let corner = corners[0];
+                if edges.len() >= 3 {
+                    let m = edges.len();
+                    let mut edge_colors = vec![EdgeColor::Black; m];
+                    for i in 0..m {
+                        edge_colors[(corner + i) % m] = teardrop[(1 + symmetrical_trichotomy(i, m)) as usize];
+                    }
+                    edge_colors
+                } else {
+                    // split the edges into thirds starting at the corner so there are enough to color
+                    let third: T = (1.0 / 3.0).as_();
+                    let half: T = 0.5.as_();
+                    let thirds = |edge: &Edge<T>| {
+                        let (first, rest) = edge.split_at(third);
+                        let (second, last) = rest.split_at(half);
+                        [first, second, last]
+                    };
+                    let mut parts: Vec<Edge<T>> = edges.iter().flat_map(thirds).collect();
+                    let edge_colors = if edges.len() >= 2 {
+                        vec![teardrop[0], teardrop[0], teardrop[1], teardrop[1], teardrop[2], teardrop[2]]
+                    } else {
+                        teardrop.to_vec()
+                    };
+                    parts.rotate_left(3 * corner);
+                    edges = parts;
+                    edge_colors
+                }
+            }
+            // several corners, each spline between two corners gets its own color
+            _ => {
+                let corner_count = corners.len();
+                let start = corners[0];
+                let m = edges.len();
+                let mut spline = 0;
+                color = switch_color(color, &mut seed, EdgeColor::Black);
+                let initial = color;
+                let mut edge_colors = vec![EdgeColor::Black; m];
+                for i in 0..m {
+                    let index = (start + i) % m;
+                    if spline + 1 < corner_count && corners[spline + 1] == index {
+                        spline += 1;
+                        let banned = if spline == corner_count - 1 { initial } else { EdgeColor::Black };
+                        color = switch_color(color, &mut seed, banned);
+                    }
+                    edge_colors[index] = color;
+                }
+                edge_colors
+            }
+        };
+        contours.push(Contour::from_edges(edges));
+        colors.push(edge_colors);
+    }
+    ColoredShape::new(Shape::from_contours(contours), colors)
+}
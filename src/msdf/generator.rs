@@ -0,0 +1,254 @@
+use num_traits::AsPrimitive;
+
+use crate::{Vector2, Vector, Shape, Edge, FillRule, Number, FloatingPoint};
+use super::{Bitmap, ColoredShape};
+
+/// # FieldOptions
+///
+/// how a shape is placed into a distance field. the center of the pixel at x, y samples the shape
+/// at (x + 0.5, y + 0.5) / scale - translate, the same projection [msdfgen](https://github.com/Chlumsky/msdfgen) uses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldOptions<T: Number> {
+    /// how many pixels one unit of the shape covers on each axis.
+    pub scale: Vector2<T>,
+    /// moves the shape before it's scaled, in the units of the shape.
+    pub translate: Vector2<T>,
+    /// the width of the range of distances the field covers in the units of the shape. a distance of
+    /// -range / 2 maps to 0, the edge of the shape maps to 0.5 and range / 2 maps to 1.
+    pub range: T,
+    /// decides which parts of the shape are inside, which have a positive distance.
+    pub fill_rule: FillRule,
+    /// pixels of a multi-channel field whose channels change more than this many times as fast as a
+    /// real distance field can between neighbours are flattened to their median. [`None`] turns off
+    /// error correction.
+    pub error_correction: Option<T>,
+}
+
+impl<T: FloatingPoint> FieldOptions<T>
+    where f64: AsPrimitive<T> {
+    pub fn new(scale: Vector2<T>, translate: Vector2<T>, range: T) -> Self {
+        Self { scale, translate, range, fill_rule: FillRule::NonZero, error_correction: Some(1.001.as_()) }
+    }
+    /// places the area from min to max of the shape over the whole field with the same scale on both
+    /// axes, centering the shape on the axis with space left over.
+    pub fn fit(min: Vector2<T>, max: Vector2<T>, width: usize, height: usize, range: T) -> Self {
+        let size = max - min;
+        let (width, height): (T, T) = ((width as f64).as_(), (height as f64).as_());
+        let scale = (width / size.x).min(height / size.y);
+        let half: T = 0.5.as_();
+        let translate = Vector2::new(
+            (width / scale - size.x) * half - min.x,
+            (height / scale - size.y) * half - min.y,
+        );
+        Self::new(Vector2::new(scale, scale), translate, range)
+    }
+    /// the point of the shape sampled by the center of the pixel at x, y.
+    pub fn project(&self, x: usize, y: usize) -> Vector2<T> {
+        let half: T = 0.5.as_();
+        let pixel = Vector2::new((x as f64).as_() + half, (y as f64).as_() + half);
+        Vector2::new(pixel.x / self.scale.x, pixel.y / self.scale.y) - self.translate
+    }
+    fn normalize(&self, distance: T) -> f32 {
+        let value: T = distance / self.range + 0.5.as_();
+        value.as_()
+    }
+}
+
+/// the distance from a point to an edge, ordered by how close the edge is with ties between edges
+/// meeting at a corner broken in favour of the edge pointing more directly away from the point.
+#[derive(Clone, Copy)]
+struct EdgeDistance<T: FloatingPoint> {
+    distance: T,
+    dot: T,
+}
+impl<T: FloatingPoint> EdgeDistance<T>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    fn farthest() -> Self {
+        Self { distance: T::infinity(), dot: T::one() }
+    }
+    fn measure(edge: &Edge<T>, p: Vector2<T>) -> Self {
+        let (distance, _) = edge.signed_distance_param(p);
+        // when the closest point is an end the dot product between the direction of the edge there and
+        // the direction to the point tells apart the edges meeting at it, similar to *SignedDistance* in msdfgen
+        let dot = [(edge.start(), T::zero()), (edge.end(), T::one())].into_iter()
+            .find(|(end, _)| (*end - p).length() == distance.abs())
+            .map(|(end, t)| edge.direction(t).normalize().dot(&(end - p).normalize()).abs())
+            .unwrap_or(T::zero());
+        Self { distance, dot }
+    }
+    fn closer_than(&self, other: &Self) -> bool {
+        let (a, b) = (self.distance.abs(), other.distance.abs());
+        a < b || (a == b && self.dot < other.dot)
+    }
+}
+
+/// the closest edge to p among the edges, along with its distance.
+fn closest_edge<'a, T: FloatingPoint>(edges: impl Iterator<Item = &'a Edge<T>>, p: Vector2<T>) -> Option<(&'a Edge<T>, EdgeDistance<T>)>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut closest = None;
+    let mut closest_distance = EdgeDistance::farthest();
+    for edge in edges {
+        let distance = EdgeDistance::measure(edge, p);
+        if distance.closer_than(&closest_distance) {
+            closest = Some(edge);
+            closest_distance = distance;
+        }
+    }
+    closest.map(|edge| (edge, closest_distance))
+}
+
+/// a field of the true distance to the shape in every pixel.
+pub fn generate_sdf<T: FloatingPoint>(shape: &Shape<T>, width: usize, height: usize, options: &FieldOptions<T>) -> Bitmap<f32>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut bitmap = Bitmap::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let distance = shape.signed_distance(options.project(x, y), options.fill_rule);
+            bitmap.set(x, y, options.normalize(distance));
+        }
+    }
+    bitmap
+}
+
+/// a field of the distance to the closest edge in every pixel, treating the ends of the closest edge as
+/// if the edge kept going in a straight line past them. this keeps corners sharp when the field is scaled up.
+pub fn generate_pseudo_sdf<T: FloatingPoint>(shape: &Shape<T>, width: usize, height: usize, options: &FieldOptions<T>) -> Bitmap<f32>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut bitmap = Bitmap::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let p = options.project(x, y);
+            let value = match closest_edge(shape.edges(), p) {
+                Some((edge, _)) => {
+                    let distance = edge.signed_pseudo_distance(p);
+                    // the sign of the edge depends on the orientation of its contour, so take it from the fill instead
+                    if (distance > T::zero()) == shape.contains(p, options.fill_rule) { distance } else { -distance }
+                }
+                None => -options.range,
+            };
+            bitmap.set(x, y, options.normalize(value));
+        }
+    }
+    bitmap
+}
+
+/// the pseudo distance to the closest edge of each channel at p.
+fn channel_distances<T: FloatingPoint>(shape: &ColoredShape<T>, p: Vector2<T>) -> [T; 3]
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    // channels without any edges are infinitely far outside
+    let mut distances = [T::neg_infinity(); 3];
+    for (channel, distance) in distances.iter_mut().enumerate() {
+        let edges = shape.edges().filter(|(_, color)| color.has_channel(channel)).map(|(edge, _)| edge);
+        if let Some((edge, _)) = closest_edge(edges, p) {
+            *distance = edge.signed_pseudo_distance(p);
+        }
+    }
+    distances
+}
+fn median<T: PartialOrd + Copy>(a: T, b: T, c: T) -> T {
+    let (low, high) = if a < b { (a, b) } else { (b, a) };
+    if c < low { low } else if c > high { high } else { c }
+}
+
+/// a multi-channel field where the median of the red, green and blue channels gives the distance to the
+/// shape, which keeps corners sharp. the edges need to be colored first with [`super::color_edges`].
+/// this follows the original algorithm from Viktor Chlumský's master's thesis, which expects
+/// contours to not overlap each other.
+pub fn generate_msdf<T: FloatingPoint>(shape: &ColoredShape<T>, width: usize, height: usize, options: &FieldOptions<T>) -> Bitmap<[f32; 3]>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut bitmap = Bitmap::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let p = options.project(x, y);
+            let pixel = multi_channel_pixel(shape, p, options);
+            bitmap.set(x, y, [pixel[0], pixel[1], pixel[2]]);
+        }
+    }
+    if let Some(threshold) = options.error_correction {
+        correct_errors(&mut bitmap, options, threshold);
+    }
+    bitmap
+}
+
+/// a multi-channel field like [`generate_msdf`] with the true distance in the alpha channel.
+pub fn generate_mtsdf<T: FloatingPoint>(shape: &ColoredShape<T>, width: usize, height: usize, options: &FieldOptions<T>) -> Bitmap<[f32; 4]>
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let mut bitmap = Bitmap::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let p = options.project(x, y);
+            let pixel = multi_channel_pixel(shape, p, options);
+            let distance = shape.shape().signed_distance(p, options.fill_rule);
+            bitmap.set(x, y, [pixel[0], pixel[1], pixel[2], options.normalize(distance)]);
+        }
+    }
+    if let Some(threshold) = options.error_correction {
+        correct_errors(&mut bitmap, options, threshold);
+    }
+    bitmap
+}
+
+fn multi_channel_pixel<T: FloatingPoint>(shape: &ColoredShape<T>, p: Vector2<T>, options: &FieldOptions<T>) -> [f32; 3]
+    where f32: AsPrimitive<T>, f64: AsPrimitive<T> {
+    let [r, g, b] = channel_distances(shape, p);
+    let mut pixel = [options.normalize(r), options.normalize(g), options.normalize(b)];
+    // the signs of the edges depend on the orientation of their contours, so every channel
+    // is flipped when the median doesn't agree with the fill
+    if (median(pixel[0], pixel[1], pixel[2]) > 0.5) != shape.shape().contains(p, options.fill_rule) {
+        pixel = pixel.map(|value| 1.0 - value);
+    }
+    pixel
+}
+
+/// whether the pixels a and b are too different to be next to each other in a real distance field, in
+/// which case a is the one that should be fixed. this is *detectClash* from [msdfgen](https://github.com/Chlumsky/msdfgen/blob/master/core/msdf-error-correction.cpp).
+fn detect_clash(a: &[f32], b: &[f32], threshold: f32) -> bool {
+    let (mut a0, mut a1, mut a2) = (a[0], a[1], a[2]);
+    let (mut b0, mut b1, mut b2) = (b[0], b[1], b[2]);
+    // sort the channels from the biggest difference to the smallest
+    if (b0 - a0).abs() < (b1 - a1).abs() {
+        std::mem::swap(&mut a0, &mut a1);
+        std::mem::swap(&mut b0, &mut b1);
+    }
+    if (b1 - a1).abs() < (b2 - a2).abs() {
+        std::mem::swap(&mut a1, &mut a2);
+        std::mem::swap(&mut b1, &mut b2);
+        if (b0 - a0).abs() < (b1 - a1).abs() {
+            std::mem::swap(&mut a0, &mut a1);
+            std::mem::swap(&mut b0, &mut b1);
+        }
+    }
+    // a single channel can change quickly at a corner, so it's only a clash when two of them do
+    (b1 - a1).abs() >= threshold &&
+        // the other pixel was already flattened
+        !(b0 == b1 && b0 == b2) &&
+        // only the pixel farther from the edge is fixed
+        (a2 - 0.5).abs() >= (b2 - 0.5).abs()
+}
+
+/// flattens pixels that clash with their neighbours to their median, which removes the artifacts left
+/// where edges of different colors come close without meeting at a corner.
+fn correct_errors<T: FloatingPoint, const N: usize>(bitmap: &mut Bitmap<[f32; N]>, options: &FieldOptions<T>, threshold: T) {
+    let (width, height) = (bitmap.width(), bitmap.height());
+    let threshold_x: f32 = (threshold / (options.scale.x * options.range)).as_();
+    let threshold_y: f32 = (threshold / (options.scale.y * options.range)).as_();
+    let mut clashes = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = bitmap.get(x, y);
+            let clash = |other: [f32; N], threshold: f32| detect_clash(&pixel, &other, threshold);
+            if (x > 0 && clash(bitmap.get(x - 1, y), threshold_x)) ||
+                (x + 1 < width && clash(bitmap.get(x + 1, y), threshold_x)) ||
+                (y > 0 && clash(bitmap.get(x, y - 1), threshold_y)) ||
+                (y + 1 < height && clash(bitmap.get(x, y + 1), threshold_y)) {
+                clashes.push((x, y));
+            }
+        }
+    }
+    for (x, y) in clashes {
+        let mut pixel = bitmap.get(x, y);
+        let value = median(pixel[0], pixel[1], pixel[2]);
+        pixel[..3].fill(value);
+        bitmap.set(x, y, pixel);
+    }
+}
@@ -0,0 +1,6 @@
+mod bitmap;
+mod coloring;
+mod generator;
+pub use bitmap::*;
+pub use coloring::*;
+pub use generator::*;
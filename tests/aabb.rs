@@ -0,0 +1,65 @@
+use drowsed_math::{Aabb2, Aabb3, Cube, Rectangle, Vector2, Vector3};
+
+#[test]
+fn aabb2_operations() {
+    let a = Aabb2::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 4.0));
+    let b = Aabb2::new(Vector2::new(1.0, -1.0), Vector2::new(3.0, 1.0));
+    assert_eq!(a.union(&b), Aabb2::new(Vector2::new(0.0, -1.0), Vector2::new(3.0, 4.0)));
+    assert_eq!(a.intersection(&b), Some(Aabb2::new(Vector2::new(1.0, 0.0), Vector2::new(2.0, 1.0))));
+    assert!(a.overlaps(&b));
+    let far = Aabb2::new(Vector2::new(5.0, 5.0), Vector2::new(6.0, 6.0));
+    assert_eq!(a.intersection(&far), None);
+    assert!(!a.overlaps(&far));
+    assert!(a.contains_point(Vector2::new(2.0, 0.0)));
+    assert!(!a.contains_point(Vector2::new(2.5, 0.0)));
+    assert!(a.union(&b).contains(&a));
+    assert!(!a.contains(&b));
+    assert_eq!(a.center(), Vector2::new(1.0, 2.0));
+    assert_eq!(a.size(), Vector2::new(2.0, 4.0));
+    assert_eq!(a.extents(), Vector2::new(1.0, 2.0));
+    assert_eq!(a.area(), 8.0);
+    assert_eq!(a.perimeter(), 12.0);
+    assert_eq!(a.expand(1.0), Aabb2::new(Vector2::new(-1.0, -1.0), Vector2::new(3.0, 5.0)));
+    assert_eq!(a.closest_point(Vector2::new(-3.0, 1.0)), Vector2::new(0.0, 1.0));
+    assert_eq!(a.closest_point(Vector2::new(1.0, 1.0)), Vector2::new(1.0, 1.0));
+    let points = [Vector2::new(1, 5), Vector2::new(-2, 3), Vector2::new(4, -1)];
+    assert_eq!(Aabb2::from_points(points.iter()), Some(Aabb2::new(Vector2::new(-2, -1), Vector2::new(4, 5))));
+    assert_eq!(Aabb2::<i32>::from_points([].iter()), None);
+}
+
+#[test]
+fn aabb3_operations() {
+    let a = Aabb3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+    let b = Aabb3::new(Vector3::new(0.5, 1.0, 2.0), Vector3::new(4.0, 4.0, 4.0));
+    assert_eq!(a.union(&b), Aabb3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 4.0, 4.0)));
+    assert_eq!(a.intersection(&b), Some(Aabb3::new(Vector3::new(0.5, 1.0, 2.0), Vector3::new(1.0, 2.0, 3.0))));
+    let above = Aabb3::new(Vector3::new(0.0, 0.0, 3.5), Vector3::new(1.0, 1.0, 4.0));
+    assert!(!a.overlaps(&above));
+    assert_eq!(a.intersection(&above), None);
+    assert!(a.contains_point(Vector3::new(1.0, 2.0, 3.0)));
+    assert!(a.contains(&Aabb3::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(1.0, 1.0, 1.0))));
+    assert_eq!(a.center(), Vector3::new(0.5, 1.0, 1.5));
+    assert_eq!(a.extents(), Vector3::new(0.5, 1.0, 1.5));
+    assert_eq!(a.surface_area(), 22.0);
+    assert_eq!(a.volume(), 6.0);
+    assert_eq!(a.closest_point(Vector3::new(2.0, -1.0, 1.0)), Vector3::new(1.0, 0.0, 1.0));
+    assert_eq!(a.expand(-0.5).min, Vector3::new(0.5, 0.5, 0.5));
+}
+
+#[test]
+fn conversions_to_and_from_shapes() {
+    let aabb = Aabb2::new(Vector2::new(-1.0, -2.0), Vector2::new(3.0, 4.0));
+    let rectangle = Rectangle::from(aabb);
+    assert_eq!(rectangle.top_left(), Vector2::new(-1.0, 4.0));
+    assert_eq!(rectangle.bottom_right(), Vector2::new(3.0, -2.0));
+    assert_eq!(Aabb2::from(rectangle), aabb);
+
+    let aabb = Aabb3::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(3.0, 4.0, 5.0));
+    let cube = Cube::from(aabb);
+    assert_eq!((cube.front_depth(), cube.back_depth()), (-3.0, 5.0));
+    assert_eq!(cube.front().top_right(), Vector2::new(3.0, 4.0));
+    assert_eq!(Aabb3::from(cube), aabb);
+    let flat = Cube::new(rectangle, rectangle);
+    assert_eq!((flat.front_depth(), flat.back_depth()), (0.0, 0.0));
+    assert_eq!(Aabb3::from(flat), Aabb3::new(Vector3::new(-1.0, -2.0, 0.0), Vector3::new(3.0, 4.0, 0.0)));
+}
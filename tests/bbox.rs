@@ -0,0 +1,83 @@
+use drowsed_math::{Aabb2, BoundingBox2D, BoundingBox3D, CubicSegment, LinearSegment, QuadraticSegment, Segment, Vector2, Vector3};
+
+mod common;
+use common::Lcg;
+
+/// the bounds found by sampling the segment densely.
+fn sampled_bounds2<S: Segment<VectorType = Vector2<f64>>>(segment: &S) -> (Vector2<f64>, Vector2<f64>) {
+    let mut low = Vector2::new(f64::MAX, f64::MAX);
+    let mut high = Vector2::new(f64::MIN, f64::MIN);
+    for i in 0..=10000 {
+        let p = segment.interpolate(i as f64 / 10000.0);
+        low = Vector2::new(low.x.min(p.x), low.y.min(p.y));
+        high = Vector2::new(high.x.max(p.x), high.y.max(p.y));
+    }
+    (low, high)
+}
+
+fn assert_tight(aabb: &Aabb2<f64>, low: Vector2<f64>, high: Vector2<f64>) {
+    // the box contains every sample and is at most the sampling error away from them
+    assert!(aabb.min.x <= low.x && aabb.min.y <= low.y, "{:?} {:?}", aabb.min, low);
+    assert!(aabb.max.x >= high.x && aabb.max.y >= high.y, "{:?} {:?}", aabb.max, high);
+    assert!(low.x - aabb.min.x < 1e-6 && low.y - aabb.min.y < 1e-6);
+    assert!(aabb.max.x - high.x < 1e-6 && aabb.max.y - high.y < 1e-6);
+}
+
+#[test]
+fn segment_bbox_is_tight() {
+    let mut rng = Lcg(23);
+    for _ in 0..200 {
+        let linear = LinearSegment::new(rng.vector2(), rng.vector2());
+        let quadratic = QuadraticSegment::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let (low, high) = sampled_bounds2(&linear);
+        assert_tight(&linear.bbox(), low, high);
+        let (low, high) = sampled_bounds2(&quadratic);
+        assert_tight(&quadratic.bbox(), low, high);
+        let (low, high) = sampled_bounds2(&cubic);
+        assert_tight(&cubic.bbox(), low, high);
+    }
+}
+
+#[test]
+fn hull_bbox_contains_bbox() {
+    let mut rng = Lcg(29);
+    for _ in 0..200 {
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let (tight, hull) = (cubic.bbox(), cubic.hull_bbox());
+        assert!(hull.contains(&tight));
+    }
+    // the control points of this curve stick out far past the curve itself
+    let cubic = CubicSegment::new(Vector2::new(0.0, 0.0), Vector2::new(0.0, 4.0), Vector2::new(1.0, 4.0), Vector2::new(1.0, 0.0));
+    assert_eq!(cubic.hull_bbox().max, Vector2::new(1.0, 4.0));
+    assert_eq!(cubic.bbox().max, Vector2::new(1.0, 3.0));
+}
+
+#[test]
+fn segment_bbox_3d() {
+    let mut rng = Lcg(31);
+    for _ in 0..100 {
+        let cubic = CubicSegment::new(rng.vector3(), rng.vector3(), rng.vector3(), rng.vector3());
+        let quadratic = QuadraticSegment::new(rng.vector3(), rng.vector3(), rng.vector3());
+        let aabb = cubic.bbox();
+        let quadratic_aabb = quadratic.bbox();
+        for i in 0..=1000 {
+            let t = i as f64 / 1000.0;
+            assert!(aabb.contains_point(cubic.interpolate(t)));
+            assert!(quadratic_aabb.contains_point(quadratic.interpolate(t)));
+        }
+        // the 3d bounds match the 2d bounds of the same curve without z
+        let flat = CubicSegment::new(
+            Vector2::new(cubic.get_point(0).x, cubic.get_point(0).y),
+            Vector2::new(cubic.get_point(1).x, cubic.get_point(1).y),
+            Vector2::new(cubic.get_point(2).x, cubic.get_point(2).y),
+            Vector2::new(cubic.get_point(3).x, cubic.get_point(3).y),
+        );
+        let flat_aabb = flat.bbox();
+        assert_eq!((aabb.min.x, aabb.min.y, aabb.max.x, aabb.max.y), (flat_aabb.min.x, flat_aabb.min.y, flat_aabb.max.x, flat_aabb.max.y));
+    }
+    let linear = LinearSegment::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(-1.0, 2.0, -3.0));
+    let aabb = linear.bbox();
+    assert_eq!(aabb.min, Vector3::new(-1.0, -2.0, -3.0));
+    assert_eq!(aabb.max, Vector3::new(1.0, 2.0, 3.0));
+}
@@ -0,0 +1,209 @@
+use drowsed_math::{
+    epa_2d, epa_3d, gjk_2d, gjk_3d, Aabb3, EuclideanGeometry, Gjk, MinkowskiSum, Obb, Rounded, Simplex, Sphere,
+    SupportMap, Transformed, Vector, Vector2, Vector3
+};
+
+mod common;
+use common::Lcg;
+
+/// a convex polygon going counter clockwise with its points on a circle.
+fn polygon(rng: &mut Lcg) -> Vec<Vector2<f64>> {
+    let center = rng.vector2() * 1.2;
+    let radius = rng.next().abs() * 0.6 + 0.2;
+    let count = 3 + (rng.next().abs() * 6.0) as usize;
+    let mut angles: Vec<f64> = (0..count).map(|_| (rng.next() + 1.0) * std::f64::consts::PI).collect();
+    angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    angles.into_iter().map(|angle| center + Vector2::new(angle.cos(), angle.sin()) * radius).collect()
+}
+/// the distance from p to the line segment from a to b.
+fn segment_distance<V: Vector<Scalar = f64>>(a: V, b: V, p: V) -> f64 {
+    let t = ((p - a).dot(&(b - a)) / (b - a).dot(&(b - a))).clamp(0.0, 1.0);
+    (a + (b - a) * t - p).length()
+}
+/// the smallest overlap of the shadows of two convex shapes on the axes, with the axis turned to go from
+/// a towards b, or [`None`] when some axis separates them.
+fn separating_axes<V: Vector<Scalar = f64>>(a: &[V], b: &[V], axes: &[V]) -> Option<(f64, V)> {
+    let shadow = |points: &[V], axis: V| points.iter().fold((f64::MAX, f64::MIN), |(low, high), p| (low.min(p.dot(&axis)), high.max(p.dot(&axis))));
+    let mut best: Option<(f64, V)> = None;
+    for &axis in axes {
+        let axis = axis.normalize();
+        let ((a_low, a_high), (b_low, b_high)) = (shadow(a, axis), shadow(b, axis));
+        let (forward, backward) = (a_high - b_low, b_high - a_low);
+        if forward < 0.0 || backward < 0.0 {
+            return None;
+        }
+        let candidate = if forward < backward { (forward, axis) } else { (backward, axis * -1.0) };
+        if best.is_none_or(|best| candidate.0 < best.0) {
+            best = Some(candidate);
+        }
+    }
+    best
+}
+fn edge_normals(polygon: &[Vector2<f64>]) -> Vec<Vector2<f64>> {
+    (0..polygon.len()).map(|i| {
+        let edge = polygon[(i + 1) % polygon.len()] - polygon[i];
+        Vector2::new(edge.y, -edge.x)
+    }).collect()
+}
+
+#[test]
+fn spheres_and_circles() {
+    let mut rng = Lcg(1);
+    let (mut hits, mut misses) = (0, 0);
+    for _ in 0..500 {
+        let a = Sphere::new(rng.vector3(), rng.next().abs() + 0.1);
+        let b = Sphere::new(rng.vector3(), rng.next().abs() + 0.1);
+        let between = (b.center - a.center).length();
+        let gap = between - a.radius - b.radius;
+        match gjk_3d(&a, &b) {
+            Gjk::Separated(separation) => {
+                misses += 1;
+                assert!(gap > -1e-9);
+                assert!((separation.distance - gap).abs() < 1e-6);
+                assert!(((separation.point_a - a.center).length() - a.radius).abs() < 1e-6);
+                assert!(((separation.point_b - b.center).length() - b.radius).abs() < 1e-6);
+            }
+            Gjk::Intersecting(simplex) => {
+                hits += 1;
+                assert!(gap < 1e-9);
+                let penetration = epa_3d(&a, &b, &simplex).unwrap();
+                assert!((penetration.depth + gap).abs() < 1e-3, "{} {}", penetration.depth, -gap);
+                assert!((penetration.normal - (b.center - a.center) / between).length() < 0.05);
+            }
+        }
+    }
+    assert!(hits > 50 && misses > 50);
+
+    for _ in 0..500 {
+        let a = Rounded::new(rng.vector2(), rng.next().abs() + 0.1);
+        let b = Rounded::new(rng.vector2(), rng.next().abs() + 0.1);
+        let between = (b.shape - a.shape).length();
+        let gap = between - a.radius - b.radius;
+        match gjk_2d(&a, &b) {
+            Gjk::Separated(separation) => assert!((separation.distance - gap).abs() < 1e-6),
+            Gjk::Intersecting(simplex) => {
+                let penetration = epa_2d(&a, &b, &simplex).unwrap();
+                assert!((penetration.depth + gap).abs() < 1e-3);
+                assert!((penetration.normal - (b.shape - a.shape) / between).length() < 0.05);
+            }
+        }
+    }
+}
+
+#[test]
+fn polygons() {
+    let mut rng = Lcg(2);
+    for _ in 0..1000 {
+        let (a, b) = (polygon(&mut rng), polygon(&mut rng));
+        let axes: Vec<_> = edge_normals(&a).into_iter().chain(edge_normals(&b)).collect();
+        let sat = separating_axes(&a, &b, &axes);
+        match gjk_2d(&a, &b) {
+            Gjk::Separated(separation) => {
+                assert!(sat.is_none_or(|(depth, _)| depth < 1e-9));
+                let brute = a.iter().flat_map(|&p| (0..b.len()).map(move |i| (p, i)))
+                    .map(|(p, i)| segment_distance(b[i], b[(i + 1) % b.len()], p))
+                    .chain(b.iter().flat_map(|&p| (0..a.len()).map(move |i| (p, i)))
+                        .map(|(p, i)| segment_distance(a[i], a[(i + 1) % a.len()], p)))
+                    .fold(f64::MAX, f64::min);
+                assert!((separation.distance - brute).abs() < 1e-6, "{} {}", separation.distance, brute);
+                assert!(((separation.point_a - separation.point_b).length() - separation.distance).abs() < 1e-9);
+            }
+            Gjk::Intersecting(simplex) => {
+                let (depth, normal) = sat.unwrap_or((0.0, Vector2::new(0.0, 0.0)));
+                if let Some(penetration) = epa_2d(&a, &b, &simplex) {
+                    assert!((penetration.depth - depth).abs() < 1e-6, "{} {}", penetration.depth, depth);
+                    // the shapes just touch once b is moved out along the normal
+                    let moved: Vec<_> = b.iter().map(|&p| p + penetration.normal * (penetration.depth + 1e-6)).collect();
+                    assert!(!gjk_2d(&a, &moved).is_intersecting());
+                    if depth > 1e-6 {
+                        assert!(penetration.normal.dot(&normal) > 0.0);
+                    }
+                } else {
+                    assert!(depth < 1e-9);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn boxes() {
+    let mut rng = Lcg(3);
+    for _ in 0..500 {
+        let a = Obb::from_quaternion(rng.vector3(), (rng.vector3() + 1.2) / 3.0, rng.rotation());
+        let b = Obb::from_quaternion(rng.vector3(), (rng.vector3() + 1.2) / 3.0, rng.rotation());
+        let mut axes: Vec<_> = a.axes().into_iter().chain(b.axes()).collect();
+        for x in a.axes() {
+            for y in b.axes() {
+                if x.cross(y).length() > 1e-6 {
+                    axes.push(x.cross(y));
+                }
+            }
+        }
+        let sat = separating_axes(&a.corners(), &b.corners(), &axes);
+        match gjk_3d(&a, &b) {
+            Gjk::Separated(separation) => {
+                assert!(sat.is_none_or(|(depth, _)| depth < 1e-9));
+                // the closest points are on the boxes and nothing of either box is closer
+                assert!((a.closest_point(separation.point_a) - separation.point_a).length() < 1e-6);
+                assert!((b.closest_point(separation.point_b) - separation.point_b).length() < 1e-6);
+                for corner in b.corners() {
+                    assert!((a.closest_point(corner) - corner).length() >= separation.distance - 1e-6);
+                }
+                for corner in a.corners() {
+                    assert!((b.closest_point(corner) - corner).length() >= separation.distance - 1e-6);
+                }
+            }
+            Gjk::Intersecting(simplex) => {
+                let (depth, _) = sat.unwrap_or((0.0, Vector3::new(0.0, 0.0, 0.0)));
+                let penetration = epa_3d(&a, &b, &simplex).unwrap();
+                assert!((penetration.depth - depth).abs() < 1e-6, "{} {}", penetration.depth, depth);
+                let moved = Obb::new(b.center + penetration.normal * (penetration.depth + 1e-6), b.half_extents, b.rotation);
+                assert!(!gjk_3d(&a, &moved).is_intersecting());
+            }
+        }
+    }
+    // boxes sharing a face only touch
+    let a = Aabb3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    let b = Aabb3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0));
+    let Gjk::Intersecting(simplex) = gjk_3d(&a, &b) else { panic!("touching boxes intersect") };
+    assert!(epa_3d(&a, &b, &simplex).unwrap().depth < 1e-9);
+}
+
+#[test]
+fn combined_shapes() {
+    let mut rng = Lcg(4);
+    for _ in 0..200 {
+        // a transformed box is an oriented box
+        let (center, half_extents, rotation) = (rng.vector3(), (rng.vector3() + 1.2) / 2.0, rng.rotation());
+        let obb = Obb::from_quaternion(center, half_extents, rotation);
+        let transformed = Transformed::from_quaternion(Aabb3::new(-half_extents, half_extents), rotation, center);
+        let direction = rng.vector3();
+        assert!((obb.support(direction) - transformed.support(direction)).length() < 1e-12);
+
+        // a box grown by a sphere is a rounded box
+        let aabb = Aabb3::from_point(rng.vector3()).include(rng.vector3());
+        let radius = rng.next().abs() * 0.3;
+        let rounded = MinkowskiSum::new(aabb, Sphere::new(Vector3::new(0.0, 0.0, 0.0), radius));
+        let p = rng.vector3() * 2.0;
+        let gap = (aabb.closest_point(p) - p).length() - radius;
+        match gjk_3d(&rounded, &p) {
+            Gjk::Separated(separation) => {
+                assert!((separation.distance - gap).abs() < 1e-6);
+                assert!((separation.point_b - p).length() < 1e-9);
+            }
+            Gjk::Intersecting(_) => assert!(gap < 1e-9),
+        }
+
+        // a stretched circle is an ellipse
+        let (sx, sy) = (rng.next().abs() + 0.5, rng.next().abs() + 0.5);
+        let ellipse = Transformed::new(Rounded::new(Vector2::new(0.0, 0.0), 1.0), drowsed_math::Matrix2::new(sx, 0.0, 0.0, sy), Vector2::new(0.0, 0.0));
+        let angle = rng.next() * std::f64::consts::PI;
+        let direction = Vector2::new(angle.cos(), angle.sin());
+        let support = ellipse.support(direction);
+        assert!(((support.x / sx).powi(2) + (support.y / sy).powi(2) - 1.0).abs() < 1e-12);
+        assert!(Vector2::new(support.x / (sx * sx), support.y / (sy * sy)).normalize().cross(direction).abs() < 1e-12);
+    }
+    let simplex = Simplex::<Vector2<f64>, 3>::from_slice(&[Vector2::new(0.0, 0.0); 5]);
+    assert_eq!(simplex.size, 3);
+}
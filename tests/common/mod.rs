@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+use drowsed_math::{Vector2, Vector3};
+use drowsed_math::complex::quaternion::Quaternion;
+
+/// small deterministic random number generator so the tests are reproducible.
+pub struct Lcg(pub u64);
+impl Lcg {
+    /// a number between -1 and 1.
+    pub fn next(&mut self) -> f64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+    }
+    /// a whole number below n.
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next() + 1.0) * 0.5 * n as f64) as usize % n
+    }
+    pub fn vector2(&mut self) -> Vector2<f64> {
+        Vector2::new(self.next(), self.next())
+    }
+    pub fn vector3(&mut self) -> Vector3<f64> {
+        Vector3::new(self.next(), self.next(), self.next())
+    }
+    /// a random unit quaternion.
+    pub fn rotation(&mut self) -> Quaternion<f64> {
+        Quaternion { vector: self.vector3(), scalar: self.next() }.normalize()
+    }
+}
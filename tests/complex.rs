@@ -0,0 +1,72 @@
+use drowsed_math::complex::Complex;
+
+mod common;
+use common::Lcg;
+
+fn assert_close(a: Complex<f64>, b: Complex<f64>, tolerance: f64) {
+    assert!((a - b).norm() < tolerance * b.norm().max(1.0), "{:?} {:?}", a, b);
+}
+
+#[test]
+fn powers() {
+    let mut rng = Lcg(1);
+    let (zero, one) = (Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+    // anything to the power of 0 is 1, including 0
+    assert_eq!(zero.powf(0.0), one);
+    assert_eq!(zero.powc(zero), one);
+    assert_eq!(Complex::new(0.3, -2.0).powc(zero), one);
+    // 0 to a positive power is 0 and to a negative power is infinite
+    assert_eq!(zero.powf(2.5), zero);
+    assert_eq!(zero.powc(Complex::new(0.5, 3.0)), zero);
+    assert_eq!(zero.powf(-1.0).re, f64::INFINITY);
+    assert_eq!(zero.powc(Complex::new(-2.0, 0.0)).re, f64::INFINITY);
+    assert!(zero.powc(Complex::new(-1.0, 1.0)).re.is_nan());
+    assert!(zero.powc(Complex::new(0.0, 1.0)).re.is_nan());
+    for _ in 0..100 {
+        let z = Complex::new(rng.next() * 3.0, rng.next() * 3.0);
+        // whole powers are repeated multiplication
+        assert_close(z.powf(3.0), z * z * z, 1e-12);
+        assert_close(z.powf(-2.0), one / (z * z), 1e-12);
+        assert_close(z.powc(Complex::new(2.0, 0.0)), z * z, 1e-12);
+        // complex powers follow the rules of exponents
+        let (a, b) = (Complex::new(rng.next(), rng.next()), Complex::new(rng.next(), rng.next()));
+        assert_close(z.powc(a) * z.powc(b), z.powc(a + b), 1e-9);
+        assert_close(z.powc(a), (a * z.ln()).exp(), 1e-12);
+        assert_close(z.powf(0.5), z.sqrt(), 1e-12);
+    }
+}
+
+#[test]
+fn inverse_functions() {
+    let mut rng = Lcg(2);
+    for _ in 0..200 {
+        let z = Complex::new(rng.next() * 2.0, rng.next() * 2.0);
+        assert_close(z.ln().exp(), z, 1e-12);
+        assert_close(z.sqrt() * z.sqrt(), z, 1e-12);
+        assert!(z.sqrt().re >= 0.0);
+        let (r, theta) = z.to_polar();
+        assert_close(Complex::from_polar(r, theta), z, 1e-12);
+        assert_close((z / Complex::new(0.5, -1.5)) * Complex::new(0.5, -1.5), z, 1e-12);
+        assert_eq!(-(-z), z);
+
+        assert_close(z.asin().sin(), z, 1e-9);
+        assert_close(z.acos().cos(), z, 1e-9);
+        assert_close(z.atan().tan(), z, 1e-9);
+        assert_close(z.asinh().sinh(), z, 1e-9);
+        assert_close(z.acosh().cosh(), z, 1e-9);
+        assert_close(z.atanh().tanh(), z, 1e-9);
+        // the principal values stay inside of the strips they are defined on
+        assert!(z.asin().re.abs() <= std::f64::consts::FRAC_PI_2 + 1e-12);
+        assert!((0.0..=std::f64::consts::PI + 1e-12).contains(&z.acos().re));
+        assert!(z.ln().im.abs() <= std::f64::consts::PI);
+    }
+    // the functions agree with the real ones on the real axis
+    for x in [-0.9, -0.2, 0.0, 0.4, 0.8] {
+        let z = Complex::new(x, 0.0);
+        assert!((z.asin().re - f64::asin(x)).abs() < 1e-12 && z.asin().im.abs() < 1e-12);
+        assert!((z.acos().re - f64::acos(x)).abs() < 1e-12 && z.acos().im.abs() < 1e-12);
+        assert!((z.atan().re - f64::atan(x)).abs() < 1e-12 && z.atan().im.abs() < 1e-12);
+        assert!((z.atanh().re - f64::atanh(x)).abs() < 1e-12 && z.atanh().im.abs() < 1e-12);
+        assert!((z.exp().re - f64::exp(x)).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,107 @@
+use drowsed_math::{Contour, CubicSegment, Edge, FillRule, LinearSegment, Orientation, QuadraticSegment, Shape, Vector2};
+
+fn polygon(points: &[(f64, f64)]) -> Contour<f64> {
+    let mut contour = Contour::new();
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        contour.push(LinearSegment::new(Vector2::new(a.0, a.1), Vector2::new(b.0, b.1)));
+    }
+    contour
+}
+
+/// a circle of radius 1 around the origin going counter clockwise made from 4 cubics.
+fn circle() -> Contour<f64> {
+    let k = 0.5522847498;
+    let mut contour = Contour::new();
+    let mut points = [Vector2::new(1.0, 0.0), Vector2::new(1.0, k), Vector2::new(k, 1.0), Vector2::new(0.0, 1.0)];
+    for _ in 0..4 {
+        contour.push(CubicSegment::new(points[0], points[1], points[2], points[3]));
+        points = points.map(|p| Vector2::new(-p.y, p.x));
+    }
+    contour
+}
+
+#[test]
+fn square_contour() {
+    let mut square = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+    assert!(square.is_closed());
+    assert_eq!(square.signed_area(), 4.0);
+    assert_eq!(square.orientation(), Orientation::CounterClockwise);
+    assert_eq!(square.winding_number(Vector2::new(0.0, 0.0)), 1);
+    // rays through the corners count them once
+    assert_eq!(square.winding_number(Vector2::new(0.0, 1.0 - 1e-9)), 1);
+    assert_eq!(square.winding_number(Vector2::new(-2.0, 1.0)), 0);
+    assert_eq!(square.winding_number(Vector2::new(2.0, 0.0)), 0);
+    assert!(square.contains(Vector2::new(0.5, -0.5), FillRule::NonZero));
+    assert!(!square.contains(Vector2::new(0.5, -1.5), FillRule::EvenOdd));
+    let bounds = square.bounds().unwrap();
+    assert_eq!((bounds.min, bounds.max), (Vector2::new(-1.0, -1.0), Vector2::new(1.0, 1.0)));
+
+    square.reverse();
+    assert!(square.is_closed());
+    assert_eq!(square.signed_area(), -4.0);
+    assert_eq!(square.orientation(), Orientation::Clockwise);
+    assert_eq!(square.winding_number(Vector2::new(0.0, 0.0)), -1);
+    // clockwise contours have a positive distance inside
+    assert!((square.signed_distance(Vector2::new(0.5, 0.0)) - 0.5).abs() < 1e-12);
+    assert!((square.signed_distance(Vector2::new(0.0, 3.0)) + 2.0).abs() < 1e-12);
+
+    let mut open = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+    open.edges_mut().pop();
+    assert!(!open.is_closed());
+    assert_eq!(Contour::<f64>::new().bounds(), None);
+}
+
+#[test]
+fn curved_contours() {
+    let circle = circle();
+    assert!(circle.is_closed());
+    assert!((circle.signed_area() - std::f64::consts::PI).abs() < 1e-3);
+    let bounds = circle.bounds().unwrap();
+    assert!((bounds.min - Vector2::new(-1.0, -1.0)).x.abs() < 1e-12 && (bounds.max - Vector2::new(1.0, 1.0)).y.abs() < 1e-12);
+    for (x, y, inside) in [(0.0, 0.0, true), (0.7, 0.7, true), (0.72, 0.72, false), (0.0, -0.999, true), (-1.001, 0.0, false)] {
+        assert_eq!(circle.contains(Vector2::new(x, y), FillRule::NonZero), inside, "{} {}", x, y);
+    }
+    // a line along the bottom and a parabola over the top
+    let mut dome = Contour::new();
+    dome.push(LinearSegment::new(Vector2::new(0.0f64, 0.0), Vector2::new(2.0, 0.0)));
+    dome.push(QuadraticSegment::new(Vector2::new(2.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(0.0, 0.0)));
+    assert!((dome.signed_area() - 4.0 / 3.0).abs() < 1e-12);
+    assert_eq!(dome.winding_number(Vector2::new(1.0, 0.9)), 1);
+    assert_eq!(dome.winding_number(Vector2::new(1.0, 1.1)), 0);
+    let edges: Vec<Edge<f64>> = dome.edges().to_vec();
+    assert_eq!(edges[1].start(), Vector2::new(2.0, 0.0));
+    assert_eq!(edges[1].reverse().end(), Vector2::new(2.0, 0.0));
+}
+
+#[test]
+fn shapes_with_holes() {
+    let outer = polygon(&[(-2.0, -2.0), (2.0, -2.0), (2.0, 2.0), (-2.0, 2.0)]);
+    let inner = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+    let origin = Vector2::new(0.0, 0.0);
+    let ring = Vector2::new(1.5, 0.0);
+    // both contours going the same way only makes a hole with the even odd rule
+    let shape = Shape::from_contours(vec![outer.clone(), inner.clone()]);
+    assert_eq!(shape.winding_number(origin), 2);
+    assert!(shape.contains(origin, FillRule::NonZero));
+    assert!(!shape.contains(origin, FillRule::EvenOdd));
+    assert_eq!(shape.signed_area(), 20.0);
+    // the hole going the other way works with both rules
+    let mut hole = inner.clone();
+    hole.reverse();
+    let mut shape = Shape::new();
+    shape.push(outer);
+    shape.push(hole);
+    assert!(shape.is_closed());
+    assert_eq!(shape.signed_area(), 12.0);
+    for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+        assert!(!shape.contains(origin, fill_rule));
+        assert!(shape.contains(ring, fill_rule));
+        assert!((shape.signed_distance(origin, fill_rule) + 1.0).abs() < 1e-12);
+        assert!((shape.signed_distance(ring, fill_rule) - 0.5).abs() < 1e-12);
+        assert!((shape.signed_distance(Vector2::new(5.0, 0.0), fill_rule) + 3.0).abs() < 1e-12);
+    }
+    let bounds = shape.bounds().unwrap();
+    assert_eq!((bounds.min, bounds.max), (Vector2::new(-2.0, -2.0), Vector2::new(2.0, 2.0)));
+    assert_eq!(shape.edges().count(), 8);
+}
@@ -0,0 +1,142 @@
+use drowsed_math::complex::Complex;
+use drowsed_math::equations::{CubicFormula, CubicSolution, QuarticFormula, QuarticSolution};
+
+mod common;
+use common::Lcg;
+
+/// the real and complex roots of a cubic solution.
+fn cubic_roots<T: Copy + Into<f64> + drowsed_math::Number>(solution: CubicSolution<T>) -> (Vec<f64>, Vec<Complex<f64>>) {
+    let complex = |c: [Complex<T>; 2]| c.iter().map(|c| Complex::new(c.re.into(), c.im.into())).collect();
+    match solution {
+        CubicSolution::None(_) | CubicSolution::All(_) => (vec![], vec![]),
+        CubicSolution::OneReal(r) => (vec![r.into()], vec![]),
+        CubicSolution::TwoReal(r) => (r.iter().map(|&r| r.into()).collect(), vec![]),
+        CubicSolution::ThreeReal(r) => (r.iter().map(|&r| r.into()).collect(), vec![]),
+        CubicSolution::TwoComplex(c) => (vec![], complex(c)),
+        CubicSolution::OneRealTwoComplex(r, c) => (vec![r.into()], complex(c)),
+    }
+}
+/// the real and complex roots of a quartic solution.
+fn quartic_roots<T: Copy + Into<f64> + drowsed_math::Number>(solution: QuarticSolution<T>) -> (Vec<f64>, Vec<Complex<f64>>) {
+    let complex = |c: &[Complex<T>]| c.iter().map(|c| Complex::new(c.re.into(), c.im.into())).collect();
+    let reals = |r: &[T]| r.iter().map(|&r| r.into()).collect();
+    match solution {
+        QuarticSolution::None(_) | QuarticSolution::All(_) => (vec![], vec![]),
+        QuarticSolution::OneReal(r) => (vec![r.into()], vec![]),
+        QuarticSolution::TwoReal(r) => (reals(&r), vec![]),
+        QuarticSolution::ThreeReal(r) => (reals(&r), vec![]),
+        QuarticSolution::FourReal(r) => (reals(&r), vec![]),
+        QuarticSolution::TwoComplex(c) => (vec![], complex(&c)),
+        QuarticSolution::OneRealTwoComplex(r, c) => (vec![r.into()], complex(&c)),
+        QuarticSolution::TwoRealTwoComplex(r, c) => (reals(&r), complex(&c)),
+        QuarticSolution::FourComplex(c) => (vec![], complex(&c)),
+    }
+}
+/// checks that the roots found are the expected ones, ignoring their order.
+fn assert_roots(found: (Vec<f64>, Vec<Complex<f64>>), reals: &[f64], complex: &[Complex<f64>], tolerance: f64) {
+    let (mut found_reals, found_complex) = found;
+    found_reals.sort_by(f64::total_cmp);
+    let mut expected = reals.to_vec();
+    expected.sort_by(f64::total_cmp);
+    assert_eq!(found_reals.len(), expected.len(), "{:?} {:?}", found_reals, expected);
+    for (a, b) in found_reals.iter().zip(expected.iter()) {
+        assert!((a - b).abs() < tolerance * b.abs().max(1.0), "{:?} {:?}", found_reals, expected);
+    }
+    assert_eq!(found_complex.len(), complex.len(), "{:?} {:?}", found_complex, complex);
+    for c in complex {
+        assert!(found_complex.iter().any(|f| (*f - *c).norm() < tolerance * c.norm().max(1.0)), "{:?} {:?}", found_complex, complex);
+    }
+}
+
+#[test]
+fn cubics() {
+    let i = |re: f64, im: f64| Complex::new(re, im);
+    // (x - 2)^3 has a triple root, which is only returned once or twice
+    for roots in [cubic_roots(f64::cubic_formula(1.0, -6.0, 12.0, -8.0)), cubic_roots(f32::cubic_formula(1.0, -6.0, 12.0, -8.0))] {
+        assert!(roots.1.is_empty() && !roots.0.is_empty());
+        assert!(roots.0.iter().all(|r| (r - 2.0).abs() < 1e-4), "{:?}", roots);
+    }
+    // (x - 1)(x^2 + 1)
+    assert_roots(cubic_roots(f64::cubic_formula(2.0, -2.0, 2.0, -2.0)), &[1.0], &[i(0.0, 1.0), i(0.0, -1.0)], 1e-12);
+    assert_roots(cubic_roots(f32::cubic_formula(1.0, -1.0, 1.0, -1.0)), &[1.0], &[i(0.0, 1.0), i(0.0, -1.0)], 1e-5);
+    // (x - 1)(x - 2)(x + 3)
+    assert_roots(cubic_roots(f64::cubic_formula(1.0, 0.0, -7.0, 6.0)), &[1.0, 2.0, -3.0], &[], 1e-12);
+    assert_roots(cubic_roots(f32::cubic_formula(-0.5, 0.0, 3.5, -3.0)), &[1.0, 2.0, -3.0], &[], 1e-5);
+    // (x - 1)^2 (x + 2) has a repeated root
+    assert_roots(cubic_roots(f64::cubic_formula(1.0, 0.0, -3.0, 2.0)), &[-2.0, 1.0], &[], 1e-12);
+    // without a cubic term the equation is solved as a quadratic
+    assert_roots(cubic_roots(f64::cubic_formula(0.0, 1.0, -3.0, 2.0)), &[1.0, 2.0], &[], 1e-12);
+
+    let mut rng = Lcg(1);
+    for _ in 0..200 {
+        let roots = [rng.next() * 4.0, rng.next() * 4.0, rng.next() * 4.0];
+        let [r0, r1, r2] = roots;
+        let scale = rng.next() + 2.0;
+        let (b, c, d) = (-(r0 + r1 + r2), r0 * r1 + r0 * r2 + r1 * r2, -r0 * r1 * r2);
+        assert_roots(cubic_roots(f64::cubic_formula(scale, b * scale, c * scale, d * scale)), &roots, &[], 1e-6);
+        // a real root and a complex pair
+        let (re, im) = (rng.next() * 2.0, rng.next().abs() + 0.1);
+        let (b, c, d) = (-(r0 + 2.0 * re), 2.0 * re * r0 + re * re + im * im, -r0 * (re * re + im * im));
+        assert_roots(cubic_roots(f64::cubic_formula(1.0, b, c, d)), &[r0], &[i(re, im), i(re, -im)], 1e-9);
+    }
+}
+
+#[test]
+fn quartics() {
+    let i = |re: f64, im: f64| Complex::new(re, im);
+    // (x - 1)(x - 2)(x - 3)(x - 4)
+    assert_roots(quartic_roots(f64::quartic_formula(1.0, -10.0, 35.0, -50.0, 24.0)), &[1.0, 2.0, 3.0, 4.0], &[], 1e-12);
+    assert_roots(quartic_roots(f32::quartic_formula(1.0, -10.0, 35.0, -50.0, 24.0)), &[1.0, 2.0, 3.0, 4.0], &[], 1e-4);
+    // (x^2 + 1)(x^2 + 4) is biquadratic
+    let complex = [i(0.0, 1.0), i(0.0, -1.0), i(0.0, 2.0), i(0.0, -2.0)];
+    assert_roots(quartic_roots(f64::quartic_formula(1.0, 0.0, 5.0, 0.0, 4.0)), &[], &complex, 1e-12);
+    assert_roots(quartic_roots(f32::quartic_formula(1.0, 0.0, 5.0, 0.0, 4.0)), &[], &complex, 1e-5);
+    // (x - 1)(x + 2)(x^2 + x + 1)
+    let complex = [i(-0.5, 0.75f64.sqrt()), i(-0.5, -(0.75f64.sqrt()))];
+    assert_roots(quartic_roots(f64::quartic_formula(1.0, 2.0, 0.0, -1.0, -2.0)), &[1.0, -2.0], &complex, 1e-12);
+    assert_roots(quartic_roots(f32::quartic_formula(1.0, 2.0, 0.0, -1.0, -2.0)), &[1.0, -2.0], &complex, 1e-5);
+    // (x - 1)^2 (x + 1)^2 and (x - 1)^2 (x^2 + 1) have repeated roots that are only returned once
+    assert_roots(quartic_roots(f64::quartic_formula(1.0, 0.0, -2.0, 0.0, 1.0)), &[1.0, -1.0], &[], 1e-9);
+    assert_roots(quartic_roots(f64::quartic_formula(1.0, -2.0, 2.0, -2.0, 1.0)), &[1.0], &[i(0.0, 1.0), i(0.0, -1.0)], 1e-6);
+    // (x - 1)^3 (x + 1) and (x - 1)^4 lose precision, but the roots still have to be close
+    for (solution, expected) in [
+        (quartic_roots(f64::quartic_formula(1.0, -2.0, 0.0, 2.0, -1.0)), [1.0, -1.0].as_slice()),
+        (quartic_roots(f64::quartic_formula(1.0, -4.0, 6.0, -4.0, 1.0)), [1.0].as_slice()),
+    ] {
+        assert!(solution.0.iter().all(|r| expected.iter().any(|e| (r - e).abs() < 1e-3)), "{:?}", solution);
+        assert!(solution.1.iter().all(|c| expected.iter().any(|e| (*c - i(*e, 0.0)).norm() < 1e-3)), "{:?}", solution);
+        assert!(expected.iter().all(|e| solution.0.iter().any(|r| (r - e).abs() < 1e-3)), "{:?}", solution);
+    }
+    // a tiny leading coefficient: 1e-10x^4 + x^2 - 3x + 2 has the roots of (x - 1)(x - 2) and
+    // a complex pair far away from them
+    let (reals, complex) = quartic_roots(f64::quartic_formula(1e-10, 0.0, 1.0, -3.0, 2.0));
+    assert_eq!(complex.len(), 2);
+    assert_roots((reals, vec![]), &[1.0, 2.0], &[], 1e-9);
+    for root in complex {
+        let residual = (root * root * root * root * 1e-10 + root * root - root * 3.0) + 2.0;
+        assert!(residual.norm() < 1e-6 * root.norm_sqr(), "{:?} {:?}", root, residual);
+        assert!((root.norm() - 1e5).abs() < 1.0);
+    }
+    // without a quartic term the equation is solved as a cubic
+    assert_roots(quartic_roots(f64::quartic_formula(0.0, 1.0, 0.0, -7.0, 6.0)), &[1.0, 2.0, -3.0], &[], 1e-12);
+
+    let mut rng = Lcg(2);
+    for _ in 0..200 {
+        let roots = [rng.next() * 3.0, rng.next() * 3.0, rng.next() * 3.0, rng.next() * 3.0];
+        let [r0, r1, r2, r3] = roots;
+        let (s01, p01, s23, p23) = (r0 + r1, r0 * r1, r2 + r3, r2 * r3);
+        // (x^2 - s01x + p01)(x^2 - s23x + p23)
+        let (b, c, d, e) = (-(s01 + s23), p01 + p23 + s01 * s23, -(s01 * p23 + s23 * p01), p01 * p23);
+        let close = roots.iter().enumerate().any(|(i, a)| roots[i + 1..].iter().any(|b| (a - b).abs() < 1e-2));
+        if !close {
+            assert_roots(quartic_roots(f64::quartic_formula(1.0, b, c, d, e)), &roots, &[], 1e-6);
+        }
+        // two real roots and a complex pair
+        let (re, im) = (rng.next() * 2.0, rng.next().abs() + 0.1);
+        let (s23, p23) = (2.0 * re, re * re + im * im);
+        let (b, c, d, e) = (-(s01 + s23), p01 + p23 + s01 * s23, -(s01 * p23 + s23 * p01), p01 * p23);
+        if (r0 - r1).abs() > 1e-2 {
+            assert_roots(quartic_roots(f64::quartic_formula(2.0, b * 2.0, c * 2.0, d * 2.0, e * 2.0)), &[r0, r1], &[i(re, im), i(re, -im)], 1e-6);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use drowsed_math::{CubicSegment, QuadraticSegment, Segment, Vector, Vector2};
+
+mod common;
+use common::Lcg;
+
+fn distance_to_line<V: Vector<Scalar = f64>>(p: V, a: V, b: V) -> f64 {
+    let ab = b - a;
+    let length = ab.dot(&ab);
+    let t = if length > 0.0 { ((p - a).dot(&ab) / length).clamp(0.0, 1.0) } else { 0.0 };
+    (p - (a + ab * t)).length()
+}
+/// the furthest any densely sampled point of the curve is from the polyline.
+fn max_error<V: Vector<Scalar = f64>, S: Segment<VectorType = V>>(segment: &S, polyline: &[V]) -> f64 {
+    const SAMPLES: usize = 2000;
+    (0..=SAMPLES).map(|i| {
+        let p = segment.interpolate(i as f64 / SAMPLES as f64);
+        polyline.windows(2).map(|line| distance_to_line(p, line[0], line[1])).fold(f64::MAX, f64::min)
+    }).fold(0.0, f64::max)
+}
+/// whether every point of the polyline is on the curve.
+fn on_curve<V: Vector<Scalar = f64>, S: Segment<VectorType = V>>(segment: &S, polyline: &[V]) -> bool {
+    const SAMPLES: usize = 4000;
+    polyline.iter().all(|&p| {
+        (0..=SAMPLES).map(|i| (segment.interpolate(i as f64 / SAMPLES as f64) - p).length()).fold(f64::MAX, f64::min) < 2e-2
+    })
+}
+
+#[test]
+fn quadratics_stay_within_tolerance() {
+    let mut rng = Lcg(1);
+    for i in 0..90 {
+        let tolerance = [0.01, 0.1, 0.5][i % 3];
+        let quadratic = QuadraticSegment::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let flatten = quadratic.flatten(tolerance);
+        let expected = flatten.len();
+        let polyline: Vec<_> = flatten.collect();
+        assert_eq!(polyline.len(), expected);
+        assert_eq!(polyline[0], *quadratic.start());
+        assert_eq!(polyline[polyline.len() - 1], *quadratic.end());
+        assert!(on_curve(&quadratic, &polyline));
+        let error = max_error(&quadratic, &polyline);
+        assert!(error <= tolerance * 1.01, "{:?} is {} away at a tolerance of {}", quadratic, error, tolerance);
+        // never more lines than splitting evenly, which is what the error bound of the second derivative needs
+        let [p0, p1, p2] = [0, 1, 2].map(|i| *quadratic.get_point(i));
+        let even = ((p0 - p1 * 2.0 + p2).length() / (4.0 * tolerance)).sqrt().ceil().max(1.0) as usize;
+        assert!(polyline.len() - 1 <= even, "{} lines where splitting evenly needs {}", polyline.len() - 1, even);
+    }
+}
+
+#[test]
+fn cubics_stay_within_tolerance() {
+    let mut rng = Lcg(2);
+    for i in 0..90 {
+        let tolerance = [0.01, 0.1, 0.5][i % 3];
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let polyline: Vec<_> = cubic.flatten(tolerance).collect();
+        assert_eq!(polyline[0], *cubic.start());
+        assert_eq!(polyline[polyline.len() - 1], *cubic.end());
+        assert!(on_curve(&cubic, &polyline));
+        let error = max_error(&cubic, &polyline);
+        assert!(error <= tolerance, "{:?} is {} away at a tolerance of {}", cubic, error, tolerance);
+        // halving the tolerance never takes fewer lines
+        assert!(cubic.flatten(tolerance / 2.0).count() >= polyline.len());
+    }
+}
+
+#[test]
+fn flattening_in_3d() {
+    let mut rng = Lcg(3);
+    for _ in 0..50 {
+        let quadratic = QuadraticSegment::new(rng.vector3(), rng.vector3(), rng.vector3());
+        let polyline: Vec<_> = quadratic.flatten(0.05).collect();
+        assert!(max_error(&quadratic, &polyline) <= 0.05 * 1.01);
+        let cubic = CubicSegment::new(rng.vector3(), rng.vector3(), rng.vector3(), rng.vector3());
+        let polyline: Vec<_> = cubic.flatten(0.05).collect();
+        assert!(max_error(&cubic, &polyline) <= 0.05);
+    }
+}
+
+#[test]
+fn straight_and_degenerate_curves() {
+    // straight curves only need a single line
+    let line = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0));
+    assert_eq!(line.flatten(0.001).count(), 2);
+    let line = CubicSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0), Vector2::new(3.0, 3.0));
+    assert_eq!(line.flatten(0.0).collect::<Vec<_>>(), vec![Vector2::new(0.0, 0.0), Vector2::new(3.0, 3.0)]);
+    // a control point past the end makes the curve double back, which still has to be covered
+    let back = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(1.0, 0.0));
+    let polyline: Vec<_> = back.flatten(0.01).collect();
+    assert!(max_error(&back, &polyline) <= 0.01);
+    let point = QuadraticSegment::new(Vector2::new(1.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(1.0, 1.0));
+    assert_eq!(point.flatten(0.1).count(), 2);
+    // a tolerance that isn't positive is capped instead of running forever
+    let curve = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0));
+    assert_eq!(curve.flatten(0.0).count(), (1 << 16) + 1);
+    assert_eq!(curve.flatten(-1.0).count(), (1 << 16) + 1);
+    let curve = curve.elevate();
+    assert_eq!(curve.flatten(-1.0).count(), (1 << 16) + 1);
+}
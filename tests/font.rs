@@ -0,0 +1,287 @@
+use drowsed_math::font::{Font, FontError, HorizontalMetrics};
+use drowsed_math::{CubicSegment, Edge, FillRule, LinearSegment, Orientation, Shape, Vector2};
+
+mod common;
+use common::Lcg;
+
+/// the font the truetype tests read, which is left out of the published crate so those tests are skipped there.
+fn dejavu() -> Option<Vec<u8>> {
+    std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/DejaVuSansMono.ttf")).ok()
+}
+
+fn line(a: (f64, f64), b: (f64, f64)) -> Edge<f64> {
+    LinearSegment::new(Vector2::new(a.0, a.1), Vector2::new(b.0, b.1)).into()
+}
+fn cubic(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> Edge<f64> {
+    CubicSegment::new(Vector2::new(a.0, a.1), Vector2::new(b.0, b.1), Vector2::new(c.0, c.1), Vector2::new(d.0, d.1)).into()
+}
+fn edges(shape: &Shape<f64>) -> Vec<Edge<f64>> {
+    shape.edges().copied().collect()
+}
+
+/// writes type 2 charstrings, numbers are always stored as shortints.
+#[derive(Default)]
+struct CharString(Vec<u8>);
+impl CharString {
+    fn n(mut self, values: &[i16]) -> Self {
+        for value in values {
+            self.0.push(28);
+            self.0.extend(value.to_be_bytes());
+        }
+        self
+    }
+    fn fixed(mut self, value: f64) -> Self {
+        self.0.push(255);
+        self.0.extend(((value * 65536.0) as i32).to_be_bytes());
+        self
+    }
+    fn op(mut self, ops: &[u8]) -> Self {
+        self.0.extend(ops);
+        self
+    }
+}
+
+fn u16s(values: &[u16]) -> Vec<u8> {
+    values.iter().flat_map(|value| value.to_be_bytes()).collect()
+}
+fn cff_index(items: &[Vec<u8>]) -> Vec<u8> {
+    let mut index = u16s(&[items.len() as u16]);
+    if items.is_empty() {
+        return index;
+    }
+    index.push(4);
+    let mut offset = 1u32;
+    index.extend(offset.to_be_bytes());
+    for item in items {
+        offset += item.len() as u32;
+        index.extend(offset.to_be_bytes());
+    }
+    items.iter().for_each(|item| index.extend(item));
+    index
+}
+fn dict_int(value: usize) -> Vec<u8> {
+    let mut bytes = vec![29];
+    bytes.extend((value as i32).to_be_bytes());
+    bytes
+}
+fn sfnt(version: &[u8; 4], tables: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let mut font = version.to_vec();
+    font.extend(u16s(&[tables.len() as u16, 0, 0, 0]));
+    let mut offset = 12 + tables.len() * 16;
+    let mut data = Vec::new();
+    for (tag, table) in tables {
+        font.extend(*tag);
+        font.extend([0; 4]);
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((table.len() as u32).to_be_bytes());
+        let padded = table.len().div_ceil(4) * 4;
+        data.extend(table);
+        data.resize(data.len() + padded - table.len(), 0);
+        offset += padded;
+    }
+    font.extend(data);
+    font
+}
+
+/// an opentype font with cff outlines for the characters a, b and c and the emoji 😀.
+fn cff_font() -> Vec<u8> {
+    let char_strings = [
+        CharString::default().op(&[14]),
+        // a width in front of the move, then a square going counter clockwise
+        CharString::default().n(&[500, 100, 100]).op(&[21]).n(&[300]).op(&[6]).n(&[300]).op(&[7]).n(&[-300]).op(&[6]).op(&[14]),
+        // stems, a hint mask with an implied stem in front of it, a local subroutine with a curve and a
+        // global subroutine with a line and the end
+        CharString::default().n(&[10, 20, 40, 20]).op(&[1]).n(&[0, 30]).op(&[19, 0xA0]).n(&[100, 100]).op(&[21])
+            .n(&[-107]).op(&[10]).n(&[-50, -50, -50, -100]).op(&[31]).n(&[-107]).op(&[29]),
+        // a width in front of a horizontal move, flex and a fractional line
+        CharString::default().n(&[300, 50]).op(&[22]).n(&[50, 0, 50, 50, 50, 0, 50, 0, 50, -50, 50, 0, 10]).op(&[12, 35])
+            .n(&[0, 100]).op(&[5]).fixed(-300.5).op(&[6]).op(&[14]),
+    ];
+    let local_subrs = cff_index(&[CharString::default().n(&[0, 100, 100, 100, 100, 0]).op(&[8, 11]).0]);
+    let global_subrs = cff_index(&[CharString::default().n(&[-100, 0]).op(&[5, 14]).0]);
+    let char_strings = cff_index(&char_strings.map(|c| c.0));
+    let header = [1, 0, 4, 4];
+    let names = cff_index(&[b"Test".to_vec()]);
+    let strings = cff_index(&[]);
+    // the top dict is always 17 bytes, which puts its index at 28 bytes
+    let char_strings_offset = header.len() + names.len() + 28 + strings.len() + global_subrs.len();
+    let private_offset = char_strings_offset + char_strings.len();
+    let mut private = dict_int(6);
+    private.push(19);
+    let mut top = dict_int(char_strings_offset);
+    top.push(17);
+    top.extend(dict_int(private.len()));
+    top.extend(dict_int(private_offset));
+    top.push(18);
+    let top = cff_index(&[top]);
+    assert_eq!(top.len(), 28);
+    let cff = [&header[..], &names, &top, &strings, &global_subrs, &char_strings, &private, &local_subrs].concat();
+
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+    let maxp = u16s(&[0, 0x5000, 4]);
+    let mut hhea = vec![0; 36];
+    hhea[4..10].copy_from_slice(&u16s(&[800, (-200i16) as u16, 0]));
+    hhea[34..36].copy_from_slice(&2u16.to_be_bytes());
+    let hmtx = u16s(&[500, 100, 600, 50, 20, 30]);
+    // format 4 maps a to c, format 12 maps the same and the emoji, and is the one that's used
+    let format4 = u16s(&[4, 32, 0, 4, 0, 0, 0, 0x63, 0xFFFF, 0, 0x61, 0xFFFF, 1u16.wrapping_sub(0x61), 1, 0, 0]);
+    let mut format12 = u16s(&[12, 0]);
+    format12.extend(40u32.to_be_bytes());
+    format12.extend(0u32.to_be_bytes());
+    format12.extend(2u32.to_be_bytes());
+    for (start, end, glyph) in [(0x61u32, 0x63u32, 1u32), (0x1F600, 0x1F600, 3)] {
+        format12.extend([start, end, glyph].iter().flat_map(|value| value.to_be_bytes()));
+    }
+    let mut cmap = u16s(&[0, 2, 3, 1, 0, 20, 3, 10]);
+    cmap.extend((20 + format4.len() as u32).to_be_bytes());
+    cmap.extend(format4);
+    cmap.extend(format12);
+
+    sfnt(b"OTTO", &[(b"CFF ", cff), (b"cmap", cmap), (b"head", head), (b"hhea", hhea), (b"hmtx", hmtx), (b"maxp", maxp)])
+}
+
+#[test]
+fn truetype_metrics_and_character_map() {
+    let Some(data) = dejavu() else { return };
+    let font = Font::parse(&data).unwrap();
+    assert_eq!(font.units_per_em(), 2048);
+    assert_eq!(font.glyph_count(), 3377);
+    assert_eq!((font.ascender(), font.descender(), font.line_gap()), (1901, -483, 0));
+    assert_eq!(font.glyph_index('A'), Some(36));
+    assert_eq!(font.glyph_index(' '), Some(3));
+    assert_eq!(font.glyph_index('€'), Some(1916));
+    assert_eq!(font.glyph_index('\u{1F600}'), None);
+    assert_eq!(font.metrics(36), Some(HorizontalMetrics { advance_width: 1233, left_side_bearing: 37 }));
+    // a monospaced font advances the same for every character
+    for c in ' '..='~' {
+        assert_eq!(font.metrics(font.glyph_index(c).unwrap()).unwrap().advance_width, 1233);
+    }
+    assert_eq!(font.metrics(3377), None);
+    assert_eq!(font.outline::<f64>(3377), Err(FontError::GlyphOutOfRange(3377)));
+}
+
+#[test]
+fn truetype_outlines() {
+    let Some(data) = dejavu() else { return };
+    let font = Font::parse(&data).unwrap();
+    for index in 0..font.glyph_count() {
+        let glyph = font.glyph::<f64>(index).unwrap();
+        assert!(glyph.shape.is_closed());
+        // the side bearing is the left of the control points, up to rounding
+        let left = glyph.shape.edges().flat_map(|edge| edge.points().to_vec()).map(|p| p.x).fold(f64::MAX, f64::min);
+        if left != f64::MAX {
+            assert!((left - glyph.metrics.left_side_bearing as f64).abs() <= 1.0, "glyph {}", index);
+        }
+    }
+    let space = font.glyph::<f32>(3).unwrap();
+    assert!(space.shape.contours().is_empty());
+    // the outside goes clockwise and holes go the other way
+    let o = font.outline::<f64>(font.glyph_index('O').unwrap()).unwrap();
+    assert_eq!(o.contours().len(), 2);
+    let orientations: Vec<_> = o.contours().iter().map(|contour| contour.orientation()).collect();
+    assert!(orientations.contains(&Orientation::Clockwise) && orientations.contains(&Orientation::CounterClockwise));
+    assert!(!o.contains(Vector2::new(616.0, 700.0), FillRule::NonZero));
+    assert!(o.contains(Vector2::new(200.0, 700.0), FillRule::NonZero));
+    assert!(!o.contains(Vector2::new(50.0, 700.0), FillRule::NonZero));
+    // a composite glyph has the contours of its components
+    let a = edges(&font.outline(font.glyph_index('A').unwrap()).unwrap());
+    let a_acute = font.outline::<f64>(font.glyph_index('Á').unwrap()).unwrap();
+    assert_eq!(a_acute.contours().len(), 3);
+    let accented = edges(&a_acute);
+    assert!(a.iter().all(|edge| accented.contains(edge)));
+}
+
+#[test]
+fn cff_outlines() {
+    let data = cff_font();
+    let font = Font::parse(&data).unwrap();
+    assert_eq!(font.units_per_em(), 1000);
+    assert_eq!(font.glyph_count(), 4);
+    assert_eq!(font.glyph_index('b'), Some(2));
+    assert_eq!(font.glyph_index('\u{1F600}'), Some(3));
+    assert_eq!(font.glyph_index('d'), None);
+    assert_eq!(font.metrics(1), Some(HorizontalMetrics { advance_width: 600, left_side_bearing: 50 }));
+    assert_eq!(font.metrics(3), Some(HorizontalMetrics { advance_width: 600, left_side_bearing: 30 }));
+    assert!(font.outline::<f64>(0).unwrap().contours().is_empty());
+    let square = font.outline::<f64>(1).unwrap();
+    assert_eq!(edges(&square), vec![
+        line((100.0, 100.0), (400.0, 100.0)),
+        line((400.0, 100.0), (400.0, 400.0)),
+        line((400.0, 400.0), (100.0, 400.0)),
+        line((100.0, 400.0), (100.0, 100.0)),
+    ]);
+    assert_eq!(square.signed_area(), 90000.0);
+    assert_eq!(edges(&font.outline(2).unwrap()), vec![
+        cubic((100.0, 100.0), (100.0, 200.0), (200.0, 300.0), (300.0, 300.0)),
+        cubic((300.0, 300.0), (250.0, 300.0), (200.0, 250.0), (200.0, 150.0)),
+        line((200.0, 150.0), (100.0, 150.0)),
+        line((100.0, 150.0), (100.0, 100.0)),
+    ]);
+    assert_eq!(edges(&font.outline(3).unwrap()), vec![
+        cubic((50.0, 0.0), (100.0, 0.0), (150.0, 50.0), (200.0, 50.0)),
+        cubic((200.0, 50.0), (250.0, 50.0), (300.0, 0.0), (350.0, 0.0)),
+        line((350.0, 0.0), (350.0, 100.0)),
+        line((350.0, 100.0), (49.5, 100.0)),
+        line((49.5, 100.0), (50.0, 0.0)),
+    ]);
+    assert_eq!(font.outline::<f64>(4), Err(FontError::GlyphOutOfRange(4)));
+}
+
+#[test]
+fn broken_fonts_are_errors() {
+    assert_eq!(Font::parse(b"not a font").err(), Some(FontError::UnknownFormat));
+    assert_eq!(Font::parse(&sfnt(b"OTTO", &[])).err(), Some(FontError::MissingTable(*b"head")));
+    let dejavu = dejavu();
+    if let Some(dejavu) = &dejavu {
+        assert_eq!(Font::parse(&dejavu[..8]).err(), Some(FontError::UnexpectedEnd));
+        assert_eq!(Font::parse_collection(dejavu, 0).err(), Some(FontError::UnknownFormat));
+    }
+    // cutting or scrambling the data never panics
+    let cff = cff_font();
+    for data in [Some(&cff[..]), dejavu.as_deref()].into_iter().flatten() {
+        for len in (0..data.len()).step_by(data.len() / 300 + 1) {
+            if let Ok(font) = Font::parse(&data[..len]) {
+                for glyph in 0..font.glyph_count().min(200) {
+                    let _ = font.outline::<f32>(glyph);
+                }
+            }
+        }
+    }
+    // offsets in the top dict that are negative or too big to be offsets
+    let top = cff.windows(6).position(|w| w[0] == 29 && w[5] == 17).unwrap();
+    let mut data = cff.clone();
+    data[top + 1..top + 5].copy_from_slice(&(-1i32).to_be_bytes());
+    assert_eq!(Font::parse(&data).err(), Some(FontError::UnexpectedEnd));
+    let private = cff.windows(6).position(|w| w[0] == 29 && w[5] == 18).unwrap();
+    let mut data = cff.clone();
+    // 100E28 written as a real number
+    data[private..private + 5].copy_from_slice(&[30, 0x10, 0x0B, 0x28, 0xFF]);
+    assert_eq!(Font::parse(&data).err(), Some(FontError::UnexpectedEnd));
+    let mut rng = Lcg(1);
+    for _ in 0..20 {
+        let mut data = cff.clone();
+        for _ in 0..10 {
+            let i = rng.below(data.len());
+            data[i] = rng.below(256) as u8;
+        }
+        if let Ok(font) = Font::parse(&data) {
+            for glyph in 0..font.glyph_count() {
+                let _ = font.outline::<f64>(glyph);
+                let _ = font.metrics(glyph);
+            }
+            let _ = font.glyph_index('a');
+        }
+    }
+    if let Some(mut data) = dejavu {
+        for _ in 0..2000 {
+            let i = rng.below(data.len());
+            data[i] = rng.below(256) as u8;
+        }
+        if let Ok(font) = Font::parse(&data) {
+            for glyph in 0..font.glyph_count() {
+                let _ = font.outline::<f64>(glyph);
+            }
+        }
+    }
+}
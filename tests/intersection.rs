@@ -0,0 +1,217 @@
+use drowsed_math::{CubicSegment, Intersect, LinearSegment, QuadraticSegment, RayCrossings, Segment, Vector, Vector2};
+
+mod common;
+use common::Lcg;
+
+/// finds the intersections by splitting both segments into many lines.
+fn brute_force<A: Segment<VectorType = Vector2<f64>>, B: Segment<VectorType = Vector2<f64>>>(a: &A, b: &B) -> Vec<Vector2<f64>> {
+    const STEPS: usize = 400;
+    let polyline = |s: &dyn Fn(f64) -> Vector2<f64>| (0..=STEPS).map(|i| s(i as f64 / STEPS as f64)).collect::<Vec<_>>();
+    let pa = polyline(&|t| a.interpolate(t));
+    let pb = polyline(&|t| b.interpolate(t));
+    let mut points = Vec::new();
+    for i in 0..STEPS {
+        for j in 0..STEPS {
+            let line = LinearSegment::new(pa[i], pa[i + 1]);
+            for intersection in line.intersect(&LinearSegment::new(pb[j], pb[j + 1])) {
+                points.push(intersection.point);
+            }
+        }
+    }
+    points
+}
+
+fn assert_on_both<A: Segment<VectorType = Vector2<f64>>, B: Segment<VectorType = Vector2<f64>>>(a: &A, b: &B, t0: f64, t1: f64) {
+    let (p, q) = (a.interpolate(t0), b.interpolate(t1));
+    assert!((p - q).length() < 1e-9, "{:?} and {:?} are {} apart", p, q, (p - q).length());
+}
+
+#[test]
+fn line_line() {
+    let a = LinearSegment::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 2.0));
+    let b = LinearSegment::new(Vector2::new(0.0, 2.0), Vector2::new(2.0, 0.0));
+    let intersections = a.intersect(&b);
+    assert_eq!(intersections.len(), 1);
+    assert_eq!((intersections[0].t0, intersections[0].t1), (0.5, 0.5));
+    assert_eq!(intersections[0].point, Vector2::new(1.0, 1.0));
+    // parallel lines never meet
+    let c = LinearSegment::new(Vector2::new(0.0, 1.0), Vector2::new(2.0, 3.0));
+    assert!(a.intersect(&c).is_empty());
+    // lines on top of each other meet at the ends of the overlap
+    let d = LinearSegment::new(Vector2::new(3.0, 3.0), Vector2::new(1.0, 1.0));
+    let intersections = a.intersect(&d);
+    assert_eq!(intersections.len(), 2);
+    assert_eq!((intersections[0].t0, intersections[0].t1), (0.5, 1.0));
+    assert_eq!((intersections[1].t0, intersections[1].t1), (1.0, 0.5));
+    // lines that would meet past their ends
+    let e = LinearSegment::new(Vector2::new(3.0, 0.0), Vector2::new(4.0, -1.0));
+    assert!(a.intersect(&e).is_empty());
+}
+
+#[test]
+fn line_curve_matches_brute_force() {
+    let mut rng = Lcg(37);
+    for _ in 0..100 {
+        let line = LinearSegment::new(rng.vector2(), rng.vector2());
+        let quadratic = QuadraticSegment::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let intersections = line.intersect(&quadratic);
+        assert_eq!(intersections.len(), brute_force(&line, &quadratic).len());
+        for intersection in intersections.iter() {
+            assert_on_both(&line, &quadratic, intersection.t0, intersection.t1);
+        }
+        let intersections = line.intersect(&cubic);
+        assert_eq!(intersections.len(), brute_force(&line, &cubic).len());
+        for intersection in intersections.iter() {
+            assert_on_both(&line, &cubic, intersection.t0, intersection.t1);
+        }
+        // swapping the segments swaps the parameters
+        let mut swapped = cubic.intersect(&line);
+        swapped.sort_by(|a, b| a.t1.partial_cmp(&b.t1).unwrap());
+        assert_eq!(swapped.len(), intersections.len());
+        for (a, b) in swapped.iter().zip(intersections.iter()) {
+            assert_eq!((a.t0, a.t1), (b.t1, b.t0));
+        }
+    }
+    // a line touching a curve
+    let quadratic = QuadraticSegment::new(Vector2::new(-1.0f64, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0));
+    let line = LinearSegment::new(Vector2::new(-1.0, 0.0), Vector2::new(1.0, 0.0));
+    let intersections = line.intersect(&quadratic);
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].t1 - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn curve_curve_matches_brute_force() {
+    let mut rng = Lcg(41);
+    for _ in 0..40 {
+        let quadratic = QuadraticSegment::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let a = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let b = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let intersections = a.intersect(&b);
+        let expected = brute_force(&a, &b);
+        assert_eq!(intersections.len(), expected.len());
+        for intersection in intersections.iter() {
+            assert_on_both(&a, &b, intersection.t0, intersection.t1);
+            assert!(expected.iter().any(|p| (*p - intersection.point).length() < 1e-3));
+        }
+        let intersections = quadratic.intersect(&b);
+        assert_eq!(intersections.len(), brute_force(&quadratic, &b).len());
+        for intersection in intersections.iter() {
+            assert_on_both(&quadratic, &b, intersection.t0, intersection.t1);
+        }
+    }
+}
+
+#[test]
+fn curve_curve_with_many_intersections() {
+    // two s shaped curves crossing each other 3 times
+    let a = CubicSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 3.0), Vector2::new(2.0, -3.0), Vector2::new(3.0, 0.0));
+    let b = CubicSegment::new(Vector2::new(0.0, 0.3), Vector2::new(1.0, -3.0), Vector2::new(2.0, 3.0), Vector2::new(3.0, -0.3));
+    let intersections = a.intersect(&b);
+    assert_eq!(intersections.len(), 3);
+    for intersection in intersections.iter() {
+        assert_on_both(&a, &b, intersection.t0, intersection.t1);
+    }
+    // a parabola and its mirror meet at the origin and at (1, 1)
+    let a = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(0.5, 0.0), Vector2::new(1.0, 1.0));
+    let b = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(0.0, 0.5), Vector2::new(1.0, 1.0));
+    let intersections = a.intersect(&b);
+    assert_eq!(intersections.len(), 2);
+    assert!(intersections[0].point.length() < 1e-9);
+    assert!((intersections[1].point - Vector2::new(1.0, 1.0)).length() < 1e-9);
+}
+
+#[test]
+fn overlapping_curves_meet_at_the_ends_of_the_overlap() {
+    let cubic = CubicSegment::new(Vector2::new(0.0f64, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, -1.0), Vector2::new(3.0, 1.0));
+    let part = cubic.subsegment(0.25, 0.75);
+    let intersections = cubic.intersect(&part);
+    assert_eq!(intersections.len(), 2);
+    assert!((intersections[0].t0 - 0.25).abs() < 1e-6 && intersections[0].t1.abs() < 1e-6);
+    assert!((intersections[1].t0 - 0.75).abs() < 1e-6 && (intersections[1].t1 - 1.0).abs() < 1e-6);
+    // a quadratic overlaps itself elevated into a cubic
+    let quadratic = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0));
+    let intersections = quadratic.intersect(&quadratic.elevate());
+    assert_eq!(intersections.len(), 2);
+}
+
+/// a circle of radius 1 around the origin going counter clockwise made from 4 cubics.
+fn circle() -> Vec<CubicSegment<Vector2<f64>>> {
+    let k = 0.5522847498;
+    let mut segments = Vec::new();
+    let mut rotate = |p: Vector2<f64>| Vector2::new(-p.y, p.x);
+    let mut points = [Vector2::new(1.0, 0.0), Vector2::new(1.0, k), Vector2::new(k, 1.0), Vector2::new(0.0, 1.0)];
+    for _ in 0..4 {
+        segments.push(CubicSegment::new(points[0], points[1], points[2], points[3]));
+        points = points.map(&mut rotate);
+    }
+    segments
+}
+
+fn winding(segments: &[CubicSegment<Vector2<f64>>], origin: Vector2<f64>, direction: Vector2<f64>) -> i32 {
+    segments.iter().flat_map(|segment| segment.ray_crossings(origin, direction)).map(|crossing| crossing.winding).sum()
+}
+
+#[test]
+fn ray_crossings_give_the_winding_number() {
+    let segments = circle();
+    let directions = [Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0), Vector2::new(-1.0, 0.0), Vector2::new(0.3, -0.7)];
+    for direction in directions {
+        // the ray going through the shared endpoints of the segments must only count them once
+        assert_eq!(winding(&segments, Vector2::new(0.0, 0.0), direction), 1);
+        assert_eq!(winding(&segments, Vector2::new(0.5, -0.25), direction), 1);
+        assert_eq!(winding(&segments, Vector2::new(2.0, 0.0), direction), 0);
+        // going around the other way
+        let reversed: Vec<_> = segments.iter().map(|segment| segment.reverse()).collect();
+        assert_eq!(winding(&reversed, Vector2::new(0.0, 0.0), direction), -1);
+    }
+    // rays only touching the circle at the top don't cross it
+    assert_eq!(winding(&segments, Vector2::new(-2.0, 1.0), Vector2::new(1.0, 0.0)), 0);
+    let crossings: Vec<_> = segments.iter().flat_map(|segment| segment.ray_crossings(Vector2::new(-2.0, 1.0), Vector2::new(1.0, 0.0))).collect();
+    assert!(!crossings.is_empty() && crossings.iter().all(|crossing| (crossing.point - Vector2::new(0.0, 1.0)).length() < 1e-6));
+    let crossings = segments[0].ray_crossings(Vector2::new(0.0, 0.5), Vector2::new(2.0, 0.0));
+    assert_eq!(crossings.len(), 1);
+    assert_eq!(crossings[0].winding, 1);
+    assert!((crossings[0].distance * 2.0 - 0.75f64.sqrt()).abs() < 1e-3);
+    // crossings behind the origin of the ray are left out
+    assert!(segments[0].ray_crossings(Vector2::new(2.0, 0.5), Vector2::new(1.0, 0.0)).is_empty());
+}
+
+#[test]
+fn ray_crossings_of_lines() {
+    let line = LinearSegment::new(Vector2::new(1.0, -1.0), Vector2::new(1.0, 1.0));
+    let crossings = line.ray_crossings(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    assert_eq!(crossings.len(), 1);
+    assert_eq!((crossings[0].t, crossings[0].distance, crossings[0].winding), (0.5, 1.0, 1));
+    let crossings = line.reverse().ray_crossings(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+    assert_eq!(crossings[0].winding, -1);
+    // a line lying along the ray doesn't cross it
+    let line = LinearSegment::new(Vector2::new(1.0, 0.0), Vector2::new(2.0, 0.0));
+    assert!(line.ray_crossings(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0)).is_empty());
+}
+
+#[test]
+fn coordinates_that_are_not_numbers() {
+    let mut rng = Lcg(43);
+    for _ in 0..20 {
+        let nan = Vector2::new(f64::NAN, rng.next());
+        let infinite = Vector2::new(rng.next(), f64::INFINITY);
+        let line = LinearSegment::new(rng.vector2(), rng.vector2());
+        let cubic = CubicSegment::new(rng.vector2(), nan, rng.vector2(), rng.vector2());
+        let quadratic = QuadraticSegment::new(rng.vector2(), rng.vector2(), infinite);
+        // nothing panics and every intersection that is returned has parameters that are numbers
+        let intersections = [
+            line.intersect(&cubic),
+            cubic.intersect(&line),
+            quadratic.intersect(&cubic),
+            cubic.intersect(&cubic),
+            LinearSegment::new(nan, rng.vector2()).intersect(&quadratic),
+        ];
+        for intersection in intersections.iter().flatten() {
+            assert!(intersection.t0.is_finite() && intersection.t1.is_finite());
+        }
+        let _ = cubic.ray_crossings(rng.vector2(), rng.vector2());
+        let _ = line.ray_crossings(nan, rng.vector2());
+    }
+}
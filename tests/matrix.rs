@@ -0,0 +1,116 @@
+use drowsed_math::{Matrix2, Matrix3, Matrix4, SquareMatrix, Vector3};
+
+mod common;
+use common::Lcg;
+
+fn random_matrix2(rng: &mut Lcg) -> Matrix2<f64> {
+    Matrix2::new(rng.next(), rng.next(), rng.next(), rng.next())
+}
+fn random_matrix3(rng: &mut Lcg) -> Matrix3<f64> {
+    Matrix3::new(rng.next(), rng.next(), rng.next(), rng.next(), rng.next(), rng.next(), rng.next(), rng.next(), rng.next())
+}
+fn random_matrix4(rng: &mut Lcg) -> Matrix4<f64> {
+    Matrix4::new(
+        rng.next(), rng.next(), rng.next(), rng.next(),
+        rng.next(), rng.next(), rng.next(), rng.next(),
+        rng.next(), rng.next(), rng.next(), rng.next(),
+        rng.next(), rng.next(), rng.next(), rng.next(),
+    )
+}
+fn elements2(m: &Matrix2<f64>) -> Vec<f64> {
+    vec![m.x.x, m.x.y, m.y.x, m.y.y]
+}
+fn elements3(m: &Matrix3<f64>) -> Vec<f64> {
+    [m.x, m.y, m.z].iter().flat_map(|v| [v.x, v.y, v.z]).collect()
+}
+fn elements4(m: &Matrix4<f64>) -> Vec<f64> {
+    [m.x, m.y, m.z, m.w].iter().flat_map(|v| [v.x, v.y, v.z, v.w]).collect()
+}
+/// the largest difference between the elements of two matrices.
+fn difference(a: Vec<f64>, b: Vec<f64>) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max)
+}
+/// the determinant by expanding along the first row.
+fn cofactor_determinant(m: &[Vec<f64>]) -> f64 {
+    if m.len() == 1 {
+        return m[0][0];
+    }
+    (0..m.len()).map(|column| {
+        let minor: Vec<Vec<f64>> = m[1..].iter().map(|row| row.iter().enumerate().filter(|(i, _)| *i != column).map(|(_, v)| *v).collect()).collect();
+        let sign = if column % 2 == 0 { 1.0 } else { -1.0 };
+        sign * m[0][column] * cofactor_determinant(&minor)
+    }).sum()
+}
+fn rows(elements: Vec<f64>, size: usize) -> Vec<Vec<f64>> {
+    elements.chunks(size).map(|row| row.to_vec()).collect()
+}
+
+#[test]
+fn determinants() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (a, b, c) = (random_matrix2(&mut rng), random_matrix3(&mut rng), random_matrix4(&mut rng));
+        assert!((a.determinant() - cofactor_determinant(&rows(elements2(&a), 2))).abs() < 1e-12);
+        assert!((b.determinant() - cofactor_determinant(&rows(elements3(&b), 3))).abs() < 1e-12);
+        assert!((c.determinant() - cofactor_determinant(&rows(elements4(&c), 4))).abs() < 1e-12);
+        // the determinant does not change when transposing and multiplies with products
+        assert!((c.transpose().determinant() - c.determinant()).abs() < 1e-12);
+        let d = random_matrix4(&mut rng);
+        assert!(((c * d).determinant() - c.determinant() * d.determinant()).abs() < 1e-12);
+        // the adjugate times the matrix is the determinant on the diagonal
+        let scaled = elements4(&Matrix4::identity()).iter().map(|v| v * c.determinant()).collect();
+        assert!(difference(elements4(&(c * c.adjugate())), scaled) < 1e-12);
+        let scaled = elements3(&Matrix3::identity()).iter().map(|v| v * b.determinant()).collect();
+        assert!(difference(elements3(&(b.adjugate() * b)), scaled) < 1e-12);
+    }
+    assert_eq!(Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 10).determinant(), -3);
+    assert_eq!(Matrix2::new(1, 2, 3, 4).trace(), 5);
+}
+
+#[test]
+fn inverses() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let a = random_matrix2(&mut rng);
+        let inverse = a.try_inverse().unwrap();
+        assert!(difference(elements2(&(a * inverse)), elements2(&Matrix2::identity())) < 1e-9);
+        assert!(difference(elements2(&(inverse * a)), elements2(&Matrix2::identity())) < 1e-9);
+
+        let b = random_matrix3(&mut rng);
+        let inverse = b.try_inverse().unwrap();
+        assert!(difference(elements3(&(b * inverse)), elements3(&Matrix3::identity())) < 1e-9);
+        assert!(difference(elements3(&(inverse * b)), elements3(&Matrix3::identity())) < 1e-9);
+
+        let c = random_matrix4(&mut rng);
+        let inverse = c.try_inverse().unwrap();
+        assert!(difference(elements4(&(c * inverse)), elements4(&Matrix4::identity())) < 1e-9);
+        assert!(difference(elements4(&(inverse * c)), elements4(&Matrix4::identity())) < 1e-9);
+    }
+    // matrices with rows that depend on each other can not be inverted
+    assert!(Matrix2::new(1.0, 2.0, 2.0, 4.0).try_inverse().is_none());
+    assert!(Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 5.0, 7.0, 9.0).try_inverse().is_none());
+    let singular = Matrix4::new(
+        1.0, 2.0, 3.0, 4.0,
+        2.0, 4.0, 6.0, 8.0,
+        0.0, 1.0, 0.0, 1.0,
+        5.0, 0.0, 2.0, 1.0,
+    );
+    assert!(singular.try_inverse().is_none());
+    assert!(Matrix4::<f64>::from(0.0).try_inverse().is_none());
+    assert!(Matrix4::from_scale(Vector3::new(1.0, 0.0, 1.0)).try_inverse_affine().is_none());
+}
+
+#[test]
+fn affine_inverses() {
+    let mut rng = Lcg(3);
+    for _ in 0..100 {
+        let rotation = Matrix4::from(rng.rotation());
+        let scale = Matrix4::from_scale(rng.vector3() + 1.5);
+        let translation = Matrix4::from_translation(rng.vector3() * 10.0);
+        let affine = translation * rotation * scale;
+        let fast = affine.try_inverse_affine().unwrap();
+        let general = affine.try_inverse().unwrap();
+        assert!(difference(elements4(&fast), elements4(&general)) < 1e-9);
+        assert!(difference(elements4(&(affine * fast)), elements4(&Matrix4::identity())) < 1e-9);
+    }
+}
@@ -0,0 +1,161 @@
+use drowsed_math::msdf::{color_edges, generate_msdf, generate_mtsdf, generate_pseudo_sdf, generate_sdf, Bitmap, EdgeColor, FieldOptions};
+use drowsed_math::{Contour, CubicSegment, FillRule, LinearSegment, QuadraticSegment, Shape, Vector2};
+
+fn polygon(points: &[(f64, f64)]) -> Contour<f64> {
+    let mut contour = Contour::new();
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        contour.push(LinearSegment::new(Vector2::new(a.0, a.1), Vector2::new(b.0, b.1)));
+    }
+    contour
+}
+
+fn circle() -> Contour<f64> {
+    let k = 0.5522847498;
+    let mut contour = Contour::new();
+    let mut points = [Vector2::new(1.0, 0.0), Vector2::new(1.0, k), Vector2::new(k, 1.0), Vector2::new(0.0, 1.0)];
+    for _ in 0..4 {
+        contour.push(CubicSegment::new(points[0], points[1], points[2], points[3]));
+        points = points.map(|p| Vector2::new(-p.y, p.x));
+    }
+    contour
+}
+
+fn median(pixel: &[f32]) -> f32 {
+    pixel[0].max(pixel[1]).min(pixel[0].min(pixel[1]).max(pixel[2]))
+}
+
+/// samples the field between pixel centers the way a gpu would.
+fn bilinear<const N: usize>(bitmap: &Bitmap<[f32; N]>, options: &FieldOptions<f64>, p: Vector2<f64>) -> [f32; N] {
+    let u = (p.x + options.translate.x) * options.scale.x - 0.5;
+    let v = (p.y + options.translate.y) * options.scale.y - 0.5;
+    let (x, y) = (u.floor().max(0.0) as usize, v.floor().max(0.0) as usize);
+    let (x, y) = (x.min(bitmap.width() - 2), y.min(bitmap.height() - 2));
+    let (fx, fy) = ((u - x as f64) as f32, (v - y as f64) as f32);
+    let mut result = [0.0; N];
+    for (i, value) in result.iter_mut().enumerate() {
+        let bottom = bitmap.get(x, y)[i] * (1.0 - fx) + bitmap.get(x + 1, y)[i] * fx;
+        let top = bitmap.get(x, y + 1)[i] * (1.0 - fx) + bitmap.get(x + 1, y + 1)[i] * fx;
+        *value = bottom * (1.0 - fy) + top * fy;
+    }
+    result
+}
+
+#[test]
+fn bitmap_layout() {
+    let mut bitmap = Bitmap::<f32>::new(3, 2);
+    bitmap.set(2, 1, 1.0);
+    assert_eq!(bitmap.get(2, 1), 1.0);
+    assert_eq!(bitmap.pixels()[5], 1.0);
+    assert_eq!(bitmap.into_pixels().len(), 6);
+}
+
+#[test]
+fn corners_get_different_colors() {
+    let shape = Shape::from_contours(vec![polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.5, 1.5), (0.0, 1.0)])]);
+    for seed in 0..10 {
+        let colored = color_edges(&shape, 3.0, seed);
+        let colors = &colored.colors()[0];
+        assert_eq!(colors.len(), 5);
+        for i in 0..colors.len() {
+            let (a, b) = (colors[i], colors[(i + 1) % colors.len()]);
+            // neighbouring edges share at most one channel and every edge has at least two
+            assert!((a.bits() & b.bits()).count_ones() <= 1, "{:?} {:?}", a, b);
+            assert!(a.bits().count_ones() >= 2);
+        }
+    }
+    // smooth contours only need a single color
+    let colored = color_edges(&Shape::from_contours(vec![circle()]), 3.0, 0);
+    assert!(colored.colors()[0].iter().all(|color| *color == colored.colors()[0][0]));
+    assert_ne!(colored.colors()[0][0], EdgeColor::White);
+}
+
+#[test]
+fn teardrops_are_split_into_thirds() {
+    // a single cubic looping back to where it started, making one corner
+    let mut contour = Contour::new();
+    contour.push(CubicSegment::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 1.0), Vector2::new(2.0, -1.0), Vector2::new(0.0, 0.0)));
+    let colored = color_edges(&Shape::from_contours(vec![contour]), 3.0, 0);
+    let contour = &colored.shape().contours()[0];
+    assert_eq!(contour.edges().len(), 3);
+    assert!(contour.is_closed());
+    let colors = &colored.colors()[0];
+    assert_eq!(colors[1], EdgeColor::White);
+    assert_ne!(colors[0], colors[2]);
+    // a quadratic and a line with one corner between them
+    let mut contour = Contour::new();
+    contour.push(QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0)));
+    contour.push(LinearSegment::new(Vector2::new(2.0, 0.0), Vector2::new(0.0, 0.0)));
+    let colored = color_edges(&Shape::from_contours(vec![contour]), 3.0, 0);
+    assert_eq!(colored.colors()[0].len(), 2);
+    assert_ne!(colored.colors()[0][0], colored.colors()[0][1]);
+}
+
+#[test]
+fn sdf_matches_distance() {
+    let square = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+    let mut reversed = square.clone();
+    reversed.reverse();
+    for contour in [square, reversed] {
+        let shape = Shape::from_contours(vec![contour]);
+        let options = FieldOptions::fit(Vector2::new(-2.0, -2.0), Vector2::new(2.0, 2.0), 16, 16, 1.0);
+        assert_eq!(options.project(0, 0), Vector2::new(-1.875, -1.875));
+        let sdf = generate_sdf(&shape, 16, 16, &options);
+        for y in 0..16 {
+            for x in 0..16 {
+                let p = options.project(x, y);
+                let expected = shape.signed_distance(p, FillRule::NonZero) + 0.5;
+                assert!((sdf.get(x, y) as f64 - expected).abs() < 1e-6);
+                assert_eq!(sdf.get(x, y) > 0.5, shape.contains(p, FillRule::NonZero));
+            }
+        }
+        // outside of the corner the pseudo distance goes to the lines through the edges
+        let pseudo = generate_pseudo_sdf(&shape, 16, 16, &options);
+        let p = options.project(13, 13);
+        assert_eq!(p, Vector2::new(1.375, 1.375));
+        assert!((pseudo.get(13, 13) - (0.5 - 0.375)).abs() < 1e-6);
+        assert!((sdf.get(13, 13) as f64 - (0.5 - 0.375 * 2f64.sqrt())).abs() < 1e-6);
+        assert!((pseudo.get(8, 8) - sdf.get(8, 8)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn msdf_keeps_corners_sharp() {
+    let square = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+    let triangle = polygon(&[(0.0, 0.0), (0.6, 0.0), (0.0, 0.6)]);
+    let mut hole = triangle.clone();
+    hole.reverse();
+    let shape = Shape::from_contours(vec![square, hole]);
+    let colored = color_edges(&shape, 3.0, 0);
+    let options = FieldOptions::fit(Vector2::new(-1.5, -1.5), Vector2::new(1.5, 1.5), 24, 24, 0.5);
+    let msdf = generate_msdf(&colored, 24, 24, &options);
+    let mtsdf = generate_mtsdf(&colored, 24, 24, &options);
+    let sdf = generate_sdf(&shape, 24, 24, &options);
+    for y in 0..24 {
+        for x in 0..24 {
+            let pixel = msdf.get(x, y);
+            assert_eq!(&mtsdf.get(x, y)[..3], &pixel[..]);
+            assert_eq!(mtsdf.get(x, y)[3], sdf.get(x, y));
+            assert_eq!(median(&pixel) > 0.5, shape.contains(options.project(x, y), FillRule::NonZero));
+        }
+    }
+    // sampling between the pixels gives the right shape everywhere but right next to the edges,
+    // including around the corners where a single channel field would round them off
+    let single = Bitmap::from_pixels(24, 24, sdf.pixels().iter().map(|v| [*v]).collect());
+    let mut sdf_mistakes = 0;
+    for i in 0..=300 {
+        for j in 0..=300 {
+            let p = Vector2::new(-1.4 + 2.8 * i as f64 / 300.0, -1.4 + 2.8 * j as f64 / 300.0);
+            let distance = shape.signed_distance(p, FillRule::NonZero);
+            if distance.abs() < 0.01 {
+                continue;
+            }
+            let inside = distance > 0.0;
+            assert_eq!(median(&bilinear(&msdf, &options, p)) > 0.5, inside, "{:?}", p);
+            if (bilinear(&single, &options, p)[0] > 0.5) != inside {
+                sdf_mistakes += 1;
+            }
+        }
+    }
+    assert!(sdf_mistakes > 0);
+}
@@ -0,0 +1,150 @@
+use drowsed_math::complex::Complex;
+use drowsed_math::equations::Polynomial;
+
+mod common;
+use common::Lcg;
+
+/// a polynomial with a degree from low up to and including high.
+fn random_polynomial(rng: &mut Lcg, low: usize, high: usize) -> Polynomial<f64> {
+    let degree = low + rng.below(high - low + 1);
+    let mut coefficients: Vec<f64> = (0..=degree).map(|_| rng.next() * 2.0).collect();
+    coefficients[degree] += if coefficients[degree] < 0.0 { -0.5 } else { 0.5 };
+    Polynomial::new(coefficients)
+}
+fn assert_polynomials_close(a: &Polynomial<f64>, b: &Polynomial<f64>, tolerance: f64) {
+    assert_eq!(a.degree(), b.degree(), "{:?} {:?}", a, b);
+    for (x, y) in a.coefficients().iter().zip(b.coefficients()) {
+        assert!((x - y).abs() < tolerance * y.abs().max(1.0), "{:?} {:?}", a, b);
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let p = random_polynomial(&mut rng, 2, 7);
+        let divisor = random_polynomial(&mut rng, 1, 4);
+        // the quotient times the divisor plus the remainder gives back the polynomial
+        let (quotient, remainder) = p.div_rem(&divisor);
+        assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+        assert_polynomials_close(&(&(&quotient * &divisor) + &remainder), &p, 1e-9);
+        // dividing a product leaves no remainder
+        let (quotient, remainder) = (&p * &divisor).div_rem(&divisor);
+        assert_polynomials_close(&quotient, &p, 1e-9);
+        assert!(remainder.coefficients().iter().all(|c| c.abs() < 1e-9));
+
+        // composing evaluates one polynomial at the other
+        let inner = random_polynomial(&mut rng, 1, 3);
+        let composed = p.compose(&inner);
+        assert_eq!(composed.degree(), p.degree() * inner.degree());
+        for _ in 0..10 {
+            let x = rng.next() * 2.0;
+            let expected = p.evaluate(inner.evaluate(x));
+            assert!((composed.evaluate(x) - expected).abs() < 1e-9 * expected.abs().max(1.0));
+        }
+        // the derivative of the antiderivative is the polynomial
+        assert_polynomials_close(&p.antiderivative(3.0).derivative(), &p, 1e-12);
+    }
+    // dividing by a larger polynomial only gives a remainder
+    let small = Polynomial::new(vec![1.0, 2.0]);
+    let (quotient, remainder) = small.div_rem(&Polynomial::from_roots(&[1.0, 2.0, 3.0]));
+    assert!(quotient.is_zero());
+    assert_eq!(remainder, small);
+    // (x^2 - 1) / (x - 1) = x + 1
+    let (quotient, remainder) = Polynomial::new(vec![-1.0, 0.0, 1.0]).div_rem(&Polynomial::new(vec![-1.0, 1.0]));
+    assert_eq!((quotient, remainder), (Polynomial::new(vec![1.0, 1.0]), Polynomial::zero()));
+    // composing with x changes nothing and composing with a constant gives a constant
+    let p = Polynomial::from_roots(&[1.0, -2.0, 0.5]);
+    assert_eq!(p.compose(&Polynomial::x()), p);
+    assert_eq!(p.compose(&Polynomial::constant(2.0)), Polynomial::constant(p.evaluate(2.0)));
+    assert!((Polynomial::new(vec![0.0, 0.0, 3.0_f64]).integrate(0.0, 2.0) - 8.0).abs() < 1e-12);
+}
+
+#[test]
+fn sturm_sequences() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let mut roots: Vec<f64> = (0..1 + rng.below(7)).map(|_| rng.next() * 3.0).collect();
+        roots.sort_by(f64::total_cmp);
+        roots.dedup_by(|a, b| (*a - *b).abs() < 1e-3);
+        // a quadratic without real roots adds two complex roots that are not counted
+        let p = &Polynomial::from_roots(&roots) * &Polynomial::new(vec![1.0 + rng.next().abs(), rng.next(), 1.0]);
+        let sequence = p.sturm_sequence();
+        assert_eq!(sequence[0], p);
+        assert_eq!(sequence[1], p.derivative());
+        assert_eq!(Polynomial::count_real_roots(&sequence, -10.0, 10.0), roots.len());
+        let (a, b) = (rng.next() * 3.0, rng.next() * 3.0);
+        let (a, b) = (a.min(b), a.max(b));
+        let inside = roots.iter().filter(|r| **r > a && **r <= b).count();
+        assert_eq!(Polynomial::count_real_roots(&sequence, a, b), inside);
+
+        let found = p.real_roots();
+        assert_eq!(found.len(), roots.len(), "{:?} {:?}", found, roots);
+        for (found, root) in found.iter().zip(roots.iter()) {
+            assert!((found - root).abs() < 1e-9, "{} {}", found, root);
+        }
+    }
+    // repeated roots are only counted once
+    let p = Polynomial::from_roots(&[1.0_f64, 1.0, 1.0, -2.0, -2.0, 3.0]);
+    let sequence = p.sturm_sequence();
+    assert_eq!(Polynomial::count_real_roots(&sequence, -5.0, 5.0), 3);
+    let roots = p.real_roots();
+    assert_eq!(roots.len(), 3);
+    for (found, expected) in roots.iter().zip([-2.0, 1.0, 3.0]) {
+        assert!((found - expected).abs() < 1e-4, "{:?}", roots);
+    }
+    assert!(Polynomial::<f64>::zero().sturm_sequence().is_empty());
+    assert!(Polynomial::constant(2.0).real_roots().is_empty());
+    assert_eq!(Polynomial::from_roots(&[0.5, -1.5]).real_roots_in(0.0, 1.0), vec![0.5]);
+}
+
+#[test]
+fn complex_roots() {
+    let mut rng = Lcg(3);
+    for _ in 0..100 {
+        // real roots and complex pairs of every degree up to 8
+        let mut expected = Vec::new();
+        let mut p = Polynomial::constant(rng.next() + 2.0);
+        for _ in 0..1 + rng.below(4) {
+            if rng.next() > 0.0 {
+                let root = rng.next() * 2.0;
+                expected.push(Complex::new(root, 0.0));
+                p = &p * &Polynomial::new(vec![-root, 1.0]);
+            } else {
+                let root = Complex::new(rng.next() * 2.0, rng.next().abs() + 0.1);
+                expected.extend([root, root.conj()]);
+                p = &p * &Polynomial::new(vec![root.norm_sqr(), -2.0 * root.re, 1.0]);
+            }
+        }
+        let found = p.complex_roots();
+        assert_eq!(found.len(), p.degree());
+        for root in found.iter() {
+            assert!(p.evaluate_complex(*root).norm() < 1e-9, "{:?} {:?}", root, p);
+        }
+        let separated = expected.iter().enumerate().all(|(i, a)| expected[i + 1..].iter().all(|b| (*a - *b).norm() > 1e-2));
+        if separated {
+            for root in expected.iter() {
+                assert!(found.iter().any(|f| (*f - *root).norm() < 1e-6), "{:?} {:?}", found, expected);
+            }
+            // real roots are returned without an imaginary part
+            for root in expected.iter().filter(|root| root.im == 0.0) {
+                assert!(found.iter().any(|f| f.im == 0.0 && (f.re - root.re).abs() < 1e-6));
+            }
+        }
+    }
+    // x^5 - 1 has the fifth roots of unity as roots
+    let found = Polynomial::new(vec![-1.0, 0.0, 0.0, 0.0, 0.0, 1.0]).complex_roots();
+    for k in 0..5 {
+        let root = Complex::from_polar(1.0, k as f64 * 2.0 * std::f64::consts::PI / 5.0);
+        assert!(found.iter().any(|f| (*f - root).norm() < 1e-9), "{:?}", found);
+    }
+}
+
+#[test]
+fn non_finite_coefficients() {
+    // roots that are not numbers are left out instead of breaking the sorting
+    for coefficients in [vec![f64::NAN, 1.0, 1.0], vec![1.0, f64::INFINITY, 1.0, 2.0], vec![-1.0, 0.0, f64::MAX]] {
+        let roots = Polynomial::new(coefficients).real_roots();
+        assert!(roots.iter().all(|root| root.is_finite()), "{:?}", roots);
+    }
+}
@@ -0,0 +1,140 @@
+use std::f64::consts::PI;
+
+use drowsed_math::{Aabb3, Capsule, Cone, Cylinder, Obb, Plane, Ray3, Sphere, Vector, Vector3};
+
+mod common;
+use common::Lcg;
+
+/// a random point inside of the box.
+fn inside(rng: &mut Lcg, aabb: &Aabb3<f64>) -> Vector3<f64> {
+    let t = (rng.vector3() + 1.0) / 2.0;
+    aabb.min + aabb.size() * t
+}
+/// checks the queries of a solid against each other using points sampled inside of its bounds.
+fn check_solid(
+    rng: &mut Lcg,
+    bounds: Aabb3<f64>,
+    volume: f64,
+    contains: impl Fn(Vector3<f64>) -> bool,
+    closest: impl Fn(Vector3<f64>) -> Vector3<f64>,
+) {
+    const SAMPLES: usize = 20_000;
+    let samples: Vec<_> = (0..SAMPLES).map(|_| inside(rng, &bounds)).filter(|&p| contains(p)).collect();
+    // the volume matches the fraction of the bounds the solid fills
+    let estimate = bounds.volume() * samples.len() as f64 / SAMPLES as f64;
+    assert!((estimate - volume).abs() < 0.05 * volume, "{} {}", estimate, volume);
+    // the bounds are as small as they can be, the closest points to far away points along every axis
+    // touch their faces
+    let center = bounds.center();
+    for axis in [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0)] {
+        for side in [-1.0, 1.0] {
+            let extreme = closest(center + axis * side * 1e6).dot(&axis) * side;
+            let face = if side > 0.0 { bounds.max.dot(&axis) } else { -bounds.min.dot(&axis) };
+            assert!((extreme - face).abs() < 1e-4, "{} {}", extreme, face);
+        }
+    }
+    for _ in 0..100 {
+        let p = inside(rng, &bounds.expand(0.5));
+        let c = closest(p);
+        assert!(bounds.expand(1e-9).contains_point(c));
+        assert!((closest(c) - c).length() < 1e-9);
+        if contains(p) {
+            assert!((c - p).length() < 1e-9);
+        }
+        let distance = (c - p).length();
+        assert!(samples.iter().all(|&s| distance <= (s - p).length() + 1e-9));
+    }
+}
+
+#[test]
+fn spheres_and_capsules() {
+    let mut rng = Lcg(1);
+    for _ in 0..10 {
+        let sphere = Sphere::new(rng.vector3(), rng.next().abs() + 0.2);
+        check_solid(&mut rng, sphere.bounds(), sphere.volume(), |p| sphere.contains_point(p), |p| sphere.closest_point(p));
+        let capsule = Capsule::new(rng.vector3(), rng.vector3(), rng.next().abs() * 0.5 + 0.1);
+        check_solid(&mut rng, capsule.bounds(), capsule.volume(), |p| capsule.contains_point(p), |p| capsule.closest_point(p));
+    }
+    assert!((Sphere::new(Vector3::new(1.0, 2.0, 3.0), 1.0).surface_area() - 4.0 * PI).abs() < 1e-12);
+    let capsule = Capsule::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0), 1.0);
+    assert!((capsule.surface_area() - 8.0 * PI).abs() < 1e-12);
+    assert!((capsule.volume() - PI * (2.0 + 4.0 / 3.0)).abs() < 1e-12);
+}
+
+#[test]
+fn cylinders_and_cones() {
+    let mut rng = Lcg(2);
+    for _ in 0..10 {
+        let cylinder = Cylinder::new(rng.vector3(), rng.vector3(), rng.next().abs() * 0.5 + 0.1);
+        check_solid(&mut rng, cylinder.bounds(), cylinder.volume(), |p| cylinder.contains_point(p), |p| cylinder.closest_point(p));
+        let cone = Cone::new(rng.vector3(), rng.vector3(), rng.next().abs() * 0.5 + 0.1);
+        check_solid(&mut rng, cone.bounds(), cone.volume(), |p| cone.contains_point(p), |p| cone.closest_point(p));
+    }
+    let cylinder = Cylinder::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 2.0), 1.0);
+    assert!((cylinder.surface_area() - 6.0 * PI).abs() < 1e-12);
+    let cone = Cone::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 4.0, 0.0), 3.0);
+    assert!((cone.surface_area() - 24.0 * PI).abs() < 1e-12);
+    assert!((cone.volume() - 12.0 * PI).abs() < 1e-12);
+}
+
+#[test]
+fn oriented_boxes() {
+    let mut rng = Lcg(3);
+    for _ in 0..10 {
+        let obb = Obb::from_quaternion(rng.vector3(), (rng.vector3() + 1.2) / 2.0, rng.rotation());
+        check_solid(&mut rng, obb.bounds(), obb.volume(), |p| obb.contains_point(p), |p| obb.closest_point(p));
+        // the bounds are the bounds of the corners
+        let corners = Aabb3::from_points(obb.corners().iter()).unwrap();
+        assert!((corners.min - obb.bounds().min).length() < 1e-12 && (corners.max - obb.bounds().max).length() < 1e-12);
+        for corner in obb.corners() {
+            assert!((obb.closest_point(corner) - corner).length() < 1e-12);
+        }
+        let p = rng.vector3();
+        assert!((obb.to_world(obb.to_local(p)) - p).length() < 1e-12);
+        // rays hit the faces of the box from outside and inside
+        for _ in 0..20 {
+            let ray = Ray3::new(rng.vector3() * 2.0, rng.vector3());
+            if let Some(hit) = ray.cast(&obb) {
+                assert!(obb.contains_point(hit.point - hit.normal * 1e-9) && !obb.contains_point(hit.point + hit.normal * 1e-9));
+                if !obb.contains_point(ray.origin) {
+                    assert!(!obb.contains_point(ray.at(hit.distance * (1.0 - 1e-9))));
+                }
+            } else {
+                assert!((0..1000).all(|i| !obb.contains_point(ray.at(i as f64 * 0.01))));
+            }
+        }
+    }
+    let obb = Obb::from_aabb(&Aabb3::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!((obb.volume(), obb.surface_area()), (48.0, 88.0));
+}
+
+#[test]
+fn planes() {
+    let mut rng = Lcg(4);
+    for _ in 0..100 {
+        let (a, b, c) = (rng.vector3(), rng.vector3(), rng.vector3());
+        let plane = Plane::from_points(a, b, c).unwrap();
+        assert!((plane.normal.length() - 1.0).abs() < 1e-12);
+        for point in [a, b, c] {
+            assert!(plane.signed_distance(point).abs() < 1e-12);
+        }
+        let p = rng.vector3();
+        let projected = plane.project(p);
+        assert!(plane.signed_distance(projected).abs() < 1e-12);
+        assert!((p - projected - plane.normal * plane.signed_distance(p)).length() < 1e-12);
+        assert_eq!(plane.contains_point(p), plane.signed_distance(p) <= 0.0);
+        assert_eq!(plane.flip().signed_distance(p), -plane.signed_distance(p));
+        let closest = plane.closest_point(p);
+        assert!(plane.signed_distance(closest) <= 1e-12 && (closest == p) == plane.contains_point(p));
+        // rays hit the plane from either side
+        let ray = Ray3::new(p, rng.vector3());
+        if let Some(hit) = ray.cast(&plane) {
+            assert!(plane.signed_distance(hit.point).abs() < 1e-9);
+        } else {
+            assert!(plane.signed_distance(ray.at(1.0)).abs() >= plane.signed_distance(p).abs());
+        }
+    }
+    let points = Plane::from_points(Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 1.0)).unwrap();
+    assert_eq!(points, Plane::new(Vector3::new(0.0, 0.0, 1.0), 1.0));
+    assert_eq!(Plane::from_points(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), Vector3::new(2.0, 2.0, 2.0)), None);
+}
@@ -0,0 +1,106 @@
+use drowsed_math::{ClipSpace, EuclideanGeometry, Matrix4, Vector, Vector3, Vector4};
+
+mod common;
+use common::Lcg;
+
+const CLIP_SPACES: [(ClipSpace, f64, f64); 5] = [
+    (ClipSpace::OPENGL, -1.0, 1.0),
+    (ClipSpace::VULKAN, 0.0, 1.0),
+    (ClipSpace::DIRECT3D, 0.0, 1.0),
+    (ClipSpace { reversed_z: true, ..ClipSpace::OPENGL }, 1.0, -1.0),
+    (ClipSpace { reversed_z: true, ..ClipSpace::VULKAN }, 1.0, 0.0),
+];
+
+/// moves a point through the matrix and does the perspective division.
+fn project(m: &Matrix4<f64>, p: Vector3<f64>) -> Vector3<f64> {
+    let clip = Vector4::new(p.x, p.y, p.z, 1.0) * *m;
+    Vector3::new(clip.x, clip.y, clip.z) / clip.w
+}
+fn assert_close(a: Vector3<f64>, b: Vector3<f64>) {
+    assert!((a - b).length() < 1e-9, "{:?} {:?}", a, b);
+}
+/// checks that the corners of the box from the edges of the near plane, and the far plane
+/// `scale` times as far away, land on the corners of normalized device coordinates.
+fn check_corners(m: &Matrix4<f64>, clip: ClipSpace, edges: [f64; 4], near: f64, far: f64, scale: f64, depth: (f64, f64)) {
+    let [left, right, bottom, top] = edges;
+    let y = if clip.flip_y { -1.0 } else { 1.0 };
+    for (x, ndc_x) in [(left, -1.0), (right, 1.0)] {
+        for (z, ndc_y) in [(bottom, -y), (top, y)] {
+            assert_close(project(m, Vector3::new(x, z, -near)), Vector3::new(ndc_x, ndc_y, depth.0));
+            assert_close(project(m, Vector3::new(x * scale, z * scale, -far)), Vector3::new(ndc_x, ndc_y, depth.1));
+        }
+    }
+}
+
+#[test]
+fn perspective_depth() {
+    let (fovy, aspect, near, far) = (1.2_f64, 16.0 / 9.0, 0.1, 100.0);
+    let half_height = near * (fovy / 2.0).tan();
+    let edges = [-half_height * aspect, half_height * aspect, -half_height, half_height];
+    for (clip, depth_near, depth_far) in CLIP_SPACES {
+        let perspective = Matrix4::perspective(fovy, aspect, near, far, clip);
+        check_corners(&perspective, clip, edges, near, far, far / near, (depth_near, depth_far));
+        let frustum = Matrix4::frustum(edges[0], edges[1], edges[2], edges[3], near, far, clip);
+        check_corners(&frustum, clip, edges, near, far, far / near, (depth_near, depth_far));
+        // depth only moves one way between the planes
+        let middle = project(&perspective, Vector3::new(0.0, 0.0, -1.0)).z;
+        assert!((middle - depth_near) * (depth_far - middle) > 0.0);
+
+        // the far plane of the infinite projection is only reached at infinity
+        let infinite = Matrix4::perspective_infinite(fovy, aspect, near, clip);
+        check_corners(&infinite, clip, edges, near, far, far / near, (depth_near, project(&infinite, Vector3::new(0.0, 0.0, -far)).z));
+        assert!((project(&infinite, Vector3::new(0.0, 0.0, -1e12)).z - depth_far).abs() < 1e-9);
+        let at_far = project(&infinite, Vector3::new(0.0, 0.0, -far)).z;
+        assert!((at_far - depth_near) * (depth_far - at_far) > 0.0);
+    }
+    // off center frustums keep their corners
+    let edges = [-0.3, 0.1, -0.05, 0.2];
+    for (clip, depth_near, depth_far) in CLIP_SPACES {
+        let frustum = Matrix4::frustum(edges[0], edges[1], edges[2], edges[3], 0.5, 20.0, clip);
+        check_corners(&frustum, clip, edges, 0.5, 20.0, 40.0, (depth_near, depth_far));
+    }
+}
+
+#[test]
+fn orthographic_depth() {
+    let edges = [-3.0, 5.0, -1.0, 2.0];
+    for (clip, depth_near, depth_far) in CLIP_SPACES {
+        let orthographic = Matrix4::orthographic(edges[0], edges[1], edges[2], edges[3], 1.0, 50.0, clip);
+        check_corners(&orthographic, clip, edges, 1.0, 50.0, 1.0, (depth_near, depth_far));
+        // the depth is linear
+        let middle = project(&orthographic, Vector3::new(0.0, 0.0, -25.5)).z;
+        assert!((middle - (depth_near + depth_far) / 2.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn look_at() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let (eye, target, up) = (rng.vector3() * 10.0, rng.vector3() * 10.0, rng.vector3());
+        let distance = (target - eye).length();
+        let rh = Matrix4::look_at_rh(eye, target, up);
+        let lh = Matrix4::look_at_lh(eye, target, up);
+        // the eye goes to the origin and the target in front of the camera
+        assert_close(project(&rh, eye), Vector3::new(0.0, 0.0, 0.0));
+        assert_close(project(&lh, eye), Vector3::new(0.0, 0.0, 0.0));
+        assert_close(project(&rh, target), Vector3::new(0.0, 0.0, -distance));
+        assert_close(project(&lh, target), Vector3::new(0.0, 0.0, distance));
+        // up stays above the camera in both, the left handed view is mirrored along x
+        let above = project(&rh, eye + up);
+        assert!(above.y > 0.0 && above.x.abs() < 1e-9);
+        let above = project(&lh, eye + up);
+        assert!(above.y > 0.0 && above.x.abs() < 1e-9);
+        let right = (target - eye).cross(up);
+        assert!(project(&rh, eye + right).x > 0.0 && project(&lh, eye + right).x < 0.0);
+        // view matrices only move and rotate
+        let (a, b) = (rng.vector3(), rng.vector3());
+        assert!(((project(&rh, a) - project(&rh, b)).length() - (a - b).length()).abs() < 1e-9);
+        assert!(((project(&lh, a) - project(&lh, b)).length() - (a - b).length()).abs() < 1e-9);
+    }
+    // a camera looking down the negative z axis from the origin is the identity
+    let view = Matrix4::look_at_rh(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0), Vector3::new(0.0, 1.0, 0.0));
+    let p = Vector3::new(1.0, 2.0, 3.0);
+    assert_close(project(&view, p), p);
+    assert_eq!(ClipSpace::OPENGL.reversed(), ClipSpace { reversed_z: true, ..ClipSpace::OPENGL });
+}
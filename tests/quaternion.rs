@@ -0,0 +1,217 @@
+use std::f64::consts::PI;
+
+use drowsed_math::{EuclideanGeometry, Matrix3, Matrix4, Vector, Vector3};
+use drowsed_math::complex::quaternion::{EulerOrder, Quaternion};
+
+mod common;
+use common::Lcg;
+
+/// every order together with the axis of its middle rotation.
+const ORDERS: [(EulerOrder, usize); 12] = [
+    (EulerOrder::ExtrinsicXYZ, 1),
+    (EulerOrder::ExtrinsicXZY, 2),
+    (EulerOrder::ExtrinsicYXZ, 0),
+    (EulerOrder::ExtrinsicYZX, 2),
+    (EulerOrder::ExtrinsicZXY, 0),
+    (EulerOrder::ExtrinsicZYX, 1),
+    (EulerOrder::IntrinsicXYZ, 1),
+    (EulerOrder::IntrinsicXZY, 2),
+    (EulerOrder::IntrinsicYXZ, 0),
+    (EulerOrder::IntrinsicYZX, 2),
+    (EulerOrder::IntrinsicZXY, 0),
+    (EulerOrder::IntrinsicZYX, 1),
+];
+
+/// the angle of the rotation that turns a into b.
+fn angle_between(a: &Quaternion<f64>, b: &Quaternion<f64>) -> f64 {
+    2.0 * a.dot(b).abs().min(1.0).acos()
+}
+/// whether two unit quaternions describe the same rotation, q and -q are the same rotation.
+fn assert_same_rotation(a: &Quaternion<f64>, b: &Quaternion<f64>) {
+    assert!(a.dot(b).abs() > 1.0 - 1e-9, "{:?} {:?}", a, b);
+}
+fn assert_close(a: Vector3<f64>, b: Vector3<f64>) {
+    assert!((a - b).length() < 1e-9, "{:?} {:?}", a, b);
+}
+
+#[test]
+fn inverses() {
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let q = Quaternion::new(rng.vector3(), rng.next()) * 3.0;
+        let identity = q * q.inverse();
+        assert!((identity.scalar - 1.0).abs() < 1e-12 && identity.vector.length() < 1e-12);
+        let identity = q.inverse() * q;
+        assert!((identity.scalar - 1.0).abs() < 1e-12 && identity.vector.length() < 1e-12);
+        // the conjugate of a unit quaternion is the inverse and rotates back
+        let unit = q.normalize();
+        assert!((unit.magnitude() - 1.0).abs() < 1e-12);
+        assert!((unit.conjugate() - unit.inverse()).magnitude() < 1e-12);
+        let v = rng.vector3();
+        assert_close(unit.inverse() * (unit * v), v);
+        // rotations keep the length of vectors
+        assert!(((unit * v).length() - v.length()).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn interpolation() {
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let (a, b) = (rng.rotation(), rng.rotation());
+        let angle = angle_between(&a, &b);
+        assert_same_rotation(&a.slerp(&b, 0.0), &a);
+        assert_same_rotation(&a.slerp(&b, 1.0), &b);
+        assert_same_rotation(&a.nlerp(&b, 0.0), &a);
+        assert_same_rotation(&a.nlerp(&b, 1.0), &b);
+        for i in 1..10 {
+            let t = i as f64 / 10.0;
+            // slerp turns at a constant speed along the shortest path
+            let slerp = a.slerp(&b, t);
+            assert!((slerp.magnitude() - 1.0).abs() < 1e-9);
+            assert!((angle_between(&a, &slerp) - t * angle).abs() < 1e-6);
+            assert!((angle_between(&slerp, &b) - (1.0 - t) * angle).abs() < 1e-6);
+            assert_same_rotation(&a.slerp(&-b, t), &slerp);
+            // nlerp stays on the same path but does not move at a constant speed
+            let nlerp = a.nlerp(&b, t);
+            assert!((nlerp.magnitude() - 1.0).abs() < 1e-12);
+            assert!((angle_between(&a, &nlerp) + angle_between(&nlerp, &b) - angle).abs() < 1e-6);
+            assert_same_rotation(&a.nlerp(&-b, t), &nlerp);
+        }
+        assert_same_rotation(&a.nlerp(&b, 0.5), &a.slerp(&b, 0.5));
+        // rotations that are almost the same
+        let close = (a + Quaternion::new(rng.vector3(), rng.next()) * 1e-4).normalize();
+        let middle = a.slerp(&close, 0.5);
+        assert!((angle_between(&a, &middle) - angle_between(&a, &close) / 2.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn euler_orders() {
+    let mut rng = Lcg(3);
+    let axes = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0)];
+    for (order, middle) in ORDERS {
+        for _ in 0..100 {
+            let mut angles = [rng.next() * PI, rng.next() * PI, rng.next() * PI];
+            angles[middle] *= 0.49;
+            let angles = Vector3::new(angles[0], angles[1], angles[2]);
+            let q = Quaternion::from_euler_ordered(angles, order);
+            assert!((q.magnitude() - 1.0).abs() < 1e-12);
+            assert_close(q.to_euler_ordered(order), angles);
+            // gimbal lock gives other angles but the same rotation
+            let mut locked = [angles.x, angles.y, angles.z];
+            locked[middle] = if rng.next() > 0.0 { PI / 2.0 } else { -PI / 2.0 };
+            let locked = Quaternion::from_euler_ordered(Vector3::new(locked[0], locked[1], locked[2]), order);
+            assert_same_rotation(&Quaternion::from_euler_ordered(locked.to_euler_ordered(order), order), &locked);
+        }
+    }
+    // extrinsic rotations turn around the world axes one after another, intrinsic ones around the
+    // turned axes, which is the same as the extrinsic rotations in the opposite order
+    let angles = Vector3::new(0.3, -0.7, 1.1);
+    let [x, y, z] = [0, 1, 2].map(|i| Quaternion::angle_axis([angles.x, angles.y, angles.z][i], axes[i]));
+    assert_same_rotation(&Quaternion::from_euler_ordered(angles, EulerOrder::ExtrinsicXYZ), &(z * y * x));
+    assert_same_rotation(&Quaternion::from_euler_ordered(angles, EulerOrder::IntrinsicXYZ), &(x * y * z));
+    assert_same_rotation(&Quaternion::from_euler_ordered(angles, EulerOrder::ExtrinsicYZX), &(x * z * y));
+    assert_same_rotation(&Quaternion::from_euler_ordered(angles, EulerOrder::IntrinsicZXY), &(z * x * y));
+    let turned = Quaternion::from_euler_ordered(Vector3::new(0.0, 0.0, PI / 2.0), EulerOrder::ExtrinsicXYZ) * axes[0];
+    assert_close(turned, axes[1]);
+}
+
+#[test]
+fn matrices() {
+    let mut rng = Lcg(4);
+    // half turns around each axis reach every branch of the conversion
+    let mut rotations = vec![
+        Quaternion::identity(),
+        Quaternion::new(Vector3::new(1.0, 0.0, 0.0), 0.0),
+        Quaternion::new(Vector3::new(0.0, 1.0, 0.0), 0.0),
+        Quaternion::new(Vector3::new(0.0, 0.0, 1.0), 0.0),
+        Quaternion::new(Vector3::new(1.0, 1.0, 0.0), 0.0).normalize(),
+    ];
+    rotations.extend((0..200).map(|_| rng.rotation()));
+    for q in rotations {
+        let matrix = Matrix3::from(q);
+        assert_same_rotation(&Quaternion::from_matrix3(&matrix), &q);
+        assert_same_rotation(&Quaternion::from_matrix3(&Matrix3::from(-q)), &q);
+        // the translation of a transformation matrix is ignored
+        let mut transform = Matrix4::from(q);
+        transform.w.x = rng.next() * 10.0;
+        transform.w.z = rng.next() * 10.0;
+        assert_same_rotation(&Quaternion::from_matrix4(&transform), &q);
+        // the matrix rotates vectors the same way as the quaternion, its axes are the turned axes
+        let v = rng.vector3();
+        assert_close(matrix.x * v.x + matrix.y * v.y + matrix.z * v.z, q * v);
+        assert_close(matrix.x, q * Vector3::new(1.0, 0.0, 0.0));
+    }
+}
+
+#[test]
+fn look_rotations() {
+    let mut rng = Lcg(5);
+    for _ in 0..100 {
+        let (forward, up) = (rng.vector3(), rng.vector3());
+        let q = Quaternion::look_rotation(forward, up);
+        assert!((q.magnitude() - 1.0).abs() < 1e-12);
+        assert_close(q * Vector3::new(0.0, 0.0, 1.0), forward.normalize());
+        // y is turned towards up as far as it can while staying perpendicular to forward
+        let y = q * Vector3::new(0.0, 1.0, 0.0);
+        let wanted = (up - forward.normalize() * up.dot(&forward.normalize())).normalize();
+        assert_close(y, wanted);
+        assert_close(q * Vector3::new(1.0, 0.0, 0.0), wanted.cross(forward.normalize()));
+    }
+    // up going along forward still gives a rotation that looks forward
+    for forward in [Vector3::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -2.0)] {
+        let q: Quaternion<f64> = Quaternion::look_rotation(forward, forward * 3.0);
+        assert!((q.magnitude() - 1.0).abs() < 1e-12);
+        assert_close(q * Vector3::new(0.0, 0.0, 1.0), forward.normalize());
+    }
+}
+
+#[test]
+fn rotation_arcs() {
+    let mut rng = Lcg(6);
+    for _ in 0..100 {
+        let (from, to) = (rng.vector3(), rng.vector3());
+        let q = Quaternion::from_rotation_arc(from, to);
+        assert!((q.magnitude() - 1.0).abs() < 1e-12);
+        assert_close(q * from.normalize(), to.normalize());
+        // the shortest rotation turns around the axis perpendicular to both
+        assert!(q.vector.dot(&from).abs() < 1e-9 && q.vector.dot(&to).abs() < 1e-9);
+        assert!((angle_between(&q, &Quaternion::identity()) - from.normalize().dot(&to.normalize()).acos()).abs() < 1e-9);
+    }
+    // opposite directions need a half turn, including along the axes the fallback is picked from
+    let mut directions = vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0), Vector3::new(-2.0, 0.0, 0.0)];
+    directions.extend((0..20).map(|_| rng.vector3()));
+    for from in directions {
+        let q = Quaternion::from_rotation_arc(from, -from);
+        assert!((q.magnitude() - 1.0).abs() < 1e-12 && q.scalar.abs() < 1e-12);
+        assert_close(q * from.normalize(), -from.normalize());
+    }
+    assert_same_rotation(&Quaternion::from_rotation_arc(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0)), &Quaternion::identity());
+}
+
+#[test]
+fn swing_twist() {
+    let mut rng = Lcg(7);
+    for _ in 0..100 {
+        let (q, axis) = (rng.rotation(), rng.vector3());
+        let (swing, twist) = q.swing_twist(axis);
+        assert!((swing.magnitude() - 1.0).abs() < 1e-9 && (twist.magnitude() - 1.0).abs() < 1e-12);
+        assert_same_rotation(&(swing * twist), &q);
+        // the twist turns around the axis and the swing around an axis perpendicular to it
+        assert!(twist.vector.cross(axis).length() < 1e-9);
+        assert!(swing.vector.dot(&axis).abs() < 1e-9);
+        // so the swing alone moves the axis to where the rotation moves it
+        assert_close(swing * axis, q * axis);
+    }
+    // a half turn perpendicular to the axis has no twist
+    let half_turn = Quaternion::new(Vector3::new(1.0, 0.0, 0.0), 0.0);
+    let (swing, twist) = half_turn.swing_twist(Vector3::new(0.0, 0.0, 1.0));
+    assert_same_rotation(&twist, &Quaternion::identity());
+    assert_same_rotation(&swing, &half_turn);
+    // a rotation around the axis is only twist
+    let around = Quaternion::angle_axis(0.8, Vector3::new(0.0, 0.0, 1.0));
+    let (swing, twist) = around.swing_twist(Vector3::new(0.0, 0.0, 2.0));
+    assert_same_rotation(&swing, &Quaternion::identity());
+    assert_same_rotation(&twist, &around);
+}
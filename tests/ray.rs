@@ -0,0 +1,178 @@
+use drowsed_math::{Aabb2, Aabb3, EuclideanGeometry, Ray, Ray2, Ray3, RayHit, Triangle, Vector, Vector3};
+
+mod common;
+use common::Lcg;
+
+/// the distance from p to the line segment from a to b.
+fn segment_distance<V: Vector<Scalar = f64>>(a: V, b: V, p: V) -> f64 {
+    let t = ((p - a).dot(&(b - a)) / (b - a).dot(&(b - a))).clamp(0.0, 1.0);
+    (a + (b - a) * t - p).length()
+}
+/// whether p is inside of the cylinder from a to b.
+fn in_cylinder<V: Vector<Scalar = f64>>(a: V, b: V, radius: f64, p: V) -> bool {
+    let t = (p - a).dot(&(b - a)) / (b - a).dot(&(b - a));
+    (0.0..=1.0).contains(&t) && (a + (b - a) * t - p).length() <= radius
+}
+
+/// the first distance at which the ray goes into or out of the solid found by marching along it.
+fn brute_first<V: Vector<Scalar = f64>>(ray: &Ray<V>, inside: &impl Fn(V) -> bool) -> Option<f64> {
+    const STEP: f64 = 1e-3;
+    let start = inside(ray.origin);
+    let mut previous = 0.0;
+    for i in 1..=10_000 {
+        let t = i as f64 * STEP;
+        if inside(ray.at(t)) != start {
+            let (mut low, mut high) = (previous, t);
+            for _ in 0..60 {
+                let middle = (low + high) / 2.0;
+                if inside(ray.at(middle)) == start { low = middle } else { high = middle }
+            }
+            return Some(high);
+        }
+        previous = t;
+    }
+    None
+}
+/// compares a hit with marching along the ray and checks the normal points out of the solid.
+fn check_solid<V: Vector<Scalar = f64> + std::fmt::Debug>(ray: &Ray<V>, hit: Option<RayHit<V>>, inside: impl Fn(V) -> bool) {
+    match (brute_first(ray, &inside), hit) {
+        (Some(distance), Some(hit)) => {
+            assert!((hit.distance - distance).abs() < 1e-6, "{:?} {:?}", hit, distance);
+            assert!((hit.point - ray.at(hit.distance)).length() < 1e-12);
+            assert!((hit.normal.length() - 1.0).abs() < 1e-9);
+            assert!(!inside(hit.point + hit.normal * 1e-6) && inside(hit.point - hit.normal * 1e-6));
+        }
+        (Some(distance), None) => panic!("missed a hit at {}", distance),
+        // marching can step over the ray grazing the solid, but the hit has to be on it
+        (None, Some(hit)) => assert!((-10..=10).any(|i| inside(ray.at(hit.distance + i as f64 * 1e-8)))),
+        (None, None) => {}
+    }
+}
+
+#[test]
+fn solids_in_3d() {
+    let mut rng = Lcg(1);
+    for _ in 0..400 {
+        let ray = Ray3::new(rng.vector3() * 2.0, rng.vector3());
+        let center = rng.vector3() * 0.5;
+        let radius = rng.next().abs() + 0.1;
+        check_solid(&ray, ray.intersect_sphere(center, radius), |p| (p - center).length() <= radius);
+
+        let aabb = Aabb3::from_point(rng.vector3()).include(rng.vector3());
+        check_solid(&ray, ray.cast(&aabb), |p| aabb.contains_point(p));
+
+        let (a, b) = (rng.vector3(), rng.vector3());
+        let radius = rng.next().abs() * 0.5 + 0.05;
+        check_solid(&ray, ray.intersect_cylinder(a, b, radius), |p| in_cylinder(a, b, radius, p));
+        check_solid(&ray, ray.intersect_capsule(a, b, radius), |p| segment_distance(a, b, p) <= radius);
+    }
+    // rays along the axis of a cylinder and along the faces of a box
+    let ray = Ray3::new(Vector3::new(0.0, 0.0, -2.0), Vector3::new(0.0, 0.0, 2.0));
+    let hit = ray.intersect_cylinder(Vector3::new(0.0, 0.0, -1.0), Vector3::new(0.0, 0.0, 1.0), 0.5).unwrap();
+    assert_eq!((hit.distance, hit.normal), (0.5, Vector3::new(0.0, 0.0, -1.0)));
+    let hit = ray.intersect_capsule(Vector3::new(0.0, 0.0, -1.0), Vector3::new(0.0, 0.0, 1.0), 0.5).unwrap();
+    assert_eq!((hit.distance, hit.normal), (0.25, Vector3::new(0.0, 0.0, -1.0)));
+    let aabb = Aabb3::new(Vector3::new(-1.0, 0.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(ray.cast(&aabb).map(|hit| hit.distance), Some(0.5));
+    assert_eq!(Ray3::new(Vector3::new(0.0, 2.0, -2.0), Vector3::new(0.0, 0.0, 1.0)).cast(&aabb), None);
+    // the ray points away
+    assert_eq!(Ray3::new(Vector3::new(0.0, 0.0, 2.0), Vector3::new(0.0, 0.0, 1.0)).intersect_sphere(Vector3::new(0.0, 0.0, 0.0), 1.0), None);
+}
+
+#[test]
+fn solids_in_2d() {
+    let mut rng = Lcg(2);
+    for _ in 0..400 {
+        let ray = Ray2::new(rng.vector2() * 2.0, rng.vector2());
+        let center = rng.vector2() * 0.5;
+        let radius = rng.next().abs() + 0.1;
+        check_solid(&ray, ray.intersect_sphere(center, radius), |p| (p - center).length() <= radius);
+
+        let aabb = Aabb2::from_point(rng.vector2()).include(rng.vector2());
+        check_solid(&ray, ray.cast(&aabb), |p| aabb.contains_point(p));
+
+        let triangle = Triangle::new(rng.vector2(), rng.vector2(), rng.vector2());
+        check_solid(&ray, ray.cast(&triangle), |p| triangle.contains(p));
+
+        let (a, b) = (rng.vector2(), rng.vector2());
+        let radius = rng.next().abs() * 0.5 + 0.05;
+        check_solid(&ray, ray.intersect_capsule(a, b, radius), |p| segment_distance(a, b, p) <= radius);
+    }
+}
+
+#[test]
+fn flat_shapes() {
+    let mut rng = Lcg(3);
+    for _ in 0..1000 {
+        let ray = Ray3::new(rng.vector3() * 2.0, rng.vector3());
+        let (point, normal) = (rng.vector3(), rng.vector3());
+        let side = |t: f64| (ray.at(t) - point).dot(&normal);
+        let crossing = -side(0.0) / ray.direction.dot(&normal);
+        match ray.intersect_plane(point, normal) {
+            Some(hit) => {
+                assert!((hit.distance - crossing).abs() < 1e-9 * crossing.max(1.0));
+                assert!((hit.point - point).dot(&normal).abs() < 1e-9 * crossing.max(1.0));
+                // the normal faces back towards the origin of the ray
+                assert!(hit.normal.dot(&ray.direction) <= 0.0 && (hit.normal.length() - 1.0).abs() < 1e-12);
+                assert!(hit.normal.cross(normal).length() < 1e-12);
+            }
+            None => assert!(crossing < 0.0),
+        }
+        let radius = rng.next().abs();
+        let disc = ray.intersect_disc(point, normal, radius);
+        match ray.intersect_plane(point, normal) {
+            Some(hit) if (hit.point - point).length() < radius - 1e-9 => assert_eq!(disc, Some(hit)),
+            Some(hit) if (hit.point - point).length() > radius + 1e-9 => assert_eq!(disc, None),
+            None => assert_eq!(disc, None),
+            _ => {}
+        }
+
+        let triangle = Triangle::new(rng.vector3(), rng.vector3(), rng.vector3());
+        let [a, b, c] = *triangle.points();
+        let fast = ray.intersect_triangle(a, b, c);
+        let watertight = ray.cast(&triangle);
+        match ray.intersect_plane(a, triangle.normal()) {
+            Some(hit) => {
+                let weights = triangle.barycentric(hit.point);
+                let smallest = weights.x.min(weights.y).min(weights.z);
+                if smallest > 1e-9 {
+                    for other in [fast, watertight] {
+                        let other = other.unwrap();
+                        assert!((other.distance - hit.distance).abs() < 1e-9 * hit.distance.max(1.0));
+                        assert!((other.normal - hit.normal).length() < 1e-9);
+                    }
+                } else if smallest < -1e-9 {
+                    assert_eq!((fast, watertight), (None, None));
+                }
+            }
+            None => assert_eq!((fast, watertight), (None, None)),
+        }
+    }
+}
+
+#[test]
+fn watertight_meshes() {
+    // a grid of triangles with rays going through every shared edge and corner
+    const SIZE: usize = 8;
+    let mut rng = Lcg(4);
+    let heights: Vec<f64> = (0..(SIZE + 1) * (SIZE + 1)).map(|_| rng.next() * 0.1).collect();
+    let vertex = |i: usize, j: usize| Vector3::new(i as f64 / SIZE as f64, j as f64 / SIZE as f64, heights[i * (SIZE + 1) + j]);
+    let mut triangles = Vec::new();
+    for i in 0..SIZE {
+        for j in 0..SIZE {
+            triangles.push(Triangle::new(vertex(i, j), vertex(i + 1, j), vertex(i + 1, j + 1)));
+            triangles.push(Triangle::new(vertex(i, j), vertex(i + 1, j + 1), vertex(i, j + 1)));
+        }
+    }
+    for i in 1..4 * SIZE {
+        for j in 1..4 * SIZE {
+            let x = i as f64 / (4 * SIZE) as f64;
+            let y = j as f64 / (4 * SIZE) as f64;
+            for direction in [Vector3::new(0.0, 0.0, -1.0), Vector3::new(0.1, -0.2, -1.0), Vector3::new(0.0, 0.0, 1.0)] {
+                let ray = Ray3::new(Vector3::new(x, y, 0.0) - direction, direction);
+                let hits = triangles.iter().filter(|triangle| ray.cast(*triangle).is_some()).count();
+                assert!(hits > 0, "the ray through {} {} slipped through", x, y);
+            }
+        }
+    }
+}
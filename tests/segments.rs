@@ -0,0 +1,243 @@
+use drowsed_math::{CubicSegment, EuclideanGeometry, FlatSegment, LinearSegment, QuadraticSegment, Segment, SolidSegment, Vector, Vector2, Vector3};
+
+mod common;
+use common::Lcg;
+
+/// finds the closest point on the segment by sampling it densely, returning the
+/// distance to it and the sign the distance should have.
+fn brute_force<S: FlatSegment<VectorType = Vector2<f64>>>(segment: &S, p: Vector2<f64>) -> (f64, f64) {
+    const SAMPLES: usize = 5000;
+    let mut best = f64::MAX;
+    let mut best_t = 0.0;
+    for i in 0..=SAMPLES {
+        let t = i as f64 / SAMPLES as f64;
+        let distance = (segment.interpolate(t) - p).length();
+        if distance < best {
+            best = distance;
+            best_t = t;
+        }
+    }
+    let sign = segment.direction(best_t).cross(segment.interpolate(best_t) - p).signum();
+    (best, sign)
+}
+
+fn assert_matches_brute_force<S: FlatSegment<VectorType = Vector2<f64>>>(segment: &S, p: Vector2<f64>) {
+    let distance = segment.signed_distance(p);
+    let (expected, sign) = brute_force(segment, p);
+    assert!((distance.abs() - expected).abs() < 1e-4, "distance {} expected {} at {:?}", distance, expected, p);
+    // the sign is ambiguous when the point lies on the curve
+    if expected > 1e-3 {
+        assert_eq!(distance.signum(), sign, "wrong sign for distance {} at {:?}", distance, p);
+    }
+}
+
+#[test]
+fn cubic_signed_distance_matches_brute_force() {
+    let mut rng = Lcg(7);
+    for _ in 0..250 {
+        let segment = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        for _ in 0..4 {
+            let p = rng.vector2() * 1.5;
+            assert_matches_brute_force(&segment, p);
+        }
+    }
+}
+
+#[test]
+fn cubic_signed_distance_with_degenerate_control_points() {
+    let mut rng = Lcg(11);
+    let a = Vector2::new(-0.5, -0.25);
+    let b = Vector2::new(0.75, 0.5);
+    let c = Vector2::new(0.25, -0.75);
+    let segments = [
+        // control points on top of the endpoints
+        CubicSegment::new(a, a, b, b),
+        CubicSegment::new(a, a, c, b),
+        CubicSegment::new(a, c, b, b),
+        // all points on a line with the control points in reverse order
+        CubicSegment::new(a, a + (b - a) * 0.75, a + (b - a) * 0.25, b),
+        // a cusp
+        CubicSegment::new(a, b, a, b),
+        // a loop
+        CubicSegment::new(a, Vector2::new(1.5, 1.0), Vector2::new(-1.5, 1.0), b),
+    ];
+    for segment in segments.iter() {
+        for _ in 0..100 {
+            let p = rng.vector2() * 1.5;
+            assert_matches_brute_force(segment, p);
+        }
+    }
+}
+
+#[test]
+fn cubic_direction_of_degenerate_endpoints() {
+    let a = Vector2::new(0.0, 0.0);
+    let b = Vector2::new(1.0, 0.0);
+    let c = Vector2::new(1.0, 1.0);
+    // falls back to the next control point
+    let segment = CubicSegment::new(a, a, b, c);
+    assert_eq!(segment.direction(0.0), b - a);
+    assert_eq!(segment.direction(1.0), c - b);
+    // falls back to the other endpoint when both control points overlap
+    let segment = CubicSegment::new(a, a, a, c);
+    assert_eq!(segment.direction(0.0), c - a);
+    assert_eq!(segment.direction(1.0), c - a);
+}
+
+// with the experimental feature the quadratic distance is the approximate sd_bezier
+#[test]
+#[cfg(not(feature = "experimental"))]
+fn elevated_quadratic_has_the_same_distance() {
+    let mut rng = Lcg(3);
+    for _ in 0..200 {
+        let (p0, p1, p2) = (rng.vector2(), rng.vector2(), rng.vector2());
+        let quadratic = QuadraticSegment::new(p0, p1, p2);
+        let cubic = quadratic.elevate();
+        let p = rng.vector2() * 1.5;
+        assert!((quadratic.signed_distance(p) - cubic.signed_distance(p)).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn pseudo_distance_extends_endpoints() {
+    let start = Vector2::new(0.0f64, 0.0);
+    let end = Vector2::new(1.0, 0.0);
+    let linear = LinearSegment::new(start, end);
+    let quadratic = QuadraticSegment::new(start, Vector2::new(0.5, 0.0), end);
+    let cubic = CubicSegment::new(start, Vector2::new(0.25, 0.0), Vector2::new(0.75, 0.0), end);
+    // beyond the end point the pseudo distance is measured to the extended line
+    let p = Vector2::new(2.0, -0.5);
+    assert!((linear.signed_distance(p).abs() - 1.25f64.sqrt()).abs() < 1e-9);
+    assert!((linear.signed_pseudo_distance(p) - 0.5).abs() < 1e-9);
+    assert!((quadratic.signed_pseudo_distance(p) - 0.5).abs() < 1e-9);
+    assert!((cubic.signed_pseudo_distance(p) - 0.5).abs() < 1e-9);
+    // before the start point
+    let p = Vector2::new(-1.0, 0.25);
+    assert!((linear.signed_pseudo_distance(p) + 0.25).abs() < 1e-9);
+    assert!((quadratic.signed_pseudo_distance(p) + 0.25).abs() < 1e-9);
+    assert!((cubic.signed_pseudo_distance(p) + 0.25).abs() < 1e-9);
+    // inside of the segment both distances are the same
+    let p = Vector2::new(0.5, 0.3);
+    assert_eq!(cubic.signed_pseudo_distance(p), cubic.signed_distance(p));
+}
+
+#[test]
+fn split_and_subsegment_trace_the_same_curve() {
+    let mut rng = Lcg(5);
+    for _ in 0..100 {
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let t = (rng.next() + 1.0) * 0.5;
+        let (before, after) = cubic.split_at(t);
+        let part = cubic.subsegment(0.8, 0.3);
+        for i in 0..=10 {
+            let u = i as f64 / 10.0;
+            assert!((before.interpolate(u) - cubic.interpolate(u * t)).length() < 1e-12);
+            assert!((after.interpolate(u) - cubic.interpolate(t + u * (1.0 - t))).length() < 1e-12);
+            assert!((part.interpolate(u) - cubic.interpolate(0.8 - 0.5 * u)).length() < 1e-12);
+            assert!((cubic.reverse().interpolate(u) - cubic.interpolate(1.0 - u)).length() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn elevated_quadratic_traces_the_same_curve() {
+    let mut rng = Lcg(13);
+    for _ in 0..100 {
+        let quadratic = QuadraticSegment::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let cubic = quadratic.elevate();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!((quadratic.interpolate(t) - cubic.interpolate(t)).length() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn cubic_to_quadratics_stays_within_tolerance() {
+    let mut rng = Lcg(17);
+    for _ in 0..100 {
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        for tolerance in [0.1, 0.01, 0.001] {
+            let quadratics = cubic.to_quadratics(tolerance);
+            let count = quadratics.len() as f64;
+            for (i, quadratic) in quadratics.iter().enumerate() {
+                for j in 0..=20 {
+                    let t = j as f64 / 20.0;
+                    let error = (quadratic.interpolate(t) - cubic.interpolate((i as f64 + t) / count)).length();
+                    assert!(error <= tolerance, "error {} with tolerance {}", error, tolerance);
+                }
+            }
+        }
+    }
+    // a quadratic elevated into a cubic only needs a single quadratic
+    let quadratic = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(2.0, 0.0));
+    let quadratics = quadratic.elevate().to_quadratics(1e-6);
+    assert_eq!(quadratics.len(), 1);
+    assert!((*quadratics[0].get_point(1) - *quadratic.get_point(1)).length() < 1e-12);
+    // tolerances that are too small or not positive are clamped instead of allocating without bound
+    let cubic = CubicSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1e30, 0.0), Vector2::new(0.0, 1e30), Vector2::new(1.0, 1.0));
+    assert_eq!(cubic.to_quadratics(f64::MIN_POSITIVE).len(), 1024);
+    assert_eq!(cubic.to_quadratics(0.0).len(), 1);
+    assert_eq!(cubic.to_quadratics(-1.0).len(), 1);
+}
+
+#[test]
+fn arc_length_of_unevenly_parameterized_line() {
+    // every control point but the last is at the start so the speed changes along the curve
+    let zero = Vector2::new(0.0f64, 0.0);
+    let end = Vector2::new(3.0, 4.0);
+    let cubic = CubicSegment::new(zero, zero, zero, end);
+    assert!((cubic.arc_length(1e-12) - 5.0).abs() < 1e-10);
+    for length in [0.0, 0.5, 2.5, 4.9, 5.0] {
+        let t = cubic.t_at_length(length);
+        assert!((cubic.interpolate(t).length() - length).abs() < 1e-10);
+    }
+    assert_eq!(cubic.t_at_length(-1.0), 0.0);
+    assert_eq!(cubic.t_at_length(10.0), 1.0);
+}
+
+#[test]
+fn arc_length_matches_polyline() {
+    let mut rng = Lcg(19);
+    for _ in 0..20 {
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        const STEPS: usize = 100000;
+        let mut polyline = 0.0;
+        for i in 0..STEPS {
+            polyline += (cubic.interpolate((i + 1) as f64 / STEPS as f64) - cubic.interpolate(i as f64 / STEPS as f64)).length();
+        }
+        assert!((cubic.arc_length(1e-10) - polyline).abs() < 1e-8);
+        // constant speed traversal lands on points that are evenly spaced along the curve
+        let t = cubic.t_at_length(polyline * 0.25);
+        let steps = (t * STEPS as f64) as usize;
+        let mut partial = 0.0;
+        for i in 0..steps {
+            partial += (cubic.interpolate((i + 1) as f64 / STEPS as f64) - cubic.interpolate(i as f64 / STEPS as f64)).length();
+        }
+        partial += (cubic.interpolate(t) - cubic.interpolate(steps as f64 / STEPS as f64)).length();
+        assert!((partial - polyline * 0.25).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn curvature_and_normal_of_circle() {
+    // the common cubic approximation of a quarter of the unit circle
+    let k = 0.5522847498;
+    let flat = CubicSegment::new(Vector2::new(1.0f64, 0.0), Vector2::new(1.0, k), Vector2::new(k, 1.0), Vector2::new(0.0, 1.0));
+    let solid = CubicSegment::new(Vector3::new(1.0f64, 0.0, 0.0), Vector3::new(1.0, k, 0.0), Vector3::new(k, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let point = flat.interpolate(t);
+        assert!((flat.curvature(t) - 1.0).abs() < 0.03);
+        assert!((solid.curvature(t) - flat.curvature(t)).abs() < 1e-12);
+        // the normal points towards the center of the circle
+        assert!((flat.normal(t) + point.normalize()).length() < 0.01);
+        let normal = solid.normal(t);
+        assert!((normal.x - flat.normal(t).x).abs() < 1e-9 && (normal.y - flat.normal(t).y).abs() < 1e-9 && normal.z == 0.0);
+    }
+    // turning clockwise gives a negative curvature
+    assert!(flat.reverse().curvature(0.5) < 0.0);
+    // straight lines have no curvature
+    let line = LinearSegment::new(Vector3::new(0.0f64, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(line.curvature(0.5), 0.0);
+}
@@ -0,0 +1,225 @@
+use drowsed_math::{BSpline, CatmullRomParameterization, CatmullRomSpline, CubicSegment, HermiteSegment, Nurbs, Segment, Vector, Vector2, Vector3};
+
+mod common;
+use common::Lcg;
+
+/// the b-spline basis function i of the degree at u from the Cox–de Boor recursion, with the
+/// last span including the end of the domain.
+fn basis(knots: &[f64], i: usize, degree: usize, u: f64, end: f64) -> f64 {
+    if degree == 0 {
+        let inside = knots[i] <= u && u < knots[i + 1] && u < end;
+        let at_end = u == end && knots[i] < knots[i + 1] && knots[i + 1] == end;
+        return if inside || at_end { 1.0 } else { 0.0 };
+    }
+    let mut value = 0.0;
+    let left = knots[i + degree] - knots[i];
+    if left > 0.0 {
+        value += (u - knots[i]) / left * basis(knots, i, degree - 1, u, end);
+    }
+    let right = knots[i + degree + 1] - knots[i + 1];
+    if right > 0.0 {
+        value += (knots[i + degree + 1] - u) / right * basis(knots, i + 1, degree - 1, u, end);
+    }
+    value
+}
+fn brute_bspline(spline: &BSpline<Vector3<f64>>, u: f64) -> Vector3<f64> {
+    let end = spline.domain().1;
+    spline.control_points().iter().enumerate()
+        .fold(Vector3::new(0.0, 0.0, 0.0), |sum, (i, &p)| sum + p * basis(spline.knots(), i, spline.degree(), u, end))
+}
+fn random_knots(rng: &mut Lcg, count: usize) -> Vec<f64> {
+    let mut knots: Vec<f64> = (0..count).map(|_| (rng.next() * 4.0).round() / 2.0).collect();
+    knots.sort_by(f64::total_cmp);
+    knots
+}
+
+#[test]
+fn hermite_segments_match_their_cubics() {
+    let mut rng = Lcg(1);
+    for _ in 0..50 {
+        let hermite = HermiteSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let cubic = hermite.to_cubic();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!((hermite.interpolate(t) - cubic.interpolate(t)).length() < 1e-12);
+            assert!((hermite.derivative(t) - cubic.derivative(t)).length() < 1e-12);
+            assert!((hermite.second_derivative(t) - cubic.second_derivative(t)).length() < 1e-12);
+        }
+        assert!((hermite.derivative(0.0) - hermite.tangents()[0]).length() < 1e-12);
+        assert!((hermite.derivative(1.0) - hermite.tangents()[1]).length() < 1e-12);
+        let back = HermiteSegment::from(cubic);
+        assert!((back.tangents()[0] - hermite.tangents()[0]).length() < 1e-12);
+        assert!((back.tangents()[1] - hermite.tangents()[1]).length() < 1e-12);
+        let reversed = hermite.reverse();
+        assert!((reversed.interpolate(0.3) - hermite.interpolate(0.7)).length() < 1e-12);
+    }
+}
+
+#[test]
+fn catmull_rom_splines_go_through_their_points() {
+    let mut rng = Lcg(2);
+    for parameterization in [CatmullRomParameterization::Uniform, CatmullRomParameterization::Centripetal, CatmullRomParameterization::Chordal] {
+        let points: Vec<_> = (0..7).map(|_| rng.vector3()).collect();
+        let spline = CatmullRomSpline::new(points.clone(), parameterization);
+        assert_eq!(spline.segment_count(), 4);
+        let cubics: Vec<CubicSegment<_>> = spline.segments().collect();
+        for (i, cubic) in cubics.iter().enumerate() {
+            assert_eq!(*cubic.start(), points[i + 1]);
+            assert_eq!(*cubic.end(), points[i + 2]);
+        }
+        // the spline is smooth where the segments meet
+        for pair in cubics.windows(2) {
+            let (a, b) = (pair[0].derivative(1.0), pair[1].derivative(0.0));
+            assert!((a.normalize() - b.normalize()).length() < 1e-9);
+        }
+        for i in 0..=4 {
+            assert!((spline.interpolate(i as f64 / 4.0).unwrap() - points[i + 1]).length() < 1e-12);
+        }
+    }
+    // the uniform tangents are half the difference of the neighbours
+    let points: Vec<_> = (0..4).map(|_| rng.vector2()).collect();
+    let uniform = CatmullRomSpline::new(points.clone(), CatmullRomParameterization::Uniform).segment(0);
+    assert!((uniform.tangents()[0] - (points[2] - points[0]) * 0.5).length() < 1e-12);
+    assert!((uniform.tangents()[1] - (points[3] - points[1]) * 0.5).length() < 1e-12);
+    // repeated points don't divide by zero
+    let repeated = CatmullRomSpline::new(vec![points[0], points[0], points[1], points[1]], CatmullRomParameterization::Centripetal);
+    assert!(repeated.interpolate(0.5).unwrap().length().is_finite());
+    assert!(CatmullRomSpline::new(points[..3].to_vec(), CatmullRomParameterization::Chordal).interpolate(0.5).is_none());
+}
+
+#[test]
+fn de_boor_matches_the_basis_functions() {
+    let mut rng = Lcg(3);
+    for degree in 0..=5 {
+        for _ in 0..10 {
+            let count = degree + 1 + (rng.next().abs() * 5.0) as usize;
+            let points: Vec<_> = (0..count).map(|_| rng.vector3()).collect();
+            let spline = BSpline::new(degree, points, random_knots(&mut rng, count + degree + 1)).unwrap();
+            let (start, end) = spline.domain();
+            if start >= end {
+                continue;
+            }
+            for i in 0..=20 {
+                let u = start + (end - start) * i as f64 / 20.0;
+                let expected = brute_bspline(&spline, u);
+                assert!((spline.interpolate(u) - expected).length() < 1e-9, "{:?} at {}", spline, u);
+                // the derivative against central differences, away from the knots where it can jump
+                if degree > 0 && i > 0 && i < 20 && spline.knots().iter().all(|&k| (k - u).abs() > 1e-3) {
+                    let h = 1e-6;
+                    let difference = (spline.interpolate(u + h) - spline.interpolate(u - h)) / (2.0 * h);
+                    assert!((spline.derivative(u) - difference).length() < 1e-4 * (1.0 + difference.length()));
+                }
+            }
+        }
+    }
+    assert!(BSpline::<Vector2<f64>>::new(2, vec![Vector2::new(0.0, 0.0); 3], vec![0.0; 5]).is_none());
+    assert!(BSpline::<Vector2<f64>>::new(1, vec![Vector2::new(0.0, 0.0); 2], vec![0.0, 1.0, 0.5, 2.0]).is_none());
+    assert!(BSpline::<Vector2<f64>>::clamped(3, vec![Vector2::new(0.0, 0.0); 3]).is_none());
+}
+
+#[test]
+fn clamped_splines_touch_their_ends() {
+    let mut rng = Lcg(4);
+    for degree in 1..=4 {
+        let points: Vec<_> = (0..degree + 4).map(|_| rng.vector2()).collect();
+        let spline = BSpline::clamped(degree, points.clone()).unwrap();
+        assert_eq!(spline.domain(), (0.0, 1.0));
+        assert!((spline.interpolate(0.0) - points[0]).length() < 1e-12);
+        assert!((spline.interpolate(1.0) - points[points.len() - 1]).length() < 1e-12);
+        // inserting knots keeps the curve
+        let mut refined = spline.clone();
+        for _ in 0..5 {
+            refined.insert_knot(rng.next().abs());
+        }
+        assert_eq!(refined.control_points().len(), points.len() + 5);
+        for i in 0..=50 {
+            let u = i as f64 / 50.0;
+            assert!((refined.interpolate(u) - spline.interpolate(u)).length() < 1e-12);
+        }
+        let uniform = BSpline::uniform(degree, points).unwrap();
+        assert_eq!(uniform.domain(), (degree as f64, (degree + 4) as f64));
+    }
+}
+
+#[test]
+fn cubics_round_trip_through_bsplines() {
+    let mut rng = Lcg(5);
+    let mut start = rng.vector2();
+    let cubics: Vec<_> = (0..5).map(|_| {
+        let cubic = CubicSegment::new(start, rng.vector2(), rng.vector2(), rng.vector2());
+        start = *cubic.end();
+        cubic
+    }).collect();
+    let spline = BSpline::from_cubics(&cubics).unwrap();
+    assert_eq!(spline.domain(), (0.0, 5.0));
+    for (i, cubic) in cubics.iter().enumerate() {
+        for j in 0..=10 {
+            let t = j as f64 / 10.0;
+            assert!((spline.interpolate(i as f64 + t) - cubic.interpolate(t)).length() < 1e-12);
+        }
+    }
+    let back = spline.to_cubics().unwrap();
+    assert_eq!(back.len(), cubics.len());
+    for (a, b) in back.iter().zip(&cubics) {
+        for j in 0..4 {
+            assert!((*a.get_point(j) - *b.get_point(j)).length() < 1e-12);
+        }
+    }
+    assert!(BSpline::from_cubics(&[cubics[0], cubics[2]]).is_none());
+    // every span of a spline of a lower degree becomes a cubic tracing it, skipping empty spans
+    for degree in 1..=3 {
+        let points: Vec<_> = (0..7).map(|_| rng.vector3()).collect();
+        let spline = BSpline::new(degree, points, random_knots(&mut rng, 8 + degree)).unwrap();
+        let (start, end) = spline.domain();
+        let cubics = spline.to_cubics().unwrap();
+        let spans: Vec<_> = spline.knots().windows(2).skip(degree).take(7 - degree).filter(|k| k[0] < k[1]).map(|k| (k[0], k[1])).collect();
+        assert_eq!(cubics.len(), spans.len());
+        for (cubic, (a, b)) in cubics.iter().zip(spans) {
+            assert!(start <= a && b <= end);
+            // repeated knots can make the spline jump at the end of a span, so stop just before it
+            for j in 0..10 {
+                let t = j as f64 / 10.0;
+                assert!((cubic.interpolate(t) - spline.interpolate(a + (b - a) * t)).length() < 1e-12);
+            }
+        }
+    }
+    let quartic = BSpline::clamped(4, (0..5).map(|_| rng.vector2()).collect()).unwrap();
+    assert!(quartic.to_cubics().is_none());
+}
+
+#[test]
+fn nurbs_trace_circles() {
+    let half = std::f64::consts::FRAC_1_SQRT_2;
+    let quarter = Nurbs::new(
+        2,
+        vec![Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(0.0, 1.0)],
+        vec![1.0, half, 1.0],
+        vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+    ).unwrap();
+    for i in 0..=100 {
+        let u = i as f64 / 100.0;
+        let p = quarter.interpolate(u);
+        assert!((p.length() - 1.0).abs() < 1e-12);
+        // moving around a circle is always perpendicular to the radius
+        let velocity = quarter.derivative(u);
+        assert!(p.dot(&velocity).abs() < 1e-12);
+        let h = 1e-6;
+        if i > 0 && i < 100 {
+            let difference = (quarter.interpolate(u + h) - quarter.interpolate(u - h)) / (2.0 * h);
+            assert!((velocity - difference).length() < 1e-6);
+        }
+    }
+    assert!(quarter.to_bspline().is_none());
+    assert!(Nurbs::new(1, vec![Vector2::new(0.0, 0.0); 2], vec![1.0, 0.0], vec![0.0, 0.0, 1.0, 1.0]).is_none());
+    // with equal weights it's the same as the spline
+    let mut rng = Lcg(6);
+    let spline = BSpline::clamped(3, (0..6).map(|_| rng.vector3()).collect()).unwrap();
+    let nurbs = Nurbs::new(3, spline.control_points().to_vec(), vec![2.5; 6], spline.knots().to_vec()).unwrap();
+    for i in 0..=20 {
+        let u = i as f64 / 20.0;
+        assert!((nurbs.interpolate(u) - spline.interpolate(u)).length() < 1e-12);
+        assert!((nurbs.derivative(u) - spline.derivative(u)).length() < 1e-9);
+    }
+    assert_eq!(nurbs.to_bspline(), Some(spline.clone()));
+    assert_eq!(Nurbs::from(spline.clone()).interpolate(0.25), spline.interpolate(0.25));
+}
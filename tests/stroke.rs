@@ -0,0 +1,173 @@
+use drowsed_math::{Contour, CubicSegment, Edge, FillRule, LineCap, LineJoin, LinearSegment, QuadraticSegment, Segment, StrokeStyle, Vector, Vector2};
+
+mod common;
+use common::Lcg;
+
+fn polyline(points: &[(f64, f64)], closed: bool) -> Contour<f64> {
+    let mut contour = Contour::new();
+    let count = if closed { points.len() } else { points.len() - 1 };
+    for i in 0..count {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        contour.push(LinearSegment::new(Vector2::new(a.0, a.1), Vector2::new(b.0, b.1)));
+    }
+    contour
+}
+fn style(width: f64, join: LineJoin<f64>, cap: LineCap) -> StrokeStyle<f64> {
+    StrokeStyle { join, cap, ..StrokeStyle::new(width) }
+}
+/// the distance from p to the closest densely sampled point of the edges.
+fn brute_distance(edges: &[Edge<f64>], p: Vector2<f64>) -> f64 {
+    const SAMPLES: usize = 1000;
+    edges.iter().flat_map(|edge| (0..=SAMPLES).map(move |i| (edge.interpolate(i as f64 / SAMPLES as f64) - p).length())).fold(f64::MAX, f64::min)
+}
+
+#[test]
+fn offset_curves_stay_at_the_distance() {
+    let mut rng = Lcg(7);
+    for i in 0..40 {
+        let distance = [0.05, -0.1, 0.02][i % 3];
+        let cubic = CubicSegment::new(rng.vector2(), rng.vector2(), rng.vector2(), rng.vector2());
+        let offsets = cubic.offset(distance, 1e-3);
+        let n0 = cubic.derivative(0.0).normalize();
+        let start = *cubic.start() + Vector2::new(-n0.y, n0.x) * distance;
+        assert!((*offsets[0].start() - start).length() < 1e-12);
+        for pair in offsets.windows(2) {
+            assert_eq!(pair[0].end(), pair[1].start());
+        }
+        // every point of the approximation is close to the exact offset
+        let exact: Vec<Vector2<f64>> = (0..=5000).map(|j| {
+            let t = j as f64 / 5000.0;
+            let n = cubic.derivative(t).normalize();
+            cubic.interpolate(t) + Vector2::new(-n.y, n.x) * distance
+        }).collect();
+        for offset in &offsets {
+            for j in 0..=10 {
+                let p = offset.interpolate(j as f64 / 10.0);
+                let error = exact.iter().map(|q| (*q - p).length()).fold(f64::MAX, f64::min);
+                assert!(error < 2e-3, "{:?} offset by {} is {} off", cubic, distance, error);
+            }
+        }
+    }
+    // quadratics are offset as cubics, lines stay lines
+    let quadratic = QuadraticSegment::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 0.0));
+    let edges = [Edge::Quadratic(quadratic)];
+    for offset in quadratic.offset(0.1, 1e-4) {
+        let p = offset.interpolate(0.5);
+        assert!((brute_distance(&edges, p) - 0.1).abs() < 1e-3);
+    }
+    let line = LinearSegment::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0)).offset(0.5);
+    assert_eq!(line, LinearSegment::new(Vector2::new(0.0, 0.5), Vector2::new(2.0, 0.5)));
+}
+
+#[test]
+fn caps_of_an_open_line() {
+    let line = polyline(&[(0.0, 0.0), (4.0, 0.0)], false);
+    let butt = line.stroke(&style(1.0, LineJoin::Bevel, LineCap::Butt));
+    assert_eq!(butt.contours().len(), 1);
+    assert!(butt.is_closed());
+    assert!((butt.signed_area().abs() - 4.0).abs() < 1e-12);
+    let square = line.stroke(&style(1.0, LineJoin::Bevel, LineCap::Square));
+    assert!((square.signed_area().abs() - 5.0).abs() < 1e-12);
+    assert!(square.contains(Vector2::new(-0.4, 0.4), FillRule::NonZero));
+    let round = line.stroke(&style(1.0, LineJoin::Bevel, LineCap::Round));
+    assert!((round.signed_area().abs() - (4.0 + std::f64::consts::PI * 0.25)).abs() < 1e-3);
+    assert!(round.contains(Vector2::new(4.45, 0.0), FillRule::NonZero));
+    assert!(!round.contains(Vector2::new(4.45, 0.4), FillRule::NonZero));
+    // a path without length only draws a dot with round and square caps
+    let dot = polyline(&[(1.0, 1.0), (1.0, 1.0)], false);
+    assert!(dot.stroke(&style(1.0, LineJoin::Bevel, LineCap::Butt)).contours().is_empty());
+    assert!((dot.stroke(&style(1.0, LineJoin::Bevel, LineCap::Square)).signed_area().abs() - 1.0).abs() < 1e-12);
+    assert!((dot.stroke(&style(2.0, LineJoin::Bevel, LineCap::Round)).signed_area().abs() - std::f64::consts::PI).abs() < 1e-2);
+}
+
+#[test]
+fn joins_of_a_closed_square() {
+    let square = polyline(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)], true);
+    // the outside of the stroke is a square 5 wide, minus the corners that are cut off
+    let outside = |join| {
+        let stroke = square.stroke(&style(1.0, join, LineCap::Butt));
+        assert_eq!(stroke.contours().len(), 2);
+        stroke.contours()[1].signed_area().abs()
+    };
+    assert!((outside(LineJoin::Miter(4.0)) - 25.0).abs() < 1e-12);
+    assert!((outside(LineJoin::Bevel) - (25.0 - 4.0 * 0.125)).abs() < 1e-12);
+    assert!((outside(LineJoin::Round) - (25.0 - 4.0 * (0.25 - std::f64::consts::PI / 16.0))).abs() < 1e-3);
+    // the inside of the square isn't filled while the stroke is, whichever way the square goes
+    for square in [square.clone(), { let mut reversed = square.clone(); reversed.reverse(); reversed }] {
+        let stroke = square.stroke(&style(1.0, LineJoin::Miter(4.0), LineCap::Butt));
+        assert!(!stroke.contains(Vector2::new(2.0, 2.0), FillRule::NonZero));
+        assert!(stroke.contains(Vector2::new(2.0, 0.4), FillRule::NonZero));
+        assert!(stroke.contains(Vector2::new(-0.45, -0.45), FillRule::NonZero));
+        assert!(!stroke.contains(Vector2::new(2.0, -0.6), FillRule::NonZero));
+    }
+}
+
+#[test]
+fn miters_past_the_limit_are_beveled() {
+    // turning back at an angle of about 6 degrees needs a miter about 20 times half the width
+    let sharp = polyline(&[(0.0, 0.0), (10.0, 0.0), (0.0, 1.0)], false);
+    let tip = Vector2::new(13.0, -0.1);
+    let mitered = sharp.stroke(&style(1.0, LineJoin::Miter(25.0), LineCap::Butt));
+    assert!(mitered.contains(tip, FillRule::NonZero));
+    let beveled = sharp.stroke(&style(1.0, LineJoin::Miter(4.0), LineCap::Butt));
+    assert!(!beveled.contains(tip, FillRule::NonZero));
+    assert!(!beveled.contains(Vector2::new(10.2, 0.0), FillRule::NonZero));
+    assert!(beveled.contains(Vector2::new(9.9, -0.4), FillRule::NonZero));
+}
+
+#[test]
+fn round_strokes_cover_everything_within_half_the_width() {
+    let mut rng = Lcg(11);
+    for _ in 0..6 {
+        let mut contour = Contour::new();
+        let mut start = rng.vector2();
+        for _ in 0..3 {
+            let cubic = CubicSegment::new(start, rng.vector2(), rng.vector2(), rng.vector2());
+            start = *cubic.end();
+            contour.push(cubic);
+        }
+        let half = 0.1;
+        let stroke = contour.stroke(&StrokeStyle { tolerance: 1e-4, ..style(2.0 * half, LineJoin::Round, LineCap::Round) });
+        for _ in 0..200 {
+            let p = rng.vector2() * 1.2;
+            let distance = brute_distance(contour.edges(), p);
+            if (distance - half).abs() < 5e-3 {
+                continue;
+            }
+            assert_eq!(stroke.contains(p, FillRule::NonZero), distance < half, "{:?} is {} from the path", p, distance);
+        }
+    }
+}
+
+#[test]
+fn dashes() {
+    let line = polyline(&[(0.0, 0.0), (10.0, 0.0)], false);
+    let dashed = |dashes: Vec<f64>, offset: f64| line.stroke(&StrokeStyle { dashes, dash_offset: offset, ..StrokeStyle::new(1.0) });
+    // dashes over 0-2, 3-5, 6-8 and 9-10
+    let stroke = dashed(vec![2.0, 1.0], 0.0);
+    assert_eq!(stroke.contours().len(), 4);
+    assert!((stroke.signed_area().abs() - 7.0).abs() < 1e-9);
+    assert!(!stroke.contains(Vector2::new(2.5, 0.0), FillRule::NonZero));
+    assert!(stroke.contains(Vector2::new(3.5, 0.0), FillRule::NonZero));
+    // starting a unit into the pattern gives dashes over 0-1, 2-4, 5-7 and 8-10
+    let stroke = dashed(vec![2.0, 1.0], 1.0);
+    assert_eq!(stroke.contours().len(), 4);
+    assert!((stroke.signed_area().abs() - 7.0).abs() < 1e-9);
+    assert!(!stroke.contains(Vector2::new(1.5, 0.0), FillRule::NonZero));
+    // negative offsets wrap around the pattern
+    assert_eq!(dashed(vec![2.0, 1.0], -2.0), stroke);
+    // an odd pattern is repeated, so this is 1 on, 1 off, 1 on, 1 off, ...
+    assert_eq!(dashed(vec![1.0], 0.0).contours().len(), 5);
+    // patterns that can't dash draw a solid line
+    assert_eq!(dashed(vec![1.0, -1.0], 0.0).contours().len(), 1);
+    assert_eq!(dashed(vec![0.0, 0.0], 0.0).contours().len(), 1);
+    // a dash over the end of a closed path carries on into the first dash
+    let square = polyline(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)], true);
+    let stroke = square.stroke(&StrokeStyle { dashes: vec![3.0, 1.0], dash_offset: 2.0, ..StrokeStyle::new(0.5) });
+    assert_eq!(stroke.contours().len(), 4);
+    assert!(stroke.contains(Vector2::new(0.0, 0.5), FillRule::NonZero));
+    assert!(stroke.contains(Vector2::new(0.5, 0.0), FillRule::NonZero));
+    // a dash covering the whole closed path strokes it as a loop
+    let stroke = square.stroke(&StrokeStyle { dashes: vec![20.0, 1.0], ..StrokeStyle::new(0.5) });
+    assert_eq!(stroke.contours().len(), 2);
+}
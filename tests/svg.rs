@@ -0,0 +1,131 @@
+use drowsed_math::{parse_svg_path, to_svg_path, Edge, Shape, SvgPathError, SvgPathErrorKind, Vector2};
+
+fn parse(data: &str) -> Shape<f64> {
+    parse_svg_path(data).unwrap()
+}
+fn points(shape: &Shape<f64>) -> Vec<Vec<(f64, f64)>> {
+    shape.edges().map(|edge| edge.points().iter().map(|p| (p.x, p.y)).collect()).collect()
+}
+fn error(data: &str) -> SvgPathError {
+    parse_svg_path::<f64>(data).unwrap_err()
+}
+
+#[test]
+fn absolute_and_relative_commands() {
+    let absolute = parse("M1 1 L3 1 H5 V3 C5 4 4 5 3 5 Q1 5 1 3 Z");
+    let relative = parse("m1 1 l2 0 h2 v2 c0 1-1 2-2 2 q-2 0-2-2 z");
+    assert_eq!(points(&absolute), points(&relative));
+    assert_eq!(points(&absolute), vec![
+        vec![(1.0, 1.0), (3.0, 1.0)],
+        vec![(3.0, 1.0), (5.0, 1.0)],
+        vec![(5.0, 1.0), (5.0, 3.0)],
+        vec![(5.0, 3.0), (5.0, 4.0), (4.0, 5.0), (3.0, 5.0)],
+        vec![(3.0, 5.0), (1.0, 5.0), (1.0, 3.0)],
+        vec![(1.0, 3.0), (1.0, 1.0)],
+    ]);
+    assert!(absolute.is_closed());
+    assert_eq!(absolute.contours().len(), 1);
+}
+
+#[test]
+fn implicit_commands_and_separators() {
+    // numbers after a move to are lines, and repeated commands can leave out the letter
+    let shape = parse("M0,0 1,0 1-1e0.5.5l1 1 1 1");
+    assert_eq!(points(&shape), vec![
+        vec![(0.0, 0.0), (1.0, 0.0)],
+        vec![(1.0, 0.0), (1.0, -1.0)],
+        vec![(1.0, -1.0), (0.5, 0.5)],
+        vec![(0.5, 0.5), (1.5, 1.5)],
+        vec![(1.5, 1.5), (2.5, 2.5)],
+    ]);
+    assert!(!shape.is_closed());
+    // a relative move to after a close starts from the start of the closed subpath
+    let shape = parse("M1 1h1v1z m1 0h1v1z");
+    assert_eq!(shape.contours().len(), 2);
+    assert_eq!(shape.contours()[1].edges()[0].start(), Vector2::new(2.0, 1.0));
+}
+
+#[test]
+fn smooth_curves_reflect_control_points() {
+    let shape = parse("M0 0C0 1 1 1 1 0S2-1 2 0");
+    assert_eq!(points(&shape)[1], vec![(1.0, 0.0), (1.0, -1.0), (2.0, -1.0), (2.0, 0.0)]);
+    let shape = parse("M0 0Q1 1 2 0T4 0t2 0");
+    assert_eq!(points(&shape)[1], vec![(2.0, 0.0), (3.0, -1.0), (4.0, 0.0)]);
+    assert_eq!(points(&shape)[2], vec![(4.0, 0.0), (5.0, 1.0), (6.0, 0.0)]);
+    // without a previous curve the first control point is the current point
+    let shape = parse("M0 0L1 0S2 1 3 0");
+    assert_eq!(points(&shape)[1][1], (1.0, 0.0));
+}
+
+#[test]
+fn arcs_become_cubics_on_the_ellipse() {
+    // a circle of radius 2 around (3, 0) from two half arcs
+    let shape = parse("M1 0A2 2 0 0 0 5 0A2 2 0 0 0 1 0Z");
+    assert!(shape.is_closed());
+    assert!(shape.edges().all(|edge| matches!(edge, Edge::Cubic(_))));
+    assert_eq!(shape.edges().count(), 4);
+    assert!((shape.signed_area() + 4.0 * std::f64::consts::PI).abs() < 1e-2);
+    for edge in shape.edges() {
+        for i in 0..=20 {
+            let p = edge.interpolate(i as f64 / 20.0);
+            let radius = ((p.x - 3.0).powi(2) + p.y * p.y).sqrt();
+            assert!((radius - 2.0).abs() < 1e-3, "{:?} is {} from the center", p, radius);
+        }
+    }
+    // a sweep flag of 0 goes towards negative angles, so with y pointing up the circle is clockwise
+    assert!(shape.contours()[0].edges()[0].interpolate(0.5).y > 0.0);
+    // radii that are too small are scaled up so the arc is a half ellipse, rotated by 90 degrees and
+    // sweeping towards positive angles around (0, 2)
+    let shape = parse("M0 0a1 .5 90 0 1 0 4");
+    let end = shape.edges().last().unwrap().end();
+    assert!((end.x - 0.0).abs() < 1e-12 && (end.y - 4.0).abs() < 1e-12);
+    for edge in shape.edges() {
+        for i in 0..=20 {
+            let p = edge.interpolate(i as f64 / 20.0);
+            let r = (p.x / 1.0).powi(2) + ((p.y - 2.0) / 2.0).powi(2);
+            assert!((r - 1.0).abs() < 2e-3, "{:?}", p);
+            assert!(p.x >= -1e-9);
+        }
+    }
+    // a zero radius is a line
+    assert_eq!(points(&parse("M0 0A0 1 0 0 0 2 2")), vec![vec![(0.0, 0.0), (2.0, 2.0)]]);
+}
+
+#[test]
+fn errors_point_at_the_problem() {
+    assert_eq!(error("L1 2"), SvgPathError { offset: 0, kind: SvgPathErrorKind::MissingMoveTo });
+    assert_eq!(error("  10 10"), SvgPathError { offset: 2, kind: SvgPathErrorKind::MissingMoveTo });
+    assert_eq!(error("M1 2 L3"), SvgPathError { offset: 7, kind: SvgPathErrorKind::ExpectedNumber });
+    assert_eq!(error("M1 2 L3 x"), SvgPathError { offset: 8, kind: SvgPathErrorKind::ExpectedNumber });
+    assert_eq!(error("M1 2 X3 4"), SvgPathError { offset: 5, kind: SvgPathErrorKind::InvalidCommand('X') });
+    assert_eq!(error("M1 2Z 3 4"), SvgPathError { offset: 6, kind: SvgPathErrorKind::InvalidCommand('3') });
+    assert_eq!(error("M0 0A1 1 0 2 0 1 1"), SvgPathError { offset: 11, kind: SvgPathErrorKind::ExpectedFlag });
+    assert_eq!(error("M1 2 L3").to_string(), "expected a number at byte 7");
+}
+
+#[test]
+fn compact_output() {
+    assert_eq!(to_svg_path(&parse("M0 0 L1 0 L1 1 L0.5 1.5 Z")), "M0 0H1V1L.5 1.5Z");
+    assert_eq!(to_svg_path(&parse("M0 0 L-0.5 -0.25 L2 2 L3 3")), "M0 0-.5-.25 2 2 3 3");
+    assert_eq!(to_svg_path(&parse("M0 0Q1 1 2 0Q3-1 4 0M5 5C6 6 7 6 8 5z")), "M0 0Q1 1 2 0 3-1 4 0M5 5C6 6 7 6 8 5Z");
+    assert_eq!(to_svg_path(&parse("M.5.5 .25.75")), "M.5.5.25.75");
+    // f32 coordinates are written with their own precision instead of picking up digits from f64
+    let shape: Shape<f32> = parse_svg_path("M0.1 0.2L0.3 0.7").unwrap();
+    assert_eq!(to_svg_path(&shape), "M.1.2.3.7");
+    assert_eq!(to_svg_path::<f64>(&Shape::new()), "");
+}
+
+#[test]
+fn round_trip() {
+    let data = [
+        "M1 1 L3 1 H5 V3 C5 4 4 5 3 5 Q1 5 1 3 Z",
+        "m10 10 a5 3 30 1 1 4 4 t2-2 s1 1 3 0 z M-1-1 h-2 v-2",
+        "M0 0c.1.2.3.4.5.6 7 8 9 10 11 12z",
+    ];
+    for data in data {
+        let shape = parse(data);
+        let written = to_svg_path(&shape);
+        assert_eq!(parse(&written), shape, "{} was written as {}", data, written);
+        assert_eq!(to_svg_path(&parse(&written)), written);
+    }
+}
@@ -0,0 +1,147 @@
+use drowsed_math::{barycentric_coordinates, EuclideanGeometry, Triangle, Vector, Vector2, Vector3};
+
+mod common;
+use common::Lcg;
+
+/// the closest of densely sampled points of the triangle.
+fn brute_closest<V: Vector<Scalar = f64>>(triangle: &Triangle<V>, p: V) -> V {
+    const SAMPLES: usize = 300;
+    let mut closest = triangle[0];
+    for i in 0..=SAMPLES {
+        for j in 0..=SAMPLES - i {
+            let (u, v) = (i as f64 / SAMPLES as f64, j as f64 / SAMPLES as f64);
+            let q = triangle.interpolate(Vector3::new(1.0 - u - v, u, v));
+            if (q - p).length() < (closest - p).length() {
+                closest = q;
+            }
+        }
+    }
+    closest
+}
+/// the distance from p to the line through a and b.
+fn line_distance(a: Vector2<f64>, b: Vector2<f64>, p: Vector2<f64>) -> f64 {
+    (b - a).cross(p - a).abs() / (b - a).length()
+}
+
+#[test]
+fn indexing_and_construction() {
+    let mut triangle = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0));
+    assert_eq!(triangle[0], Vector2::new(0.0, 0.0));
+    assert_eq!(triangle[2], Vector2::new(0.0, 1.0));
+    triangle[1] = Vector2::new(2.0, 0.0);
+    assert_eq!(triangle.points()[1], Vector2::new(2.0, 0.0));
+    assert_eq!(Triangle::from(*triangle.points()), triangle);
+}
+
+#[test]
+fn measurements() {
+    let right = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 3.0));
+    assert_eq!(right.area(), 6.0);
+    assert_eq!(right.signed_area(), 6.0);
+    assert_eq!(Triangle::new(right[0], right[2], right[1]).signed_area(), -6.0);
+    assert_eq!(right.centroid(), Vector2::new(4.0 / 3.0, 1.0));
+    // the circumcenter of a right triangle is the middle of its longest side
+    assert_eq!(right.circumcenter(), Some(Vector2::new(2.0, 1.5)));
+    // a 3 4 5 triangle has an inscribed circle of radius 1
+    assert!((right.incenter() - Vector2::new(1.0, 1.0)).length() < 1e-12);
+    let flat = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(3.0, 3.0));
+    assert_eq!(flat.circumcenter(), None);
+    assert_eq!(flat.area(), 0.0);
+    let point = Triangle::new(Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.0));
+    assert_eq!(point.incenter(), Vector2::new(1.0, 2.0));
+
+    let mut rng = Lcg(1);
+    for _ in 0..100 {
+        let triangle = Triangle::new(rng.vector3(), rng.vector3(), rng.vector3());
+        let [a, b, c] = *triangle.points();
+        let cross = (b - a).cross(c - a);
+        assert!((triangle.area() - cross.length() / 2.0).abs() < 1e-12);
+        let normal = triangle.normal();
+        assert!((normal.length() - 1.0).abs() < 1e-12);
+        assert!(normal.dot(&(b - a)).abs() < 1e-12 && normal.dot(&(c - a)).abs() < 1e-12);
+        assert!(normal.dot(&cross) > 0.0);
+        let center = triangle.circumcenter().unwrap();
+        let radius = (center - a).length();
+        assert!(((center - b).length() - radius).abs() < 1e-9 && ((center - c).length() - radius).abs() < 1e-9);
+        // the circumcenter is on the plane of the triangle
+        assert!((center - a).dot(&normal).abs() < 1e-9);
+        assert!(triangle.contains(triangle.incenter()));
+        assert!(triangle.contains(triangle.centroid()));
+    }
+    // the incenter is the same distance from every side
+    for _ in 0..100 {
+        let triangle = Triangle::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let [a, b, c] = *triangle.points();
+        let center = triangle.incenter();
+        let radius = line_distance(a, b, center);
+        assert!((line_distance(b, c, center) - radius).abs() < 1e-9);
+        assert!((line_distance(c, a, center) - radius).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn barycentric_coordinates_and_containment() {
+    let mut rng = Lcg(2);
+    for _ in 0..200 {
+        let triangle = Triangle::new(rng.vector2(), rng.vector2(), rng.vector2());
+        let p = rng.vector2();
+        let weights = triangle.barycentric(p);
+        assert!((weights.x + weights.y + weights.z - 1.0).abs() < 1e-9);
+        assert!((triangle.interpolate(weights) - p).length() < 1e-9);
+        // matches the free function, which weighs the points the other way around
+        let [a, b, c] = *triangle.points();
+        let free = barycentric_coordinates(a, b, c, p);
+        assert_eq!((free.z, free.y, free.x), (weights.x, weights.y, weights.z));
+        // inside when p is on the same side of every edge
+        let sides = [(a, b), (b, c), (c, a)].map(|(from, to)| (to - from).cross(p - from));
+        let inside = sides.iter().all(|&side| side >= 0.0) || sides.iter().all(|&side| side <= 0.0);
+        assert_eq!(triangle.contains(p), inside);
+        for i in 0..3 {
+            assert!(triangle.contains(triangle[i]));
+        }
+    }
+    for _ in 0..200 {
+        let triangle = Triangle::new(rng.vector3(), rng.vector3(), rng.vector3());
+        let weights = Vector3::new(rng.next(), rng.next(), 0.0);
+        let weights = Vector3::new(weights.x, weights.y, 1.0 - weights.x - weights.y);
+        let p = triangle.interpolate(weights);
+        let inside = weights.x >= 0.0 && weights.y >= 0.0 && weights.z >= 0.0;
+        assert!((triangle.barycentric(p) - weights).length() < 1e-9);
+        // moving off the plane keeps the weights
+        let lifted = p + triangle.normal() * rng.next();
+        assert!((triangle.barycentric(lifted) - weights).length() < 1e-9);
+        if weights.x.min(weights.y).min(weights.z).abs() > 1e-9 {
+            assert_eq!(triangle.contains(lifted), inside);
+        }
+    }
+    let flat = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0));
+    assert!(!flat.contains(Vector2::new(0.5, 0.5)));
+}
+
+#[test]
+fn closest_points() {
+    let mut rng = Lcg(3);
+    for _ in 0..30 {
+        let triangle = Triangle::new(rng.vector2(), rng.vector2(), rng.vector2());
+        for _ in 0..10 {
+            let p = rng.vector2() * 2.0;
+            let closest = triangle.closest_point(p);
+            let brute = brute_closest(&triangle, p);
+            assert!((closest - p).length() <= (brute - p).length() + 1e-12);
+            assert!((closest - brute).length() < 1e-2);
+            if triangle.contains(p) {
+                assert!((closest - p).length() < 1e-12);
+            }
+        }
+    }
+    for _ in 0..30 {
+        let triangle = Triangle::new(rng.vector3(), rng.vector3(), rng.vector3());
+        for _ in 0..10 {
+            let p = rng.vector3() * 2.0;
+            let closest = triangle.closest_point(p);
+            let brute = brute_closest(&triangle, p);
+            assert!((closest - p).length() <= (brute - p).length() + 1e-12);
+            assert!((closest - brute).length() < 1e-2);
+        }
+    }
+}